@@ -0,0 +1,59 @@
+//! Times the monthly lookup hot path against the bundled history
+//!
+//! Run with: cargo bench --bench lookup
+#![allow(clippy::unwrap_used)] // benches favour brevity
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use hmrc_rates::{Rates, YearMonth};
+use rust_decimal::Decimal;
+
+/// Runs `f` in batches until half a second has passed and prints the mean time per call.
+fn bench(name: &str, mut f: impl FnMut()) {
+    const BATCH: u32 = 1_000;
+    for _ in 0..BATCH {
+        f();
+    }
+    let (mut calls, mut elapsed) = (0u32, Duration::ZERO);
+    while elapsed < Duration::from_millis(500) {
+        let start = Instant::now();
+        for _ in 0..BATCH {
+            f();
+        }
+        elapsed += start.elapsed();
+        calls += BATCH;
+    }
+    println!("{name:<32} {:>10.1?}/call", elapsed / calls);
+}
+
+fn main() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let table = rates.monthly(month).unwrap();
+    let amount = Decimal::from(2500);
+
+    bench("Rates::monthly_rate", || {
+        black_box(rates.monthly_rate(black_box("USD"), month).unwrap());
+    });
+    bench("Rates::convert_detailed", || {
+        black_box(
+            rates
+                .convert_detailed(amount, black_box("USD"), month)
+                .unwrap(),
+        );
+    });
+    bench("Table::rate", || {
+        black_box(table.rate(black_box("USD")).unwrap());
+    });
+    bench("Table::rate, last code", || {
+        black_box(table.rate(black_box("ZMW")).unwrap());
+    });
+    bench("Rates::monthly, unpublished", || {
+        black_box(
+            rates
+                .monthly(black_box(YearMonth::new(2001, 1).unwrap()))
+                .ok(),
+        );
+    });
+}
@@ -8,32 +8,92 @@ mod parse;
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
-use parse::{ParsedRate, dedup_majority};
+use parse::{ParsedCountry, ParsedRate, dedup_majority};
 
 fn main() {
-    println!("cargo:rerun-if-changed=data");
+    let (data, custom) = data_dir();
+    println!("cargo:rerun-if-changed={}", data.display());
     if std::env::var_os("CARGO_FEATURE_BUNDLED").is_none() {
         return;
     }
     let out = PathBuf::from(std::env::var_os("OUT_DIR").expect("OUT_DIR not set"));
 
-    let monthly = load_monthly(Path::new("data/monthly"));
-    let average = load_year_end(Path::new("data/average"), "average");
-    let spot = load_year_end(Path::new("data/spot"), "spot");
-    let weekly = load_weekly(Path::new("data/weekly"));
+    let (mut monthly, mut countries) = load_monthly(&data.join("monthly"), !custom);
+    let mut average = load_year_end(&data.join("average"), "average");
+    let mut spot = load_year_end(&data.join("spot"), "spot");
+    let mut weekly = load_weekly(&data.join("weekly"));
+
+    if let Some((year, month)) = bundle_from() {
+        let from = year * 12 + month as i32 - 1;
+        monthly.retain(|(key, _)| *key >= from);
+        countries.retain_mut(|run| {
+            run.first = run.first.max(from);
+            run.last >= from
+        });
+        assert!(
+            !monthly.is_empty(),
+            "HMRC_RATES_BUNDLE_FROM={year}-{month:02} is after the newest bundled month"
+        );
+        // A year-end period is kept if it ends in or after the cutoff month
+        let year_end_month = |key: i32| (key / 2) * 12 + if key % 2 == 1 { 11 } else { 2 };
+        average.retain(|(key, _)| year_end_month(*key) >= from);
+        spot.retain(|(key, _)| year_end_month(*key) >= from);
+        let first_day = parse::date::days_from_civil(year, month, 1);
+        weekly.retain(|(_, end, _)| *end >= first_day);
+    }
 
     let mut code = String::new();
     emit_series(&mut code, "MONTHLY", &monthly);
     emit_series(&mut code, "SPOT", &spot);
     emit_series(&mut code, "AVERAGE", &average);
     emit_weeks(&mut code, "WEEKLY", &weekly);
+    emit_countries(&mut code, &countries);
 
     std::fs::write(out.join("bundled.rs"), code).expect("failed to write bundled.rs");
 }
 
+/// `HMRC_RATES_BUNDLE_FROM=YYYY-MM` bundles only periods ending in or after that month.
+/// An environment variable rather than a feature: features are additive,
+/// and one dependent trimming history must not strip it from another.
+fn bundle_from() -> Option<(i32, u32)> {
+    println!("cargo:rerun-if-env-changed=HMRC_RATES_BUNDLE_FROM");
+    let value = std::env::var("HMRC_RATES_BUNDLE_FROM").ok()?;
+    Some(
+        parse::parse_year_month(value.trim())
+            .unwrap_or_else(|| panic!("HMRC_RATES_BUNDLE_FROM='{value}' is not YYYY-MM")),
+    )
+}
+
+/// `HMRC_RATES_DATA_DIR` replaces the crate's own `data/` with a directory of the same layout,
+/// e.g. a curated or extended history; `true` when it does.
+/// Relative paths resolve against this crate's directory, so give an absolute one,
+/// or set it with `relative = true` in `.cargo/config.toml`'s `[env]`.
+fn data_dir() -> (PathBuf, bool) {
+    println!("cargo:rerun-if-env-changed=HMRC_RATES_DATA_DIR");
+    match std::env::var_os("HMRC_RATES_DATA_DIR") {
+        Some(dir) if !dir.is_empty() => {
+            let dir = PathBuf::from(dir);
+            assert!(
+                dir.join("monthly").is_dir(),
+                "HMRC_RATES_DATA_DIR={} has no monthly/ directory",
+                dir.display()
+            );
+            (dir, true)
+        }
+        _ => (PathBuf::from("data"), false),
+    }
+}
+
 /// Sorted (period key, sorted deduped rates) for one series.
 type SeriesData = Vec<(i32, Vec<ParsedRate>)>;
 
+/// One country's listing, unchanged over the monthly keys `first..=last`.
+struct CountryRun {
+    first: i32,
+    last: i32,
+    country: ParsedCountry,
+}
+
 fn sorted_files(dir: &Path, extension: &str) -> Vec<PathBuf> {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return Vec::new(); // absent directory = empty series
@@ -56,28 +116,33 @@ fn file_period(path: &Path) -> (i32, u32) {
         .unwrap_or_else(|| panic!("{}: file name is not YYYY-MM", path.display()))
 }
 
-fn load_monthly(dir: &Path) -> SeriesData {
+/// `complete`: every month must be a full HMRC table, as the crate's own data is;
+/// a custom data set may be curated down to a few currencies.
+fn load_monthly(dir: &Path, complete: bool) -> (SeriesData, Vec<CountryRun>) {
     let mut series = SeriesData::new();
+    let mut listings = Vec::new();
     for path in sorted_files(dir, "xml") {
         let (year, month) = file_period(&path);
         let bytes = std::fs::read(&path).expect("unreadable file");
-        let ((py, pm), rates) =
+        let doc =
             parse::parse_monthly_xml(&bytes).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
         assert_eq!(
-            (py, pm),
+            doc.period,
             (year, month),
             "{}: Period does not match file name",
             path.display()
         );
-        let rates = dedup_majority(rates).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let rates = dedup_majority(doc.rates).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
         assert!(
-            rates.len() >= 100,
+            !rates.is_empty() && (!complete || rates.len() >= 100),
             "{}: implausibly few rates",
             path.display()
         );
-        series.push((year * 12 + month as i32 - 1, rates));
+        let key = year * 12 + month as i32 - 1;
+        series.push((key, rates));
+        listings.extend(doc.countries.into_iter().map(|c| (key, c)));
     }
-    assert!(!series.is_empty(), "data/monthly is empty");
+    assert!(!series.is_empty(), "{} is empty", dir.display());
     series.sort_by_key(|(k, _)| *k);
     for pair in series.windows(2) {
         assert!(
@@ -87,7 +152,25 @@ fn load_monthly(dir: &Path) -> SeriesData {
             pair[1].0
         );
     }
-    series
+    (series, country_runs(listings))
+}
+
+/// Collapses per-month listings into runs of consecutive identical months per country.
+fn country_runs(mut listings: Vec<(i32, ParsedCountry)>) -> Vec<CountryRun> {
+    listings.sort_by(|(a, x), (b, y)| x.country_code.cmp(&y.country_code).then(a.cmp(b)));
+    listings.dedup_by(|(a, x), (b, y)| a == b && x.country_code == y.country_code);
+    let mut runs: Vec<CountryRun> = Vec::new();
+    for (key, country) in listings {
+        match runs.last_mut() {
+            Some(run) if run.last + 1 == key && run.country == country => run.last = key,
+            _ => runs.push(CountryRun {
+                first: key,
+                last: key,
+                country,
+            }),
+        }
+    }
+    runs
 }
 
 fn load_year_end(dir: &Path, label: &str) -> SeriesData {
@@ -196,6 +279,31 @@ fn emit_series(code: &mut String, name: &str, series: &SeriesData) {
     .expect("write");
 }
 
+fn emit_countries(code: &mut String, runs: &[CountryRun]) {
+    writeln!(
+        code,
+        "pub(crate) static COUNTRIES: &[crate::store::CountryRun] = &["
+    )
+    .expect("write");
+    for run in runs {
+        let c = &run.country;
+        writeln!(
+            code,
+            "    crate::store::CountryRun {{ first: {}, last: {}, row: crate::store::CountryRow {{ \
+             code: alloc::borrow::Cow::Borrowed({:?}), name: alloc::borrow::Cow::Borrowed({:?}), \
+             currency: *b\"{}\", currency_name: alloc::borrow::Cow::Borrowed({:?}) }} }},",
+            run.first,
+            run.last,
+            c.country_code,
+            c.country_name,
+            core::str::from_utf8(&c.code).expect("code is ASCII"),
+            c.currency_name,
+        )
+        .expect("write");
+    }
+    writeln!(code, "];").expect("write");
+}
+
 fn emit_weeks(code: &mut String, name: &str, weeks: &[(i32, i32, Vec<ParsedRate>)]) {
     emit_arena(code, name, weeks.iter().map(|(_, _, r)| r.as_slice()));
 
@@ -0,0 +1,54 @@
+// Generated by cbindgen from src/lib.rs, do not edit.
+
+#ifndef HMRC_RATES_H
+#define HMRC_RATES_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Outcome of a call; anything but `HMRC_RATES_STATUS_OK` leaves outputs untouched.
+typedef enum HmrcRatesStatus {
+  HMRC_RATES_STATUS_OK = 0,
+  // A null pointer, non-UTF-8 text, a malformed amount or an invalid month.
+  HMRC_RATES_STATUS_INVALID_ARGUMENT = 1,
+  // The currency code is never published in the monthly series.
+  HMRC_RATES_STATUS_UNKNOWN_CURRENCY = 2,
+  // No monthly table for that month.
+  HMRC_RATES_STATUS_PERIOD_NOT_AVAILABLE = 3,
+  // The month exists but the currency is absent from it.
+  HMRC_RATES_STATUS_NOT_IN_PERIOD = 4,
+  // The output buffer cannot hold the result and its terminating NUL.
+  HMRC_RATES_STATUS_BUFFER_TOO_SMALL = 5,
+  // The amount is too large to convert to GBP exactly.
+  HMRC_RATES_STATUS_OVERFLOW = 6,
+} HmrcRatesStatus;
+
+// Opaque handle to the bundled rates.
+typedef struct HmrcRates HmrcRates;
+
+// The bundled rates; free with [`hmrc_rates_free`]. Never null.
+struct HmrcRates *hmrc_rates_new(void);
+
+// Releases a handle from [`hmrc_rates_new`]; null is ignored.
+//
+// # Safety
+//
+// `rates` must be null or a handle from [`hmrc_rates_new`] not yet freed.
+void hmrc_rates_free(struct HmrcRates *rates);
+
+// Converts `amount` (e.g. `"1234.56"`) of `currency` to GBP at the monthly
+// rate for `year`-`month`, writing the exact result as a NUL-terminated string.
+//
+// # Safety
+//
+// `rates` must be a live handle, `amount` and `currency` NUL-terminated strings,
+// and `out` valid for writes of `out_len` bytes.
+enum HmrcRatesStatus hmrc_rates_convert(const struct HmrcRates *rates,
+                                        const char *amount,
+                                        const char *currency,
+                                        int32_t year,
+                                        uint32_t month,
+                                        char *out,
+                                        size_t out_len);
+
+#endif  /* HMRC_RATES_H */
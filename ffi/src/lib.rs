@@ -0,0 +1,210 @@
+//! C bindings for the `hmrc-rates` crate, with the header in `include/hmrc_rates.h`.
+//!
+//! Amounts cross the boundary as decimal strings so they stay exact,
+//! and results are written into caller-owned buffers.
+//! Every function reports an [`HmrcRatesStatus`]; none of them panic across the boundary.
+
+use std::ffi::{CStr, c_char};
+
+use hmrc_rates::{ConvertError, LookupError, Rates, YearMonth};
+use rust_decimal::Decimal;
+
+/// Opaque handle to the bundled rates.
+pub struct HmrcRates(Rates);
+
+/// Outcome of a call; anything but `HMRC_RATES_STATUS_OK` leaves outputs untouched.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HmrcRatesStatus {
+    Ok = 0,
+    /// A null pointer, non-UTF-8 text, a malformed amount or an invalid month.
+    InvalidArgument = 1,
+    /// The currency code is never published in the monthly series.
+    UnknownCurrency = 2,
+    /// No monthly table for that month.
+    PeriodNotAvailable = 3,
+    /// The month exists but the currency is absent from it.
+    NotInPeriod = 4,
+    /// The output buffer cannot hold the result and its terminating NUL.
+    BufferTooSmall = 5,
+    /// The amount is too large to convert to GBP exactly.
+    Overflow = 6,
+}
+
+impl From<LookupError> for HmrcRatesStatus {
+    fn from(e: LookupError) -> HmrcRatesStatus {
+        match e {
+            LookupError::UnknownCurrency { .. } => HmrcRatesStatus::UnknownCurrency,
+            LookupError::PeriodNotAvailable { .. } => HmrcRatesStatus::PeriodNotAvailable,
+            LookupError::NotInPeriod { .. } => HmrcRatesStatus::NotInPeriod,
+            _ => HmrcRatesStatus::InvalidArgument,
+        }
+    }
+}
+
+impl From<ConvertError> for HmrcRatesStatus {
+    fn from(e: ConvertError) -> HmrcRatesStatus {
+        match e {
+            ConvertError::Lookup(e) => e.into(),
+            ConvertError::Arithmetic(_) => HmrcRatesStatus::Overflow,
+            _ => HmrcRatesStatus::InvalidArgument,
+        }
+    }
+}
+
+/// The bundled rates; free with [`hmrc_rates_free`]. Never null.
+#[unsafe(no_mangle)]
+pub extern "C" fn hmrc_rates_new() -> *mut HmrcRates {
+    Box::into_raw(Box::new(HmrcRates(Rates::new())))
+}
+
+/// Releases a handle from [`hmrc_rates_new`]; null is ignored.
+///
+/// # Safety
+///
+/// `rates` must be null or a handle from [`hmrc_rates_new`] not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hmrc_rates_free(rates: *mut HmrcRates) {
+    if !rates.is_null() {
+        // SAFETY: the caller passes a live handle from `hmrc_rates_new`
+        drop(unsafe { Box::from_raw(rates) });
+    }
+}
+
+/// Converts `amount` (e.g. `"1234.56"`) of `currency` to GBP at the monthly
+/// rate for `year`-`month`, writing the exact result as a NUL-terminated string.
+///
+/// # Safety
+///
+/// `rates` must be a live handle, `amount` and `currency` NUL-terminated strings,
+/// and `out` valid for writes of `out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hmrc_rates_convert(
+    rates: *const HmrcRates,
+    amount: *const c_char,
+    currency: *const c_char,
+    year: i32,
+    month: u32,
+    out: *mut c_char,
+    out_len: usize,
+) -> HmrcRatesStatus {
+    // SAFETY: the caller upholds the pointer contracts above
+    let (Some(rates), Some(amount), Some(currency)) =
+        (unsafe { rates.as_ref() }, unsafe { text(amount) }, unsafe {
+            text(currency)
+        })
+    else {
+        return HmrcRatesStatus::InvalidArgument;
+    };
+    let (Ok(amount), Some(year_month)) = (
+        amount.trim().parse::<Decimal>(),
+        YearMonth::new(year, month),
+    ) else {
+        return HmrcRatesStatus::InvalidArgument;
+    };
+    match rates.0.checked_convert(amount, currency, year_month) {
+        // SAFETY: the caller guarantees `out` holds `out_len` bytes
+        Ok(gbp) => unsafe { write(&gbp.amount().to_string(), out, out_len) },
+        Err(e) => e.into(),
+    }
+}
+
+/// # Safety
+///
+/// `s` must be null or a NUL-terminated string.
+unsafe fn text<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    // SAFETY: non-null and NUL-terminated per the caller
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+/// # Safety
+///
+/// `out` must be null or valid for writes of `out_len` bytes.
+unsafe fn write(s: &str, out: *mut c_char, out_len: usize) -> HmrcRatesStatus {
+    if out.is_null() {
+        return HmrcRatesStatus::InvalidArgument;
+    }
+    if s.len() >= out_len {
+        return HmrcRatesStatus::BufferTooSmall;
+    }
+    // SAFETY: `s.len() + 1 <= out_len` bytes fit in `out`
+    unsafe {
+        std::ptr::copy_nonoverlapping(s.as_ptr().cast::<c_char>(), out, s.len());
+        out.add(s.len()).write(0);
+    }
+    HmrcRatesStatus::Ok
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn convert(amount: &CStr, currency: &CStr, out_len: usize) -> (HmrcRatesStatus, String) {
+        let rates = hmrc_rates_new();
+        let mut out = vec![0 as c_char; out_len];
+        let status = unsafe {
+            hmrc_rates_convert(
+                rates,
+                amount.as_ptr(),
+                currency.as_ptr(),
+                2025,
+                8,
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+        unsafe { hmrc_rates_free(rates) };
+        let text = unsafe { CStr::from_ptr(out.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_owned();
+        (status, text)
+    }
+
+    #[test]
+    fn converts_exactly_into_the_buffer() {
+        let (status, gbp) = convert(c"100", c"EUR", 64);
+        assert_eq!(status, HmrcRatesStatus::Ok);
+        let expected = Rates::new()
+            .convert_detailed(Decimal::from(100), "EUR", YearMonth::new(2025, 8).unwrap())
+            .unwrap();
+        assert_eq!(gbp, expected.gbp().amount().to_string());
+    }
+
+    #[test]
+    fn failures_map_to_status_codes() {
+        assert_eq!(
+            convert(c"1", c"ZZZ", 64).0,
+            HmrcRatesStatus::UnknownCurrency
+        );
+        assert_eq!(
+            convert(c"lots", c"USD", 64).0,
+            HmrcRatesStatus::InvalidArgument
+        );
+        assert_eq!(
+            convert(c"79228162514264337593543950335", c"KWD", 64).0,
+            HmrcRatesStatus::Overflow
+        );
+        assert_eq!(
+            convert(c"100", c"EUR", 4),
+            (HmrcRatesStatus::BufferTooSmall, String::new())
+        );
+        let status = unsafe {
+            hmrc_rates_convert(
+                std::ptr::null(),
+                c"1".as_ptr(),
+                c"USD".as_ptr(),
+                2025,
+                8,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        assert_eq!(status, HmrcRatesStatus::InvalidArgument);
+        unsafe { hmrc_rates_free(std::ptr::null_mut()) };
+    }
+}
@@ -0,0 +1,69 @@
+//! Node.js bindings for the `hmrc-rates` crate, via napi-rs.
+//!
+//! The bundled history is compiled in, so lookups need no network or file I/O.
+//! Amounts and rates cross the boundary as decimal strings:
+//! JavaScript numbers are binary floats and would lose exactness.
+
+use chrono::NaiveDate;
+use hmrc_rates::{RateType, Rates, YearMonth};
+use napi::{Error, Result};
+use napi_derive::napi;
+use rust_decimal::Decimal;
+
+/// The month of an ISO date, `"YYYY-MM-DD"`, or of a bare `"YYYY-MM"`.
+fn parse_month(date_iso: &str) -> Result<YearMonth> {
+    let date_iso = date_iso.trim();
+    if let Ok(date) = date_iso.parse::<NaiveDate>() {
+        return Ok(date.into());
+    }
+    date_iso
+        .parse()
+        .map_err(|_| Error::from_reason(format!("invalid date '{date_iso}'")))
+}
+
+fn reason(e: impl std::fmt::Display) -> Error {
+    Error::from_reason(e.to_string())
+}
+
+/// Converts `amount` of `currency` to GBP at the monthly rate for `dateIso`.
+///
+/// Takes and returns exact decimal strings, e.g. `convert("100", "USD", "2025-08-15")`.
+/// An amount too large to convert exactly throws rather than overflowing.
+#[napi]
+pub fn convert(amount: String, currency: String, date_iso: String) -> Result<String> {
+    let amount: Decimal = amount
+        .trim()
+        .parse()
+        .map_err(|_| Error::from_reason(format!("invalid amount '{amount}'")))?;
+    let gbp = Rates::bundled()
+        .checked_convert(amount, &currency, parse_month(&date_iso)?)
+        .map_err(reason)?;
+    Ok(gbp.amount().to_string())
+}
+
+/// `currency`'s monthly rate for `dateIso`, in units per £1, as a decimal string.
+#[napi]
+pub fn rate(currency: String, date_iso: String) -> Result<String> {
+    let rate = Rates::bundled()
+        .monthly_rate(&currency, parse_month(&date_iso)?)
+        .map_err(reason)?;
+    Ok(rate.units_per_gbp().to_string())
+}
+
+/// Currency codes in the monthly table for `dateIso`, or every monthly code when omitted.
+#[napi]
+pub fn currencies(date_iso: Option<String>) -> Result<Vec<String>> {
+    let rates = Rates::bundled();
+    Ok(match date_iso {
+        Some(date_iso) => rates
+            .monthly(parse_month(&date_iso)?)
+            .map_err(reason)?
+            .currencies()
+            .map(|c| c.to_string())
+            .collect(),
+        None => rates
+            .currencies(RateType::Monthly)
+            .map(|c| c.to_string())
+            .collect(),
+    })
+}
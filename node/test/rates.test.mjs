@@ -0,0 +1,26 @@
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { convert, currencies, rate } from "../index.js";
+
+test("convert is exact and takes a date or a month", () => {
+  assert.equal(convert("135.41", "USD", "2025-08-15"), "100");
+  assert.equal(convert("135.41", "usd", "2025-08"), "100");
+});
+
+test("rate is units per pound", () => {
+  assert.equal(rate("USD", "2025-08-01"), "1.3541");
+});
+
+test("currencies lists one month or every monthly code", () => {
+  assert.ok(currencies("2025-08").includes("EUR"));
+  assert.ok(currencies().length >= currencies("2025-08").length);
+});
+
+test("lookups are strict", () => {
+  assert.throws(() => convert("1", "USD", "1999-01-01"));
+  assert.throws(() => rate("ZZZ", "2025-08"));
+  assert.throws(() => convert("ten", "USD", "2025-08"), /invalid amount/);
+  assert.throws(() => rate("USD", "15/08/2025"), /invalid date/);
+  assert.throws(() => convert("79228162514264337593543950335", "KWD", "2025-08"), /overflows/);
+});
@@ -74,6 +74,7 @@ class Currency:
     """A three-letter currency code as published by HMRC (not always ISO 4217)."""
 
     GBP: ClassVar[Currency]
+    def __init__(self, code: str) -> None: ...
     @property
     def code(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
@@ -125,6 +126,7 @@ class Rate:
     def period(self) -> Period: ...
     def to_gbp(self, amount: Amount) -> Decimal: ...
     def from_gbp(self, gbp: Amount) -> Decimal: ...
+    def to_currency(self, amount: Amount, target: Rate) -> Decimal: ...
     def __eq__(self, other: object) -> bool: ...
 
 @final
@@ -137,6 +139,7 @@ class Table:
     def rate_type(self) -> RateType: ...
     def rate(self, code: str) -> Rate: ...
     def get(self, code: str) -> Rate | None: ...
+    def currencies(self) -> list[Currency]: ...
     def __len__(self) -> int: ...
     def __iter__(self) -> Iterator[tuple[Currency, Rate]]: ...
 
@@ -149,6 +152,11 @@ class Rates:
     def monthly_rate_or_earlier(
         self, code: str, year_month: YearMonthLike, max_months_back: int
     ) -> Rate: ...
+    def convert(self, amount: Amount, code: str, year_month: YearMonthLike) -> Decimal: ...
+    def convert_between(
+        self, amount: Amount, from_code: str, to_code: str, year_month: YearMonthLike
+    ) -> Decimal: ...
+    def convert_str(self, input: str, year_month: YearMonthLike) -> Decimal: ...
     def monthly(self, year_month: YearMonthLike) -> Table: ...
     def spot(self, period: YearEnd) -> Table: ...
     def average(self, period: YearEnd) -> Table: ...
@@ -157,6 +165,7 @@ class Rates:
     def spot_periods(self) -> list[YearEnd]: ...
     def average_periods(self) -> list[YearEnd]: ...
     def weeks(self) -> list[Period]: ...
+    def coverage(self, table: RateType) -> tuple[Period, Period] | None: ...
     def currencies(self, table: RateType) -> list[Currency]: ...
 
 @final
@@ -57,6 +57,13 @@ fn lookup_err(e: hmrc_rates::LookupError) -> PyErr {
     }
 }
 
+fn convert_err(e: hmrc_rates::ConvertError) -> PyErr {
+    match e {
+        hmrc_rates::ConvertError::Lookup(e) => lookup_err(e),
+        e => PyValueError::new_err(e.to_string()),
+    }
+}
+
 fn fetch_err(e: hmrc_rates::FetchError) -> PyErr {
     FetchError::new_err(e.to_string())
 }
@@ -218,6 +225,14 @@ impl std::fmt::Display for PyCurrency {
 
 #[pymethods]
 impl PyCurrency {
+    /// Validates three ASCII letters (any case); whether HMRC publishes the code is not checked.
+    #[new]
+    fn new(code: &str) -> PyResult<Self> {
+        code.parse()
+            .map(PyCurrency)
+            .map_err(|_| PyValueError::new_err(format!("invalid currency code '{code}'")))
+    }
+
     /// Pound sterling, the base of every HMRC rate.
     #[classattr]
     #[allow(non_snake_case)]
@@ -328,20 +343,16 @@ impl PyPeriod {
         }
     }
 
+    /// The first day the period covers; a year end spans the twelve months up to it.
     #[getter]
     fn start(&self) -> Option<NaiveDate> {
-        match self.0 {
-            hmrc_rates::Period::Week { start, .. } => Some(start),
-            _ => None,
-        }
+        self.0.start()
     }
 
+    /// The last day the period covers (inclusive).
     #[getter]
     fn end(&self) -> Option<NaiveDate> {
-        match self.0 {
-            hmrc_rates::Period::Week { end, .. } => Some(end),
-            _ => None,
-        }
+        self.0.end()
     }
 
     fn __repr__(&self) -> String {
@@ -438,6 +449,11 @@ impl PyRate {
             .ok_or_else(|| PyOverflowError::new_err("decimal overflow in from_gbp"))
     }
 
+    /// Converts an amount in this rate's currency to `target`'s currency through GBP.
+    fn to_currency(&self, amount: Amount, target: PyRef<'_, PyRate>) -> PyResult<Decimal> {
+        cross(amount.0, self.0, target.0)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Rate('{}', {}, '{}')",
@@ -448,6 +464,14 @@ impl PyRate {
     }
 }
 
+/// `amount * target / source`, the checked form of `Rate::to_currency`.
+fn cross(amount: Decimal, source: hmrc_rates::Rate, target: hmrc_rates::Rate) -> PyResult<Decimal> {
+    amount
+        .checked_mul(target.units_per_gbp())
+        .and_then(|v| v.checked_div(source.units_per_gbp()))
+        .ok_or_else(|| PyOverflowError::new_err("decimal overflow in to_currency"))
+}
+
 #[derive(Copy, Clone)]
 enum TableKey {
     YearMonth(hmrc_rates::YearMonth),
@@ -517,6 +541,11 @@ impl PyTable {
             .and_then(|t| t.get(code).map(PyRate))
     }
 
+    /// The currencies quoted in this table, ascending by code.
+    fn currencies(&self) -> Vec<PyCurrency> {
+        self.entries.iter().map(|(c, _)| PyCurrency(*c)).collect()
+    }
+
     fn __len__(&self) -> usize {
         self.entries.len()
     }
@@ -594,6 +623,37 @@ impl PyRates {
             .map_err(lookup_err)
     }
 
+    /// Converts `amount` of `code` to GBP at the monthly rate for `year_month` (exact, unrounded).
+    fn convert(&self, amount: Amount, code: &str, year_month: YearMonthArg) -> PyResult<Decimal> {
+        let rate = self
+            .inner
+            .monthly_rate(code, year_month.into_year_month()?)
+            .map_err(lookup_err)?;
+        PyRate(rate).to_gbp(amount)
+    }
+
+    /// Converts `amount` between two currencies at one month's rates, through GBP.
+    fn convert_between(
+        &self,
+        amount: Amount,
+        from_code: &str,
+        to_code: &str,
+        year_month: YearMonthArg,
+    ) -> PyResult<Decimal> {
+        let year_month = year_month.into_year_month()?;
+        let from = self.inner.monthly_rate(from_code, year_month);
+        let to = self.inner.monthly_rate(to_code, year_month);
+        cross(amount.0, from.map_err(lookup_err)?, to.map_err(lookup_err)?)
+    }
+
+    /// Converts free text like `"1234.56 USD"` to GBP at the monthly rate (exact, unrounded).
+    fn convert_str(&self, input: &str, year_month: YearMonthArg) -> PyResult<Decimal> {
+        self.inner
+            .convert_str(input, year_month.into_year_month()?)
+            .map(Decimal::from)
+            .map_err(convert_err)
+    }
+
     /// The full monthly table for a month.
     fn monthly(&self, year_month: YearMonthArg) -> PyResult<PyTable> {
         PyTable::build(
@@ -637,6 +697,12 @@ impl PyRates {
         self.inner.weeks().map(PyPeriod).collect()
     }
 
+    /// The first and last loaded period of a series, or `None` when it is empty.
+    fn coverage(&self, table: PyRateType) -> Option<(PyPeriod, PyPeriod)> {
+        let (first, last) = self.inner.coverage(table.to_rust())?;
+        Some((PyPeriod(first), PyPeriod(last)))
+    }
+
     /// Every currency that appears anywhere in a series.
     fn currencies(&self, table: PyRateType) -> Vec<PyCurrency> {
         self.inner
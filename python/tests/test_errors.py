@@ -54,3 +54,10 @@ def test_table_rate_unknown_currency():
 def test_invalid_month_string():
     with pytest.raises(ValueError, match="YYYY-MM"):
         rates.monthly_rate("USD", "August 2025")
+
+
+def test_convert_str_errors():
+    with pytest.raises(ValueError, match="1234.56 USD"):
+        rates.convert_str("USD 1234.56", "2025-08")
+    with pytest.raises(UnknownCurrencyError):
+        rates.convert_str("1 ZZZ", "2025-08")
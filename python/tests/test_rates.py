@@ -56,6 +56,35 @@ def test_monthly_rate_or_earlier_reveals_substitution():
     assert rate.period.year_month == newest
 
 
+def test_convert_takes_dates_and_decimals():
+    rate = rates.monthly_rate("USD", YearMonth(2025, 8))
+    gbp = rates.convert(Decimal("2500"), "usd", datetime.date(2025, 8, 15))
+    assert type(gbp) is Decimal
+    assert gbp == rate.to_gbp(Decimal("2500"))
+    assert rates.convert(2500, "USD", "2025-08") == gbp
+
+
+def test_convert_between_goes_through_gbp():
+    usd = rates.monthly_rate("USD", YearMonth(2025, 8))
+    eur = rates.monthly_rate("EUR", YearMonth(2025, 8))
+    euros = rates.convert_between(Decimal("100"), "USD", "EUR", "2025-08")
+    assert euros == usd.to_currency(Decimal("100"), eur)
+    assert euros == Decimal("100") * eur.units_per_gbp / usd.units_per_gbp
+
+
+def test_period_date_bounds():
+    period = rates.monthly_rate("USD", YearMonth(2024, 2)).period
+    assert (period.start, period.end) == (
+        datetime.date(2024, 2, 1),
+        datetime.date(2024, 2, 29),
+    )
+    year = rates.average(YearEnd.march(2025)).period
+    assert (year.start, year.end) == (
+        datetime.date(2024, 4, 1),
+        datetime.date(2025, 3, 31),
+    )
+
+
 def test_monthly_table():
     table = rates.monthly(YearMonth(2025, 8))
     assert len(table) > 100
@@ -70,6 +99,7 @@ def test_monthly_table():
     assert table.get("XXX") is None
     usd = table.rate("USD")
     assert table.get("USD") == usd
+    assert table.currencies() == list(entries)
 
 
 def test_spot_average_weekly_tables():
@@ -95,6 +125,13 @@ def test_period_listings():
     assert "USD" in [c.code for c in currencies]
 
 
+def test_coverage():
+    first, last = rates.coverage(RateType.MONTHLY)
+    assert first.year_month == YearMonth(2014, 2)
+    assert last.year_month == rates.months()[-1]
+    assert first.start == datetime.date(2014, 2, 1)
+
+
 def test_float_amounts_rejected():
     rate = rates.monthly_rate("USD", YearMonth(2025, 8))
     with pytest.raises(TypeError, match="float"):
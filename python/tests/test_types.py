@@ -46,6 +46,9 @@ def test_currency():
     assert str(Currency.GBP) == "GBP"
     assert repr(Currency.GBP) == "Currency('GBP')"
     assert len({Currency.GBP, Currency.GBP}) == 1
+    assert Currency(" gbp ") == Currency.GBP
+    with pytest.raises(ValueError):
+        Currency("US DOLLARS")
 
 
 def test_rate_type_members():
@@ -1,18 +1,28 @@
 #!/usr/bin/env python3
 """Download new HMRC exchange rate files from the Trade Tariff API into data/.
 
-Usage: update_rates.py [--data-dir data] [--verbose]
+Usage: update_rates.py [--data-dir data] [--verbose] [--check]
 
-Emits GitHub Actions outputs on stdout: has-new, release-body, downloaded, skipped, failed.
+Before downloading, renames files saved under HMRC's own names (exrates-monthly-0825.xml,
+monthly_xml_2025-8.xml) to the canonical YYYY-MM.ext. Every download is validated
+before it is written. --check only renames and validates the local files, without network
+access, and exits non-zero if any is invalid; forks keeping private data dirs can run it in CI.
+
+Emits GitHub Actions outputs on stdout:
+has-new, release-body, downloaded, skipped, failed, normalized, invalid.
 """
 
 import argparse
 import calendar
+import csv
 import datetime
+import decimal
+import io
 import json
 import pathlib
 import re
 import sys
+import xml.etree.ElementTree as ET
 import urllib.error
 import urllib.request
 
@@ -28,6 +38,15 @@ SERIES = {
     "spot": ("csv", "spot"),
 }
 FILE_RE = re.compile(r"/(monthly|average|spot)_(xml|csv)_(\d{4})-(\d{1,2})\.(xml|csv)$")
+CANONICAL_RE = re.compile(r"^(\d{4})-(\d{2})\.(xml|csv)$")
+# Names files arrive under when saved by hand; each yields (year, month)
+DOWNLOAD_NAMES = [
+    (re.compile(r"^exrates[-_]monthly[-_](\d{2})(\d{2})\.(xml|csv)$", re.I),
+     lambda m: (2000 + int(m.group(2)), int(m.group(1)))),
+    (re.compile(r"^(?:monthly|average|spot)_(?:xml|csv)_(\d{4})-(\d{1,2})\.(xml|csv)$", re.I),
+     lambda m: (int(m.group(1)), int(m.group(2)))),
+    (re.compile(r"^(\d{4})-(\d{1})\.(xml|csv)$"), lambda m: (int(m.group(1)), int(m.group(2)))),
+]
 
 
 def fetch(url: str) -> bytes | None:
@@ -82,9 +101,109 @@ def discover_files(
     return found
 
 
-def looks_valid(payload: bytes, fmt: str) -> bool:
-    head = payload.lstrip()[:64]
-    return head.startswith(b"<?xml") if fmt == "xml" else b"," in head
+def positive(text: str | None) -> bool:
+    try:
+        return decimal.Decimal((text or "").strip().replace(",", "")) > 0
+    except decimal.InvalidOperation:
+        return False
+
+
+def invalid_xml(payload: bytes, year: int, month: int) -> str | None:
+    try:
+        root = ET.fromstring(payload)
+    except ET.ParseError as e:
+        return f"not XML: {e}"
+    if root.tag != "exchangeRateMonthList":
+        return f"unexpected root <{root.tag}>"
+    period = root.get("Period", "")
+    try:
+        start = datetime.datetime.strptime(period.split(" to ")[0].strip(), "%d/%b/%Y")
+    except ValueError:
+        return f"unreadable Period '{period}'"
+    if (start.year, start.month) != (year, month):
+        return f"Period '{period}' is not {year}-{month:02d}"
+    records = root.findall("exchangeRate")
+    if not records:
+        return "no exchangeRate records"
+    for i, record in enumerate(records, 1):
+        code = (record.findtext("currencyCode") or "").strip()
+        if not re.fullmatch(r"[A-Za-z]{3}", code):
+            return f"record {i}: invalid currencyCode '{code}'"
+        if not positive(record.findtext("rateNew")):
+            return f"record {i} ({code}): rateNew is not a positive number"
+    return None
+
+
+def invalid_csv(payload: bytes) -> str | None:
+    try:
+        text = payload.decode("utf-8-sig")
+    except UnicodeDecodeError:
+        text = payload.decode("cp1252", errors="replace")
+    rows = list(csv.reader(io.StringIO(text)))
+    if len(rows) < 2:
+        return "no rate rows"
+    header = [h.strip().lower() for h in rows[0]]
+    rate = next((i for i, h in enumerate(header) if "units per" in h), None)
+    code = next((i for i, h in enumerate(header) if h == "currency code"), None)
+    if rate is None or code is None:
+        return f"unexpected header {rows[0]}"
+    for line, row in enumerate(rows[1:], 2):
+        if not any(cell.strip() for cell in row):
+            continue
+        if len(row) <= max(rate, code) or not positive(row[rate]):
+            return f"line {line}: no positive units-per-pound rate"
+    return None
+
+
+def invalid(payload: bytes, fmt: str, year: int, month: int) -> str | None:
+    """Why the payload is not a usable rates file for the period, or None."""
+    return invalid_xml(payload, year, month) if fmt == "xml" else invalid_csv(payload)
+
+
+def normalize_names(target_dir: pathlib.Path, fmt: str, verbose: bool) -> tuple[int, int]:
+    """Rename download-named files to YYYY-MM.ext; (renamed, conflicting) counts."""
+    renamed = conflicts = 0
+    # A set: on case-insensitive filesystems both globs match the same files
+    for path in sorted(set(target_dir.glob(f"*.{fmt}")) | set(target_dir.glob(f"*.{fmt.upper()}"))):
+        if CANONICAL_RE.match(path.name):
+            continue
+        for pattern, period in DOWNLOAD_NAMES:
+            m = pattern.match(path.name)
+            if m:
+                break
+        else:
+            continue
+        year, month = period(m)
+        target = target_dir / f"{year}-{month:02d}.{fmt}"
+        if target.exists() and target.read_bytes() != path.read_bytes():
+            conflicts += 1
+            print(f"conflict: {path} differs from {target}", file=sys.stderr)
+            continue
+        if target.exists():
+            path.unlink()
+        else:
+            path.rename(target)
+        renamed += 1
+        if verbose:
+            print(f"renamed {path} -> {target}", file=sys.stderr)
+    return renamed, conflicts
+
+
+def check_series(rate_type: str, data_dir: pathlib.Path) -> int:
+    """Validate every local file of a series; the number of invalid ones."""
+    fmt, subdir = SERIES[rate_type]
+    bad = 0
+    for path in sorted((data_dir / subdir).glob(f"*.{fmt}")):
+        m = CANONICAL_RE.match(path.name)
+        if not m:
+            bad += 1
+            print(f"invalid {path}: not named YYYY-MM.{fmt}", file=sys.stderr)
+            continue
+        reason = invalid(path.read_bytes(), fmt, int(m.group(1)), int(m.group(2)))
+        if reason:
+            bad += 1
+            print(f"invalid {path}: {reason}", file=sys.stderr)
+    return bad
 
 
 def update_series(rate_type: str, data_dir: pathlib.Path, verbose: bool):
@@ -102,10 +221,11 @@ def update_series(rate_type: str, data_dir: pathlib.Path, verbose: bool):
             skipped += 1
             continue
         payload = fetch(HOST + path)
-        if payload is None or not looks_valid(payload, fmt):
+        reason = "download failed" if payload is None else invalid(payload, fmt, year, month)
+        if reason:
             failed += 1
             if verbose:
-                print(f"failed {rate_type} {year}-{month:02d}", file=sys.stderr)
+                print(f"failed {rate_type} {year}-{month:02d}: {reason}", file=sys.stderr)
             continue
         if target.exists() and target.read_bytes() == payload:
             skipped += 1
@@ -128,8 +248,21 @@ def main() -> None:
     parser = argparse.ArgumentParser()
     parser.add_argument("--data-dir", type=pathlib.Path, default=pathlib.Path("data"))
     parser.add_argument("--verbose", action="store_true")
+    parser.add_argument("--check", action="store_true", help="validate local files only")
     args = parser.parse_args()
 
+    normalized = conflicts = 0
+    for fmt, subdir in SERIES.values():
+        if (args.data_dir / subdir).is_dir():
+            r, c = normalize_names(args.data_dir / subdir, fmt, args.verbose)
+            normalized += r
+            conflicts += c
+    if args.check:
+        bad = conflicts + sum(check_series(rate_type, args.data_dir) for rate_type in SERIES)
+        print(f"normalized={normalized}")
+        print(f"invalid={bad}")
+        sys.exit(1 if bad else 0)
+
     downloaded, skipped, failed = [], 0, 0
     for rate_type in SERIES:
         d, s, f = update_series(rate_type, args.data_dir, args.verbose)
@@ -142,6 +275,8 @@ def main() -> None:
     print(f"downloaded={len(downloaded)}")
     print(f"skipped={skipped}")
     print(f"failed={failed}")
+    print(f"normalized={normalized}")
+    print(f"invalid={conflicts}")
 
 
 if __name__ == "__main__":
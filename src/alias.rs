@@ -0,0 +1,107 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::types::Currency;
+
+/// Other names for currency codes, resolved before every lookup in a [`Rates`](crate::Rates).
+///
+/// Imported bank feeds and spreadsheets often say `RMB` or `NTD` where HMRC
+/// publishes `CNY` and `TWD`. Aliases match case-insensitively, ignoring
+/// surrounding whitespace; the [`Rate`](crate::Rate) found still names the
+/// published currency, so the substitution stays visible.
+/// A [`Rates`](crate::Rates) starts with none, see [`Rates::set_aliases`](crate::Rates::set_aliases).
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Aliases, Rates, YearMonth};
+///
+/// let mut rates = Rates::new();
+/// rates.set_aliases(Aliases::common().with("Greenback", "USD".parse().unwrap()));
+/// let aug = YearMonth::new(2025, 8).unwrap();
+/// assert_eq!(rates.monthly_rate("rmb", aug)?.currency().as_ref(), "CNY");
+/// assert_eq!(rates.monthly_rate("greenback", aug)?, rates.monthly_rate("USD", aug)?);
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Aliases {
+    names: Vec<(Box<str>, Currency)>, // sorted by uppercase name
+}
+
+/// The [`Aliases::common`] set: informal and superseded labels for published codes.
+const COMMON: &[(&str, [u8; 3])] = &[
+    ("EURO", *b"EUR"),
+    ("NIS", *b"ILS"), // new Israeli shekel
+    ("NTD", *b"TWD"), // New Taiwan dollar
+    ("RMB", *b"CNY"), // renminbi
+    ("RUR", *b"RUB"), // pre-1998 rouble code
+    ("STG", *b"GBP"), // sterling
+    ("STN", *b"STD"), // ISO's code for the dobra HMRC lists as STD
+    ("UKP", *b"GBP"),
+    ("YEN", *b"JPY"),
+    ("YUAN", *b"CNY"),
+];
+
+impl Aliases {
+    /// No aliases.
+    pub const fn new() -> Aliases {
+        Aliases { names: Vec::new() }
+    }
+
+    /// Common informal labels: `RMB`, `YUAN` → `CNY`, `NTD` → `TWD`, `NIS` → `ILS`,
+    /// `STG`, `UKP` → `GBP`, `EURO` → `EUR`, `YEN` → `JPY`,
+    /// and the superseded `RUR` → `RUB` and `STN` → `STD`.
+    pub fn common() -> Aliases {
+        COMMON
+            .iter()
+            .fold(Aliases::new(), |aliases, &(name, code)| {
+                aliases.with(name, Currency::from_code(code))
+            })
+    }
+
+    /// Makes `name` resolve to `currency`, replacing any earlier alias for it.
+    pub fn insert(&mut self, name: &str, currency: Currency) {
+        let name: Box<str> = name.trim().to_ascii_uppercase().into();
+        match self.names.binary_search_by(|(n, _)| n.cmp(&name)) {
+            Ok(i) => self.names[i].1 = currency,
+            Err(i) => self.names.insert(i, (name, currency)),
+        }
+    }
+
+    /// [`Aliases::insert`] as a builder step.
+    pub fn with(mut self, name: &str, currency: Currency) -> Aliases {
+        self.insert(name, currency);
+        self
+    }
+
+    /// Removes the alias `name`, returning its currency.
+    pub fn remove(&mut self, name: &str) -> Option<Currency> {
+        let i = self.position(name)?;
+        Some(self.names.remove(i).1)
+    }
+
+    /// The currency `name` stands for, if it is an alias.
+    pub fn resolve(&self, name: &str) -> Option<Currency> {
+        Some(self.names[self.position(name)?].1)
+    }
+
+    /// Every alias and its currency, ascending by (uppercase) name.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, Currency)> {
+        self.names
+            .iter()
+            .map(|(name, currency)| (&**name, *currency))
+    }
+
+    /// `true` if there are no aliases.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    // Case-insensitive without allocating: lookups stay allocation-free
+    fn position(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        self.names
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+    }
+}
@@ -1,7 +1,7 @@
 //! Small CLI over the library: convert amounts, look up rates, list coverage.
 use chrono::NaiveDate;
 use clap::{Parser, Subcommand, ValueEnum};
-use hmrc_rates::{RateType, Rates, Updater, YearEnd, YearMonth};
+use hmrc_rates::{CsvConverter, RateType, Rates, TableFormat, Updater, YearEnd, YearMonth};
 use rust_decimal::Decimal;
 
 #[derive(Parser)]
@@ -31,15 +31,58 @@ enum Command {
         #[arg(long, value_enum, default_value_t = Series::Monthly)]
         r#type: Series,
     },
+    /// Print a month's monthly rates as a table of currency, country and rate.
+    Rates {
+        month: YearMonth,
+        /// A Markdown table instead of a boxed one.
+        #[arg(long)]
+        markdown: bool,
+    },
     /// List the published periods of a series.
     List {
         #[arg(value_enum)]
         r#type: Series,
     },
-    /// List every currency a series has ever quoted.
+    /// List every currency a series has ever quoted, or one month's currencies.
     Currencies {
-        #[arg(value_enum)]
+        #[arg(value_enum, default_value_t = Series::Monthly)]
         r#type: Series,
+        /// Only the currencies in this month's monthly table (YYYY-MM).
+        #[arg(long)]
+        month: Option<YearMonth>,
+    },
+    /// Append GBP amounts and the rates used to a CSV of transactions.
+    ConvertCsv {
+        /// Input CSV; `-` reads standard input.
+        input: std::path::PathBuf,
+        /// Output CSV; standard output if omitted.
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+        #[arg(long, default_value = "amount")]
+        amount_column: String,
+        #[arg(long, default_value = "currency")]
+        currency_column: String,
+        #[arg(long, default_value = "date")]
+        date_column: String,
+        /// chrono format of the dates, e.g. %d/%m/%Y.
+        #[arg(long, default_value = "%Y-%m-%d")]
+        date_format: String,
+    },
+    /// Read headerless `amount,currency,date` lines from standard input and write each
+    /// to standard output as it is converted, with the GBP amount and rate appended.
+    Pipe {
+        /// Tab-separated lines instead of comma-separated.
+        #[arg(long)]
+        tsv: bool,
+        /// chrono format of the dates, e.g. %d/%m/%Y.
+        #[arg(long, default_value = "%Y-%m-%d")]
+        date_format: String,
+    },
+    /// Serve the JSON API: /convert, /rates/{month} and /currencies.
+    #[cfg(feature = "serve")]
+    Serve {
+        #[arg(default_value = "127.0.0.1:8080")]
+        addr: std::net::SocketAddr,
     },
 }
 
@@ -107,17 +150,83 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 rate.period()
             );
         }
+        Command::Rates { month, markdown } => {
+            let format = match markdown {
+                true => TableFormat::Markdown,
+                false => TableFormat::Ascii,
+            };
+            print!("{}", rates.format_month(month, format)?);
+        }
         Command::List { r#type } => match r#type {
             Series::Monthly => rates.months().for_each(|m| println!("{m}")),
             Series::Spot => rates.spot_periods().for_each(|p| println!("{p}")),
             Series::Average => rates.average_periods().for_each(|p| println!("{p}")),
             Series::Weekly => rates.weeks().for_each(|w| println!("{w}")),
         },
-        Command::Currencies { r#type } => {
+        Command::Currencies {
+            r#type,
+            month: Some(year_month),
+        } => {
+            if !matches!(r#type, Series::Monthly) {
+                return Err("--month applies to the monthly series only".into());
+            }
+            rates
+                .monthly(year_month)?
+                .currencies()
+                .for_each(|c| println!("{c}"));
+        }
+        Command::Currencies {
+            r#type,
+            month: None,
+        } => {
             rates
                 .currencies(r#type.into())
                 .for_each(|c| println!("{c}"));
         }
+        Command::ConvertCsv {
+            input,
+            output,
+            amount_column,
+            currency_column,
+            date_column,
+            date_format,
+        } => {
+            let converter = CsvConverter::new()
+                .amount_column(amount_column)
+                .currency_column(currency_column)
+                .date_column(date_column)
+                .date_format(date_format);
+            let reader: Box<dyn std::io::Read> = if input.as_os_str() == "-" {
+                Box::new(std::io::stdin().lock())
+            } else {
+                Box::new(std::fs::File::open(&input)?)
+            };
+            let writer: Box<dyn std::io::Write> = match output {
+                Some(path) => Box::new(std::fs::File::create(path)?),
+                None => Box::new(std::io::stdout().lock()),
+            };
+            let rows = converter.convert(&rates, reader, writer)?;
+            eprintln!("converted {rows} rows");
+        }
+        Command::Pipe { tsv, date_format } => {
+            let converter = CsvConverter::new()
+                .headerless()
+                .streaming()
+                .delimiter(if tsv { b'\t' } else { b',' })
+                .date_format(date_format);
+            converter.convert(&rates, std::io::stdin().lock(), std::io::stdout().lock())?;
+        }
+        #[cfg(feature = "serve")]
+        Command::Serve { addr } => {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_io()
+                .build()?;
+            runtime.block_on(async {
+                let listener = tokio::net::TcpListener::bind(addr).await?;
+                eprintln!("serving HMRC rates on http://{addr}");
+                hmrc_rates::serve::serve(listener, rates).await
+            })?;
+        }
     }
     Ok(())
 }
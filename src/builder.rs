@@ -0,0 +1,146 @@
+use crate::alias::Aliases;
+use crate::conversion::Conversion;
+use crate::rates::{DuplicateRates, LookupPolicy, NegativeAmounts, Rates};
+use crate::redenom::Redenomination;
+
+/// Assembles a [`Rates`]: where the data comes from, then how lookups behave.
+///
+/// Data steps apply in call order, each over the last: the bundle (or nothing without
+/// `bundled`), then directories and readers with `load`, or any prepared [`Rates`].
+/// [`RatesBuilder::duplicate_rates`] governs the loads after it and stays with the result.
+/// Other settings apply to the result whatever order they come in.
+/// Loading steps fail as their [`Rates`] counterparts do, so chain them with `?`.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Aliases, LookupPolicy, Rates, YearMonth};
+///
+/// let rates = Rates::builder()
+///     .aliases(Aliases::common())
+///     .lookup_policy(LookupPolicy::Earlier(1))
+///     .build();
+/// let next = rates.months().next_back().unwrap().next();
+/// let yuan = rates.monthly_rate("RMB", next)?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+#[must_use]
+pub struct RatesBuilder {
+    data: Rates,
+    settings: Rates,
+}
+
+impl Rates {
+    /// A [`RatesBuilder`] starting from the bundled data, or from no data without `bundled`.
+    pub fn builder() -> RatesBuilder {
+        #[cfg(feature = "bundled")]
+        let data = Rates::new();
+        #[cfg(not(feature = "bundled"))]
+        let data = Rates::empty();
+        RatesBuilder {
+            data,
+            settings: Rates::empty(),
+        }
+    }
+}
+
+impl RatesBuilder {
+    /// Drops the data so far, e.g. to use only files you supply.
+    pub fn empty(self) -> RatesBuilder {
+        self.data(Rates::empty())
+    }
+
+    /// Replaces the data so far with `rates`' data, such as a snapshot
+    /// or `Updater::refreshed` with `http`; its settings are not kept.
+    pub fn data(mut self, rates: Rates) -> RatesBuilder {
+        self.data = rates.with_duplicate_rates(self.data.duplicate_rates());
+        self
+    }
+
+    /// How the loading steps after this one resolve a currency repeated at different
+    /// rates, see [`Rates::set_duplicate_rates`]; loads before it are not revisited.
+    pub fn duplicate_rates(mut self, duplicates: DuplicateRates) -> RatesBuilder {
+        self.data.set_duplicate_rates(duplicates);
+        self
+    }
+
+    /// Adds a directory of HMRC monthly downloads, see [`Rates::load_dir`].
+    #[cfg(feature = "load")]
+    pub fn dir(
+        mut self,
+        dir: impl AsRef<std::path::Path>,
+    ) -> Result<RatesBuilder, crate::LoadError> {
+        self.data.load_dir(dir)?;
+        Ok(self)
+    }
+
+    /// Adds one HMRC monthly XML document, see [`Rates::load_reader`].
+    #[cfg(feature = "load")]
+    pub fn reader(mut self, reader: impl std::io::Read) -> Result<RatesBuilder, crate::LoadError> {
+        self.data.load_reader(reader)?;
+        Ok(self)
+    }
+
+    /// Replaces the data so far with the bundle, cache and new periods `updater` fetches,
+    /// see [`Updater::refreshed`](crate::Updater::refreshed).
+    #[cfg(feature = "http")]
+    pub fn fetched(self, updater: &crate::Updater) -> Result<RatesBuilder, crate::FetchError> {
+        let refreshed = updater.refreshed()?;
+        Ok(self.data(refreshed))
+    }
+
+    /// The currency aliases, see [`Rates::set_aliases`].
+    pub fn aliases(mut self, aliases: Aliases) -> RatesBuilder {
+        self.settings.set_aliases(aliases);
+        self
+    }
+
+    /// The redenominations, see [`Rates::set_redenominations`].
+    pub fn redenominations(
+        mut self,
+        redenominations: impl IntoIterator<Item = Redenomination>,
+    ) -> RatesBuilder {
+        self.settings.set_redenominations(redenominations);
+        self
+    }
+
+    /// How unpublished months resolve, and how far a fallback may go;
+    /// see [`Rates::set_lookup_policy`].
+    pub fn lookup_policy(mut self, policy: LookupPolicy) -> RatesBuilder {
+        self.settings.set_lookup_policy(policy);
+        self
+    }
+
+    /// What conversions do with a negative amount, see [`Rates::set_negative_amounts`].
+    pub fn negative_amounts(mut self, negatives: NegativeAmounts) -> RatesBuilder {
+        self.settings.set_negative_amounts(negatives);
+        self
+    }
+
+    /// A conversion observer, see [`Rates::set_observer`].
+    pub fn observer(
+        mut self,
+        observer: impl Fn(&Conversion) + Send + Sync + 'static,
+    ) -> RatesBuilder {
+        self.settings.set_observer(observer);
+        self
+    }
+
+    /// The configured [`Rates`].
+    pub fn build(self) -> Rates {
+        self.data.with_settings_of(&self.settings)
+    }
+}
+
+impl core::fmt::Debug for RatesBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RatesBuilder")
+            .field("data", &self.data)
+            .field("aliases", self.settings.aliases())
+            .field("redenominations", &self.settings.redenominations())
+            .field("lookup_policy", &self.settings.lookup_policy())
+            .field("negative_amounts", &self.settings.negative_amounts())
+            .field("duplicate_rates", &self.data.duplicate_rates())
+            .finish()
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, NaiveDate, TimeZone};
+
+use crate::error::ConvertError;
+use crate::gbp::Gbp;
+use crate::rates::Rates;
+
+/// Where "today" comes from, see [`Rates::convert_today_with`].
+///
+/// [`SystemClock`] reads the system time; tests pin a date with [`FixedClock`].
+pub trait Clock {
+    /// The current date.
+    fn today(&self) -> NaiveDate;
+}
+
+/// Today's UTC date from the system clock.
+///
+/// UTC, not UK time: for the hour after midnight in British Summer Time
+/// it still says yesterday, which matters on the first of a month.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        chrono::Utc::now().date_naive()
+    }
+}
+
+/// A clock stopped at one date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedClock(pub NaiveDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.0
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn today(&self) -> NaiveDate {
+        (**self).today()
+    }
+}
+
+impl Rates {
+    /// Converts `amount` to GBP at this month's monthly rate, exactly.
+    ///
+    /// Strict like [`Rates::monthly_rate`]: fails if the current month is not loaded,
+    /// e.g. bundled data older than today. See [`SystemClock`] for which date counts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let gbp = Rates::new().convert_today(Decimal::from(100), "USD")?;
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn convert_today(
+        &self,
+        amount: rust_decimal::Decimal,
+        code: impl AsRef<str>,
+    ) -> Result<Gbp, ConvertError> {
+        self.convert_today_with(amount, code, SystemClock)
+    }
+
+    /// [`Rates::convert_today`] with "today" from `clock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hmrc_rates::{FixedClock, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let clock = FixedClock(NaiveDate::from_ymd_opt(2025, 8, 15).unwrap());
+    /// let gbp = rates.convert_today_with(Decimal::from(100), "USD", clock)?;
+    /// let rate = rates.monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?;
+    /// assert_eq!(gbp.amount(), rate.to_gbp(Decimal::from(100)));
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_today_with(
+        &self,
+        amount: rust_decimal::Decimal,
+        code: impl AsRef<str>,
+        clock: impl Clock,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.monthly_rate(code, clock.today())?;
+        Ok(self.converted(amount, rate)?.gbp())
+    }
+
+    /// Converts `amount` to GBP at the monthly rate for the day `datetime` falls on
+    /// in its own time zone, exactly.
+    ///
+    /// Give the timestamp in the zone you report in: 23:30 UTC on 31 July is already
+    /// 1 August in London during British Summer Time, so August's rate applies.
+    /// [`DateTime::with_timezone`] moves a timestamp there, e.g. to
+    /// `chrono_tz::Europe::London`. The month resolves by [`Rates::lookup_policy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use hmrc_rates::{Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let bst = FixedOffset::east_opt(3600).unwrap();
+    /// let paid = Utc.with_ymd_and_hms(2025, 7, 31, 23, 30, 0).unwrap();
+    /// let gbp = rates.convert_at(Decimal::from(100), "USD", paid.with_timezone(&bst))?;
+    /// let august = rates.monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?;
+    /// assert_eq!(gbp.amount(), august.to_gbp(Decimal::from(100)));
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_at<Tz: TimeZone>(
+        &self,
+        amount: rust_decimal::Decimal,
+        code: impl AsRef<str>,
+        datetime: DateTime<Tz>,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.monthly_rate(code, datetime.date_naive())?;
+        Ok(self.converted(amount, rate)?.gbp())
+    }
+}
@@ -0,0 +1,78 @@
+use rust_decimal::Decimal;
+
+use crate::gbp::Gbp;
+use crate::rate::Rate;
+use crate::types::{Currency, Period};
+
+/// One conversion to GBP with everything needed to reproduce it.
+///
+/// Keeps the input amount, the rate used (with its currency and period)
+/// and the exact result, so tax software can justify every figure.
+/// No rounding is applied.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Period, Rates, YearMonth};
+/// use rust_decimal::Decimal;
+///
+/// let rates = Rates::new();
+/// let month = YearMonth::new(2025, 8).unwrap();
+/// let conversion = rates.convert_detailed(Decimal::from(100), "USD", month)?;
+/// assert_eq!(conversion.period(), Period::YearMonth(month));
+/// let exact = conversion.amount() / conversion.rate().units_per_gbp();
+/// assert_eq!(conversion.gbp().amount(), exact);
+/// # Ok::<(), hmrc_rates::ConvertError>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conversion {
+    amount: Decimal,
+    rate: Rate,
+    gbp: Gbp,
+}
+
+impl Conversion {
+    /// Converts `amount`, given in `rate`'s currency, to GBP.
+    ///
+    /// # Panics
+    ///
+    /// If the result does not fit a `Decimal`; see [`Rate::checked_to_gbp`].
+    pub fn new(amount: Decimal, rate: Rate) -> Conversion {
+        Conversion::with_gbp(amount, rate, rate.to_gbp(amount))
+    }
+
+    /// A conversion whose result, `gbp`, the caller has already worked out.
+    pub(crate) fn with_gbp(amount: Decimal, rate: Rate, gbp: Decimal) -> Conversion {
+        Conversion {
+            amount,
+            rate,
+            gbp: Gbp::new(gbp),
+        }
+    }
+
+    /// The input amount, in [`Conversion::currency`].
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// The rate the conversion used.
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+
+    /// The currency of the input amount.
+    pub fn currency(&self) -> Currency {
+        self.rate.currency()
+    }
+
+    /// The HMRC period of the rate used.
+    pub fn period(&self) -> Period {
+        self.rate.period()
+    }
+
+    /// The exact, unrounded GBP result.
+    pub fn gbp(&self) -> Gbp {
+        self.gbp
+    }
+}
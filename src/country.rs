@@ -0,0 +1,52 @@
+use crate::store::CountryRow;
+use crate::types::Currency;
+
+/// A country as an HMRC monthly file lists it, with the currency it uses.
+///
+/// Several countries can share one currency (EUR, XOF, ...).
+/// Codes are HMRC's own two letters: mostly ISO 3166, but not always
+/// (`"DH"` is Abu Dhabi).
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Rates, YearMonth};
+///
+/// let rates = Rates::new();
+/// let month = YearMonth::new(2025, 8).unwrap();
+/// let japan = rates.country("JP", month).unwrap();
+/// assert_eq!(japan.name(), "Japan");
+/// assert_eq!(japan.currency().as_str(), "JPY");
+/// let yen = rates.country_rate("jp", month)?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Country<'a> {
+    row: &'a CountryRow,
+}
+
+impl<'a> Country<'a> {
+    pub(crate) fn new(row: &'a CountryRow) -> Country<'a> {
+        Country { row }
+    }
+
+    /// HMRC's country code, e.g. `"JP"`.
+    pub fn code(&self) -> &'a str {
+        &self.row.code
+    }
+
+    /// The country name, e.g. `"Japan"`.
+    pub fn name(&self) -> &'a str {
+        &self.row.name
+    }
+
+    /// The currency the country's rate is published in.
+    pub fn currency(&self) -> Currency {
+        Currency::from_code(self.row.currency)
+    }
+
+    /// The currency's name as HMRC gives it, e.g. `"Yen"`.
+    pub fn currency_name(&self) -> &'a str {
+        &self.row.currency_name
+    }
+}
@@ -1,7 +1,6 @@
 // Compiled as parse.rs's submodule (shared with build.rs).
 
 /// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
-#[allow(dead_code)] // used by the build.rs instantiation only
 pub fn days_from_civil(year: i32, month: u32, day: u32) -> i32 {
     let y = i64::from(year) - i64::from(month <= 2);
     let era = if y >= 0 { y } else { y - 399 } / 400;
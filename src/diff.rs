@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use rust_decimal::Decimal;
+
+use crate::error::LookupError;
+use crate::rates::Rates;
+use crate::types::{Currency, YearMonth};
+
+/// How the monthly table changed between two months, see [`Rates::diff_months`].
+///
+/// Every list is ascending by currency; unchanged rates are left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthDiff {
+    from: YearMonth,
+    to: YearMonth,
+    added: Vec<(Currency, Decimal)>,
+    removed: Vec<(Currency, Decimal)>,
+    changed: Vec<RateChange>,
+}
+
+impl MonthDiff {
+    /// The month compared from.
+    pub fn from(&self) -> YearMonth {
+        self.from
+    }
+
+    /// The month compared to.
+    pub fn to(&self) -> YearMonth {
+        self.to
+    }
+
+    /// Currencies only in [`MonthDiff::to`], with their new rate.
+    pub fn added(&self) -> &[(Currency, Decimal)] {
+        &self.added
+    }
+
+    /// Currencies only in [`MonthDiff::from`], with their last rate.
+    pub fn removed(&self) -> &[(Currency, Decimal)] {
+        &self.removed
+    }
+
+    /// Currencies in both months whose rate moved.
+    pub fn changed(&self) -> &[RateChange] {
+        &self.changed
+    }
+
+    /// `true` if both months hold the same table.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// One currency's rate in both months of a [`MonthDiff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RateChange {
+    currency: Currency,
+    old_rate: Decimal,
+    new_rate: Decimal,
+}
+
+impl RateChange {
+    /// The currency that moved.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Units per £1 in the earlier month of the diff.
+    pub fn old_rate(&self) -> Decimal {
+        self.old_rate
+    }
+
+    /// Units per £1 in the later month of the diff.
+    pub fn new_rate(&self) -> Decimal {
+        self.new_rate
+    }
+
+    /// The move as a percentage of the old rate, e.g. `2.5` for 1.20 to 1.23.
+    ///
+    /// Unrounded, to `Decimal`'s 28 significant digits.
+    /// A rising rate means sterling buys more of the currency.
+    pub fn percent_change(&self) -> Decimal {
+        (self.new_rate - self.old_rate) / self.old_rate * Decimal::ONE_HUNDRED
+    }
+}
+
+impl Rates {
+    /// Currencies added, removed and re-rated from the monthly table for `from`
+    /// to the one for `to`.
+    ///
+    /// Both months must be published, otherwise their lookup error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// let diff = rates.diff_months(aug.prev(), aug)?;
+    /// for change in diff.changed() {
+    ///     println!("{}: {} -> {} ({:.2}%)", change.currency(), change.old_rate(), change.new_rate(), change.percent_change());
+    /// }
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn diff_months(
+        &self,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+    ) -> Result<MonthDiff, LookupError> {
+        let (from, to) = (from.into(), to.into());
+        let (before, after) = (self.monthly(from)?, self.monthly(to)?);
+        let mut diff = MonthDiff {
+            from,
+            to,
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        };
+        for (currency, rate) in before.iter() {
+            let old = rate.units_per_gbp();
+            match after.get(currency) {
+                None => diff.removed.push((currency, old)),
+                Some(next) if next.units_per_gbp() != old => diff.changed.push(RateChange {
+                    currency,
+                    old_rate: old,
+                    new_rate: next.units_per_gbp(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for (currency, rate) in after.iter() {
+            if before.get(currency).is_none() {
+                diff.added.push((currency, rate.units_per_gbp()));
+            }
+        }
+        Ok(diff)
+    }
+}
@@ -1,23 +1,47 @@
 use alloc::boxed::Box;
 
+use rust_decimal::Decimal;
+
 use crate::types::{Currency, Period, RateType};
 
 /// Why a rate lookup failed.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[non_exhaustive]
 pub enum LookupError {
-    /// The code never appears anywhere in this rate series (or is not a valid code).
-    #[error("currency '{code}' is not published in HMRC {table} rates")]
-    UnknownCurrency { code: Box<str>, table: RateType },
+    /// The code never appears anywhere in this rate series.
+    ///
+    /// `suggestions` holds published codes one edit away, e.g. `EUR` for `"EUT"`.
+    #[error("currency '{code}' is not published in HMRC {table} rates{}", did_you_mean(.suggestions))]
+    UnknownCurrency {
+        code: Box<str>,
+        table: RateType,
+        suggestions: Box<[Currency]>,
+    },
 
-    /// The series has no table for this period; `available` gives the loaded range.
-    #[error("no HMRC {table} rates for {period}{}", available_range(.available))]
+    /// The input, after aliases, is not three ASCII letters, so it cannot be a code at all.
+    /// Checked before any table is consulted.
+    ///
+    /// `suggestions` holds published codes one edit away, e.g. `EUR` for `"EURO"`.
+    #[error("'{code}' is not a three-letter currency code{}", did_you_mean(.suggestions))]
+    InvalidCurrencyCode {
+        code: Box<str>,
+        suggestions: Box<[Currency]>,
+    },
+
+    /// The series has no table for this period; `available` gives the loaded range
+    /// and `nearest` the published period closest to the one asked for.
+    #[error("no HMRC {table} rates for {period}{}", available_range(.available, .nearest))]
     PeriodNotAvailable {
         table: RateType,
         period: Period,
         available: Option<(Period, Period)>,
+        nearest: Option<Period>,
     },
 
+    /// No country with this code is listed for the period.
+    #[error("no country '{code}' in the HMRC rates for {period}")]
+    UnknownCountry { code: Box<str>, period: Period },
+
     /// The period exists but this currency is absent from it.
     #[error("'{currency}' has no HMRC {table} rate for {period}")]
     NotInPeriod {
@@ -27,10 +51,164 @@ pub enum LookupError {
     },
 }
 
-fn available_range(available: &Option<(Period, Period)>) -> alloc::string::String {
+impl LookupError {
+    /// A stable name for the kind of failure, e.g. `"unknown_currency"`.
+    ///
+    /// Messages may be reworded between releases; these codes are not,
+    /// so match or log on them rather than on the text.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LookupError::UnknownCurrency { .. } => "unknown_currency",
+            LookupError::InvalidCurrencyCode { .. } => "invalid_currency_code",
+            LookupError::PeriodNotAvailable { .. } => "period_not_available",
+            LookupError::UnknownCountry { .. } => "unknown_country",
+            LookupError::NotInPeriod { .. } => "not_in_period",
+        }
+    }
+}
+
+/// Two sources hold different tables for one period, see [`Rates::merge`](crate::Rates::merge).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("conflicting HMRC {table} rates for {period}")]
+pub struct MergeConflict {
+    /// The series of the clashing period.
+    pub table: RateType,
+    /// The clashing period.
+    pub period: Period,
+}
+
+/// A rate that [`Rates::insert_rate`](crate::Rates::insert_rate) cannot hold.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid HMRC rate {rate} for {currency}")]
+pub struct InvalidRate {
+    /// The currency the rate was for.
+    pub currency: Currency,
+    /// The rejected rate.
+    pub rate: rust_decimal::Decimal,
+}
+
+/// Why [`Gbp::as_pence`](crate::Gbp::as_pence) has no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum PenceError {
+    /// The amount has a fraction of a penny; round it first.
+    #[error("{amount} is not a whole number of pence")]
+    Fractional { amount: crate::gbp::Gbp },
+
+    /// The amount in pence does not fit an `i64`.
+    #[error("{amount} in pence does not fit an i64")]
+    OutOfRange { amount: crate::gbp::Gbp },
+}
+
+/// Why [`Rates::from_snapshot`](crate::Rates::from_snapshot) rejected its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// The input does not start with the snapshot header.
+    #[error("not an HMRC rates snapshot")]
+    NotASnapshot,
+
+    /// The snapshot was written in a format version this release cannot read.
+    #[error("unsupported snapshot version {version}")]
+    UnsupportedVersion { version: u16 },
+
+    /// The header is right but the body is truncated or inconsistent.
+    #[error("malformed snapshot: {reason}")]
+    Malformed { reason: &'static str },
+}
+
+/// Why a `checked_` conversion, such as [`Rate::checked_to_gbp`](crate::Rate::checked_to_gbp),
+/// has no result it can vouch for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// The result does not fit a `Decimal`.
+    #[error("converting {amount} overflows a Decimal")]
+    Overflow { amount: Decimal },
+
+    /// The result is inexact and too large to keep
+    /// [`Rate::GUARANTEED_PLACES`](crate::Rate::GUARANTEED_PLACES) decimal places.
+    #[error("converting {amount} loses precision: {result} keeps too few decimal places")]
+    PrecisionLoss { amount: Decimal, result: Decimal },
+}
+
+impl ArithmeticError {
+    /// A stable name for the kind of failure, see [`LookupError::code`].
+    pub fn code(&self) -> &'static str {
+        match self {
+            ArithmeticError::Overflow { .. } => "overflow",
+            ArithmeticError::PrecisionLoss { .. } => "precision_loss",
+        }
+    }
+
+    /// The same failure, reported against the caller's `amount` rather than an intermediate.
+    pub(crate) fn with_amount(self, amount: Decimal) -> ArithmeticError {
+        match self {
+            ArithmeticError::Overflow { .. } => ArithmeticError::Overflow { amount },
+            ArithmeticError::PrecisionLoss { result, .. } => {
+                ArithmeticError::PrecisionLoss { amount, result }
+            }
+        }
+    }
+}
+
+/// Why a conversion failed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ConvertError {
+    /// The input is not an amount followed by a currency code, e.g. `"1234.56 USD"`.
+    #[error("invalid amount '{input}', expected a number and a currency code like \"1234.56 USD\"")]
+    InvalidInput { input: Box<str> },
+
+    /// The input parsed, but the rate lookup failed.
+    #[error(transparent)]
+    Lookup(#[from] LookupError),
+
+    /// The rate was found, but the arithmetic overflowed or lost precision,
+    /// see [`Rates::checked_convert`](crate::Rates::checked_convert).
+    #[error(transparent)]
+    Arithmetic(#[from] ArithmeticError),
+
+    /// The amount is negative and [`NegativeAmounts::Reject`](crate::NegativeAmounts::Reject)
+    /// is in effect.
+    #[error("negative amount {amount} rejected, see Rates::set_negative_amounts")]
+    NegativeAmount { amount: Decimal },
+}
+
+impl ConvertError {
+    /// A stable name for the kind of failure, see [`LookupError::code`].
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConvertError::InvalidInput { .. } => "invalid_input",
+            ConvertError::Lookup(e) => e.code(),
+            ConvertError::Arithmetic(e) => e.code(),
+            ConvertError::NegativeAmount { .. } => "negative_amount",
+        }
+    }
+}
+
+fn did_you_mean(suggestions: &[Currency]) -> alloc::string::String {
+    use alloc::format;
+    match suggestions {
+        [] => alloc::string::String::new(),
+        [only] => format!(" — did you mean '{only}'?"),
+        [rest @ .., last] => {
+            let rest: alloc::vec::Vec<_> = rest.iter().map(|c| format!("'{c}'")).collect();
+            format!(" — did you mean {} or '{last}'?", rest.join(", "))
+        }
+    }
+}
+
+fn available_range(
+    available: &Option<(Period, Period)>,
+    nearest: &Option<Period>,
+) -> alloc::string::String {
     use alloc::format;
-    match available {
-        Some((first, last)) => format!(" (available {first} to {last})"),
-        None => alloc::string::String::from(" (no data loaded)"),
+    match (available, nearest) {
+        (Some((first, last)), Some(nearest)) => {
+            format!(" (available {first} to {last}; nearest is {nearest})")
+        }
+        (Some((first, last)), None) => format!(" (available {first} to {last})"),
+        (None, _) => alloc::string::String::from(" (no data loaded)"),
     }
 }
@@ -0,0 +1,224 @@
+//! Daily rates from other central banks, for comparison or as a fallback source.
+//!
+//! HMRC guidance accepts "a rate from a reputable source" in some cases.
+//! [`DailyRates`] reads the Bank of England's daily spot CSV and the ECB's
+//! euro reference rates, normalized to currency units per £1 like HMRC's own.
+
+use std::collections::BTreeMap;
+use std::io::{BufReader, Read};
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+
+use crate::load::LoadError;
+use crate::source::RateSource;
+use crate::types::{Currency, Period, YearMonth};
+
+/// Bank of England "into sterling" spot series, by the three letters after `XUDL`.
+const BOE_SERIES: [(&str, &str); 13] = [
+    ("ADS", "AUD"),
+    ("CDS", "CAD"),
+    ("DKS", "DKK"),
+    ("ERS", "EUR"),
+    ("HDS", "HKD"),
+    ("JYS", "JPY"),
+    ("NDS", "NZD"),
+    ("NKS", "NOK"),
+    ("SFS", "CHF"),
+    ("SGS", "SGD"),
+    ("SKS", "SEK"),
+    ("USS", "USD"),
+    ("ZRS", "ZAR"),
+];
+
+/// Daily rates per £1 from a central-bank feed, strictly by date.
+///
+/// As a [`RateSource`] each month is answered by its last published day,
+/// the month's closing rate; use [`DailyRates::units_per_gbp_on`] for a given day.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{RateSource, Rates, SourceChain, YearMonth};
+/// use hmrc_rates::feeds::DailyRates;
+///
+/// let csv = "DATE,XUDLUSS\n29 Aug 2025,1.3507\n";
+/// let boe = DailyRates::from_boe_csv(csv.as_bytes())?;
+/// let chain = SourceChain::new().with(Rates::new()).with(boe);
+/// let rate = chain.rate("USD", YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DailyRates {
+    days: BTreeMap<NaiveDate, BTreeMap<Currency, Decimal>>,
+}
+
+impl DailyRates {
+    /// A Bank of England database CSV export of `XUDL...` spot series.
+    ///
+    /// The first column is the date (`02 Jan 2025`), the rest are series,
+    /// each named by its BoE code or a plain ISO code. Blank cells are skipped.
+    pub fn from_boe_csv(reader: impl Read) -> Result<DailyRates, LoadError> {
+        let mut csv = csv::Reader::from_reader(reader);
+        let currencies = csv
+            .headers()
+            .map_err(|e| bad(format!("bad CSV: {e}")))?
+            .iter()
+            .skip(1)
+            .map(boe_currency)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut rates = DailyRates::default();
+        for record in csv.records() {
+            let record = record.map_err(|e| bad(format!("bad CSV row: {e}")))?;
+            let mut cells = record.iter();
+            let day = cells.next().unwrap_or_default().trim();
+            if day.is_empty() {
+                continue;
+            }
+            let date = NaiveDate::parse_from_str(day, "%d %b %Y")
+                .map_err(|_| bad(format!("bad date {day:?}")))?;
+            let table = rates.days.entry(date).or_default();
+            for (currency, cell) in currencies.iter().zip(cells) {
+                let cell = cell.trim();
+                if !cell.is_empty() {
+                    table.insert(*currency, positive(cell)?);
+                }
+            }
+        }
+        Ok(rates)
+    }
+
+    /// An ECB euro reference-rate XML file (`eurofxref-hist.xml` and friends).
+    ///
+    /// The ECB quotes per euro; each day is re-based on its GBP quote,
+    /// so days without one are dropped. EUR itself is included.
+    /// A quote whose re-based rate does not fit a `Decimal` is bad data.
+    pub fn from_ecb_xml(reader: impl Read) -> Result<DailyRates, LoadError> {
+        use quick_xml::events::Event;
+
+        let mut xml = quick_xml::Reader::from_reader(BufReader::new(reader));
+        let mut buf = Vec::new();
+        let mut per_eur: BTreeMap<NaiveDate, BTreeMap<Currency, Decimal>> = BTreeMap::new();
+        let mut date = None;
+        loop {
+            buf.clear();
+            let event = xml
+                .read_event_into(&mut buf)
+                .map_err(|e| bad(format!("XML error: {e}")))?;
+            let cube = match &event {
+                Event::Start(el) | Event::Empty(el) if el.local_name().as_ref() == b"Cube" => el,
+                Event::Eof => break,
+                _ => continue,
+            };
+            let (mut time, mut currency, mut rate) = (None, None, None);
+            for attr in cube.attributes() {
+                let attr = attr.map_err(|e| bad(format!("bad attribute: {e}")))?;
+                let value = attr
+                    .unescape_value()
+                    .map_err(|e| bad(format!("bad attribute: {e}")))?
+                    .into_owned();
+                match attr.key.as_ref() {
+                    b"time" => time = Some(value),
+                    b"currency" => currency = Some(value),
+                    b"rate" => rate = Some(value),
+                    _ => {}
+                }
+            }
+            if let Some(time) = time {
+                let day = time
+                    .parse::<NaiveDate>()
+                    .map_err(|_| bad(format!("bad time {time:?}")))?;
+                date = Some(day);
+            }
+            if let (Some(currency), Some(rate)) = (currency, rate) {
+                let day = date.ok_or_else(|| bad("rate outside a dated Cube".into()))?;
+                let currency = currency
+                    .parse()
+                    .map_err(|_| bad(format!("bad currency {currency:?}")))?;
+                per_eur
+                    .entry(day)
+                    .or_default()
+                    .insert(currency, positive(&rate)?);
+            }
+        }
+
+        let mut rates = DailyRates::default();
+        for (day, quotes) in per_eur {
+            let Some(&gbp) = quotes.get(&Currency::GBP) else {
+                continue;
+            };
+            let rebase = |per_eur: Decimal| {
+                per_eur
+                    .checked_div(gbp)
+                    .filter(|rate| *rate > Decimal::ZERO)
+                    .ok_or_else(|| {
+                        bad(format!(
+                            "{per_eur} per euro on {day} is out of range per £1"
+                        ))
+                    })
+            };
+            let table = rates.days.entry(day).or_default();
+            table.insert(Currency::from_code(*b"EUR"), rebase(Decimal::ONE)?);
+            for (currency, rate) in quotes {
+                if currency != Currency::GBP {
+                    table.insert(currency, rebase(rate)?);
+                }
+            }
+        }
+        Ok(rates)
+    }
+
+    /// Currency units per £1 on exactly `date`, if the feed has that day.
+    pub fn units_per_gbp_on(&self, currency: Currency, date: NaiveDate) -> Option<Decimal> {
+        self.days.get(&date)?.get(&currency).copied()
+    }
+
+    /// The days held, in order.
+    pub fn days(&self) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+impl RateSource for DailyRates {
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+        let period = Period::YearMonth(year_month);
+        let (first, last) = (period.start()?, period.end()?);
+        self.days
+            .range(first..=last)
+            .rev()
+            .find_map(|(_, table)| table.get(&currency).copied())
+    }
+
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+        let month = |d: &NaiveDate| YearMonth::new(d.year(), d.month());
+        Some((
+            month(self.days.keys().next()?)?,
+            month(self.days.keys().next_back()?)?,
+        ))
+    }
+}
+
+fn bad(reason: String) -> LoadError {
+    LoadError::BadData {
+        reason,
+        offset: None,
+    }
+}
+
+fn positive(text: &str) -> Result<Decimal, LoadError> {
+    text.trim()
+        .parse::<Decimal>()
+        .ok()
+        .filter(|rate| *rate > Decimal::ZERO)
+        .ok_or_else(|| bad(format!("bad rate {text:?}")))
+}
+
+fn boe_currency(header: &str) -> Result<Currency, LoadError> {
+    let header = header.trim();
+    let code = header
+        .strip_prefix("XUDL")
+        .and_then(|series| BOE_SERIES.iter().find(|(s, _)| *s == series))
+        .map_or(header, |(_, code)| code);
+    code.parse()
+        .map_err(|_| bad(format!("unknown Bank of England series {header:?}")))
+}
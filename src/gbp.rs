@@ -0,0 +1,624 @@
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::error::PenceError;
+use crate::number::NumberFormat;
+
+/// An exact amount of pounds sterling.
+///
+/// What the conversion helpers return, so a GBP figure cannot be mixed up
+/// with a foreign amount by accident.
+/// Arithmetic is GBP with GBP, or GBP scaled by a plain `Decimal`.
+/// Like the rest of the crate it rounds only when asked;
+/// [`Gbp::round`] applies the scale and [`Rounding`] the regime asks for.
+///
+/// ```
+/// use hmrc_rates::Gbp;
+/// use rust_decimal::Decimal;
+///
+/// let fee = Gbp::new(Decimal::new(1250, 2));
+/// let total: Gbp = [fee, fee * Decimal::from(2), -fee].into_iter().sum();
+/// assert_eq!(total, fee * Decimal::from(2));
+/// assert_eq!(total.to_string(), "£25.00");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Numeric)
+)]
+pub struct Gbp(Decimal);
+
+impl Gbp {
+    /// No money at all.
+    pub const ZERO: Gbp = Gbp(Decimal::ZERO);
+
+    /// Wraps an amount already in GBP.
+    pub fn new(amount: Decimal) -> Gbp {
+        Gbp(amount)
+    }
+
+    /// The amount in pounds, exact.
+    pub fn amount(self) -> Decimal {
+        self.0
+    }
+
+    /// Exactly `pence` hundredths of a pound, e.g. from a ledger that stores integer pence.
+    pub fn from_pence(pence: i64) -> Gbp {
+        Gbp(Decimal::new(pence, 2))
+    }
+
+    /// The amount as whole pence.
+    ///
+    /// Never rounds: a fraction of a penny is an error, so round first.
+    ///
+    /// ```
+    /// use hmrc_rates::{Gbp, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let gbp = Gbp::new(Decimal::new(73_845, 3));
+    /// assert!(gbp.as_pence().is_err());
+    /// assert_eq!(gbp.round(2, Rounding::HalfUp).as_pence()?, 7385);
+    /// # Ok::<(), hmrc_rates::PenceError>(())
+    /// ```
+    pub fn as_pence(self) -> Result<i64, PenceError> {
+        let out_of_range = PenceError::OutOfRange { amount: self };
+        let pence = self
+            .0
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .ok_or(out_of_range)?;
+        if !pence.fract().is_zero() {
+            return Err(PenceError::Fractional { amount: self });
+        }
+        pence.to_i64().ok_or(out_of_range)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, other: Gbp) -> Option<Gbp> {
+        self.0.checked_add(other.0).map(Gbp)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_sub(self, other: Gbp) -> Option<Gbp> {
+        self.0.checked_sub(other.0).map(Gbp)
+    }
+
+    /// `None` on overflow.
+    pub fn checked_mul(self, factor: Decimal) -> Option<Gbp> {
+        self.0.checked_mul(factor).map(Gbp)
+    }
+
+    /// `None` on overflow or division by zero.
+    pub fn checked_div(self, divisor: Decimal) -> Option<Gbp> {
+        self.0.checked_div(divisor).map(Gbp)
+    }
+
+    /// The absolute amount.
+    pub fn abs(self) -> Gbp {
+        Gbp(self.0.abs())
+    }
+
+    /// `true` below zero.
+    pub fn is_negative(self) -> bool {
+        self.0.is_sign_negative() && !self.0.is_zero()
+    }
+
+    /// Rounded to `decimal_places` (2 for whole pence) with `rounding`.
+    ///
+    /// The result has exactly `decimal_places` digits after the point, padded with zeros,
+    /// so `£1.5` rounds to `£1.50` (up to `Decimal`'s 28 places).
+    ///
+    /// ```
+    /// use hmrc_rates::{Gbp, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let gbp = Gbp::new(Decimal::new(73_845, 3));
+    /// assert_eq!(gbp.round(2, Rounding::HalfEven).to_string(), "£73.84");
+    /// assert_eq!(gbp.round(2, Rounding::HalfUp).to_string(), "£73.85");
+    /// assert_eq!(gbp.round(0, Rounding::TowardZero).to_string(), "£73");
+    /// ```
+    pub fn round(self, decimal_places: u32, rounding: Rounding) -> Gbp {
+        Gbp(rounding.apply(self.0, decimal_places))
+    }
+
+    /// The amount laid out for reports, see [`FormatOptions`].
+    pub fn format_with(self, options: FormatOptions) -> FormattedGbp {
+        FormattedGbp { gbp: self, options }
+    }
+}
+
+/// Layout choices for [`Gbp::format_with`]; the default matches `Display`.
+///
+/// ```
+/// use hmrc_rates::{FormatOptions, Gbp, Rounding};
+/// use rust_decimal::Decimal;
+///
+/// let report = FormatOptions::new().thousands().accounting().pence(Rounding::HalfEven);
+/// assert_eq!(Gbp::new(Decimal::new(12345, 1)).format_with(report).to_string(), "£1,234.50");
+/// assert_eq!(Gbp::new(Decimal::new(-5, 1)).format_with(report).to_string(), "(£0.50)");
+/// let bare = FormatOptions::new().without_symbol();
+/// assert_eq!(Gbp::new(Decimal::new(-5, 1)).format_with(bare).to_string(), "-0.5");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct FormatOptions {
+    thousands: bool,
+    accounting: bool,
+    no_symbol: bool,
+    pence: Option<Rounding>,
+}
+
+impl FormatOptions {
+    /// `Display`'s layout: `£1234.5`, `-£0.5`.
+    pub const fn new() -> FormatOptions {
+        FormatOptions {
+            thousands: false,
+            accounting: false,
+            no_symbol: false,
+            pence: None,
+        }
+    }
+
+    /// Groups whole pounds in threes with commas: `£1,234.5`.
+    pub const fn thousands(mut self) -> FormatOptions {
+        self.thousands = true;
+        self
+    }
+
+    /// Negatives in parentheses instead of a minus sign: `(£0.50)`.
+    pub const fn accounting(mut self) -> FormatOptions {
+        self.accounting = true;
+        self
+    }
+
+    /// Leaves out the `£`.
+    pub const fn without_symbol(mut self) -> FormatOptions {
+        self.no_symbol = true;
+        self
+    }
+
+    /// Exactly two decimal places, rounding extra digits with `rounding`.
+    ///
+    /// Only the text is rounded; the crate never picks a rounding for you.
+    pub const fn pence(mut self, rounding: Rounding) -> FormatOptions {
+        self.pence = Some(rounding);
+        self
+    }
+}
+
+/// A [`Gbp`] laid out by [`FormatOptions`], see [`Gbp::format_with`].
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedGbp {
+    gbp: Gbp,
+    options: FormatOptions,
+}
+
+impl fmt::Display for FormattedGbp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FormatOptions {
+            thousands,
+            accounting,
+            no_symbol,
+            pence,
+        } = self.options;
+        let gbp = match pence {
+            Some(rounding) => self.gbp.round(2, rounding),
+            None => self.gbp,
+        };
+        let negative = gbp.is_negative();
+        match (negative, accounting) {
+            (true, true) => f.write_str("(")?,
+            (true, false) => f.write_str("-")?,
+            (false, _) => {}
+        }
+        if !no_symbol {
+            f.write_str("£")?;
+        }
+        let number = if thousands {
+            NumberFormat::UK
+        } else {
+            NumberFormat::PLAIN
+        };
+        number.write_abs(f, gbp.0)?;
+        if negative && accounting {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// How [`Gbp::round`] and the minor-unit roundings treat the discarded digits.
+///
+/// There is no default: which one applies depends on the tax regime.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Rounding {
+    /// Halves go to the even digit (banker's rounding).
+    HalfEven,
+    /// Halves go away from zero.
+    HalfUp,
+    /// Discarded digits are dropped, e.g. for customs valuations.
+    TowardZero,
+    /// Any discarded digit rounds away from zero.
+    AwayFromZero,
+}
+
+impl Rounding {
+    /// `amount` rounded to exactly `decimal_places` digits after the point.
+    pub(crate) fn apply(self, amount: Decimal, decimal_places: u32) -> Decimal {
+        let mut rounded = amount.round_dp_with_strategy(decimal_places, self.strategy());
+        rounded.rescale(decimal_places);
+        rounded
+    }
+
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            Rounding::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Rounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Rounding::TowardZero => RoundingStrategy::ToZero,
+            Rounding::AwayFromZero => RoundingStrategy::AwayFromZero,
+        }
+    }
+}
+
+impl From<Gbp> for Decimal {
+    fn from(gbp: Gbp) -> Decimal {
+        gbp.0
+    }
+}
+
+/// `£` and the exact digits, sign first: `£73.85`, `-£0.50`.
+impl fmt::Display for Gbp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-£{}", self.0.abs())
+        } else {
+            write!(f, "£{}", self.0.abs())
+        }
+    }
+}
+
+/// Input that is not a sterling amount as [`Gbp`]'s `FromStr` reads it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseGbpError;
+
+impl fmt::Display for ParseGbpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid GBP amount, expected a number like \"£73.85\" or \"-73.85\"")
+    }
+}
+
+impl core::error::Error for ParseGbpError {}
+
+/// Parses what `Display` writes, and the bare number: `"£73.85"`, `"73.85"`,
+/// `"-£0.50"`, `"£-0.50"`, `"-0.50"`, with surrounding whitespace ignored.
+///
+/// The amount is plain digits with at most one decimal point, no exponent or grouping,
+/// and must fit a `Decimal` exactly: digits beyond its 28 places of scale
+/// are an error, never rounded away.
+///
+/// ```
+/// use hmrc_rates::Gbp;
+///
+/// let fee: Gbp = "£73.85".parse()?;
+/// assert_eq!(fee, Gbp::from_pence(7385));
+/// assert_eq!(fee.to_string().parse::<Gbp>()?, fee);
+/// assert_eq!("-£0.50".parse::<Gbp>()?, -Gbp::from_pence(50));
+/// assert!("£1e3".parse::<Gbp>().is_err());
+/// # Ok::<(), hmrc_rates::ParseGbpError>(())
+/// ```
+impl core::str::FromStr for Gbp {
+    type Err = ParseGbpError;
+
+    fn from_str(s: &str) -> Result<Gbp, ParseGbpError> {
+        let s = s.trim();
+        let (outer, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let s = s.strip_prefix('£').unwrap_or(s);
+        let (inner, s) = match s.strip_prefix('-') {
+            Some(rest) if !outer => (true, rest),
+            _ => (false, s),
+        };
+        let plain = s.bytes().any(|b| b.is_ascii_digit())
+            && s.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+            && s.bytes().filter(|&b| b == b'.').count() <= 1;
+        if !plain {
+            return Err(ParseGbpError);
+        }
+        let amount = Decimal::from_str_exact(s).map_err(|_| ParseGbpError)?;
+        Ok(Gbp(if outer || inner { -amount } else { amount }))
+    }
+}
+
+impl Add for Gbp {
+    type Output = Gbp;
+
+    fn add(self, other: Gbp) -> Gbp {
+        Gbp(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Gbp {
+    fn add_assign(&mut self, other: Gbp) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Gbp {
+    type Output = Gbp;
+
+    fn sub(self, other: Gbp) -> Gbp {
+        Gbp(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Gbp {
+    fn sub_assign(&mut self, other: Gbp) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Gbp {
+    type Output = Gbp;
+
+    fn neg(self) -> Gbp {
+        Gbp(-self.0)
+    }
+}
+
+impl Mul<Decimal> for Gbp {
+    type Output = Gbp;
+
+    fn mul(self, factor: Decimal) -> Gbp {
+        Gbp(self.0 * factor)
+    }
+}
+
+impl Mul<Gbp> for Decimal {
+    type Output = Gbp;
+
+    fn mul(self, gbp: Gbp) -> Gbp {
+        Gbp(self * gbp.0)
+    }
+}
+
+impl Div<Decimal> for Gbp {
+    type Output = Gbp;
+
+    fn div(self, divisor: Decimal) -> Gbp {
+        Gbp(self.0 / divisor)
+    }
+}
+
+impl Sum for Gbp {
+    fn sum<I: Iterator<Item = Gbp>>(iter: I) -> Gbp {
+        iter.fold(Gbp::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Gbp> for Gbp {
+    fn sum<I: Iterator<Item = &'a Gbp>>(iter: I) -> Gbp {
+        iter.copied().sum()
+    }
+}
+
+// `NUMERIC` columns, through whichever backends `Decimal` itself supports
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use rust_decimal::Decimal;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    use super::Gbp;
+
+    impl<DB: Database> Type<DB> for Gbp
+    where
+        Decimal: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <Decimal as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <Decimal as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Gbp
+    where
+        Decimal: Encode<'q, DB>,
+    {
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.0.encode_by_ref(buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Gbp
+    where
+        Decimal: Decode<'r, DB>,
+    {
+        fn decode(value: DB::ValueRef<'r>) -> Result<Gbp, BoxDynError> {
+            Decimal::decode(value).map(Gbp)
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::Numeric;
+    use rust_decimal::Decimal;
+
+    use super::Gbp;
+
+    impl<DB: Backend> ToSql<Numeric, DB> for Gbp
+    where
+        Decimal: ToSql<Numeric, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0.to_sql(out)
+        }
+    }
+
+    impl<DB: Backend> FromSql<Numeric, DB> for Gbp
+    where
+        Decimal: FromSql<Numeric, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Gbp> {
+            Decimal::from_sql(bytes).map(Gbp)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn gbp(s: &str) -> Gbp {
+        Gbp::new(s.parse().unwrap())
+    }
+
+    #[test]
+    fn display_puts_the_sign_before_the_symbol() {
+        assert_eq!(gbp("73.85").to_string(), "£73.85");
+        assert_eq!(gbp("-0.50").to_string(), "-£0.50");
+        assert_eq!(gbp("-0").to_string(), "£0");
+        assert!(!gbp("-0").is_negative());
+    }
+
+    #[test]
+    fn parses_display_forms_and_bare_numbers() {
+        for (text, amount) in [
+            ("£73.85", "73.85"),
+            (" 73.85 ", "73.85"),
+            ("-£0.50", "-0.50"),
+            ("£-0.50", "-0.50"),
+            ("-7", "-7"),
+            (".5", "0.5"),
+        ] {
+            assert_eq!(text.parse::<Gbp>(), Ok(gbp(amount)), "{text}");
+        }
+        let exact = gbp("1234.123456789");
+        assert_eq!(exact.to_string().parse::<Gbp>(), Ok(exact));
+        for text in [
+            "",
+            "£",
+            "-",
+            "£ 5",
+            "--5",
+            "-£-5",
+            "+5",
+            "5.0.0",
+            "1e3",
+            "1,000",
+            "1_000",
+            "GBP 5",
+            "0.12345678901234567890123456789",
+        ] {
+            assert_eq!(text.parse::<Gbp>(), Err(ParseGbpError), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        let max = Gbp::new(Decimal::MAX);
+        assert_eq!(max.checked_add(gbp("1")), None);
+        assert_eq!(max.checked_mul(Decimal::TWO), None);
+        assert_eq!(gbp("1").checked_div(Decimal::ZERO), None);
+        assert_eq!(gbp("1.5").checked_sub(gbp("2")), Some(gbp("-0.5")));
+    }
+
+    #[test]
+    fn operators_stay_exact() {
+        let mut total = gbp("0.1");
+        total += gbp("0.2");
+        assert_eq!(total, gbp("0.3"));
+        total -= gbp("0.3");
+        assert_eq!(total, Gbp::ZERO);
+        assert_eq!(Decimal::TWO * gbp("1.25"), gbp("2.50"));
+        assert_eq!(gbp("10") / Decimal::from(4), gbp("2.5"));
+        assert_eq!((-gbp("3")).abs(), gbp("3"));
+        assert_eq!(Decimal::from(gbp("3")), Decimal::from(3));
+    }
+
+    #[test]
+    fn rounding_modes_differ_on_halves_and_negatives() {
+        let cases = [
+            ("2.345", Rounding::HalfEven, "2.34"),
+            ("2.355", Rounding::HalfEven, "2.36"),
+            ("2.345", Rounding::HalfUp, "2.35"),
+            ("-2.345", Rounding::HalfUp, "-2.35"),
+            ("2.349", Rounding::TowardZero, "2.34"),
+            ("-2.349", Rounding::TowardZero, "-2.34"),
+            ("2.341", Rounding::AwayFromZero, "2.35"),
+            ("-2.341", Rounding::AwayFromZero, "-2.35"),
+        ];
+        for (amount, rounding, expected) in cases {
+            assert_eq!(
+                gbp(amount).round(2, rounding),
+                gbp(expected),
+                "{amount} {rounding:?}"
+            );
+        }
+        // Already within scale: same value, padded to the scale
+        let padded = gbp("1.5").round(2, Rounding::AwayFromZero);
+        assert_eq!(padded, gbp("1.5"));
+        assert_eq!(padded.to_string(), "£1.50");
+        assert_eq!(gbp("7").round(0, Rounding::HalfEven).amount().scale(), 0);
+    }
+
+    #[test]
+    fn format_options_combine() {
+        let show =
+            |amount: &str, options: FormatOptions| gbp(amount).format_with(options).to_string();
+        let plain = FormatOptions::new();
+        assert_eq!(show("-1234.5", plain), gbp("-1234.5").to_string());
+        assert_eq!(show("1234567.891", plain.thousands()), "£1,234,567.891");
+        assert_eq!(show("123", plain.thousands()), "£123");
+        assert_eq!(
+            show("-999.999", plain.thousands().pence(Rounding::HalfUp)),
+            "-£1,000.00"
+        );
+        assert_eq!(
+            show("-0.004", plain.accounting().pence(Rounding::HalfEven)),
+            "£0.00"
+        );
+        assert_eq!(show("-12", plain.accounting().without_symbol()), "(12)");
+        assert_eq!(show("0.1", plain.pence(Rounding::TowardZero)), "£0.10");
+    }
+
+    #[test]
+    fn pence_round_trip_without_rounding() {
+        assert_eq!(Gbp::from_pence(-7385), gbp("-73.85"));
+        assert_eq!(Gbp::from_pence(7385).to_string(), "£73.85");
+        assert_eq!(gbp("73.8").as_pence(), Ok(7380));
+        assert_eq!(gbp("-0.01").as_pence(), Ok(-1));
+        assert_eq!(Gbp::from_pence(i64::MIN).as_pence(), Ok(i64::MIN));
+        assert_eq!(
+            gbp("0.005").as_pence(),
+            Err(PenceError::Fractional {
+                amount: gbp("0.005")
+            })
+        );
+        let huge = Gbp::new(Decimal::from(i64::MAX));
+        assert_eq!(
+            huge.as_pence(),
+            Err(PenceError::OutOfRange { amount: huge })
+        );
+    }
+}
@@ -1,12 +1,16 @@
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 
 use chrono::Datelike;
+use sha2::{Digest, Sha256};
 
-use crate::parse::{self, ParsedRate};
-use crate::rates::Rates;
-use crate::store::Entry;
+use crate::load::{Month, dedup, file_source, month_table};
+use crate::metrics::Metrics;
+use crate::parse;
+use crate::provenance::Source;
+use crate::rates::{DuplicateRates, Rates};
 use crate::types::{RateType, YearEnd, YearMonth};
 
 const DEFAULT_BASE_URL: &str =
@@ -17,6 +21,8 @@ const USER_AGENT: &str = concat!(
     " (+https://github.com/velikodniy/hmrc-rates)"
 );
 const CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// The longest run [`Updater::fetch_range`] accepts, a century of months.
+const MAX_RANGE_MONTHS: usize = 12 * 100;
 
 /// Why fetching fresh rates failed.
 #[derive(Debug, thiserror::Error)]
@@ -28,6 +34,32 @@ pub enum FetchError {
     /// The endpoint answered, but the payload failed validation.
     #[error("HMRC returned malformed data from {url}: {reason}")]
     BadData { url: String, reason: String },
+    /// A fetched file's SHA-256 is not the one given by [`Updater::with_checksum`].
+    #[error("checksum mismatch for {url}: expected {}, got {}", hex(.expected), hex(.actual))]
+    ChecksumMismatch {
+        url: String,
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    /// A line of a [`Updater::with_sha256sums`] manifest is not `<64 hex digits>  <file name>`.
+    #[error("bad checksum manifest line {line}")]
+    BadManifest { line: usize },
+    /// [`Updater::fetch_range`] was given a range it will not walk, see its errors.
+    #[error("cannot fetch the months {from} to {to}")]
+    BadRange { from: YearMonth, to: YearMonth },
+}
+
+impl FetchError {
+    /// A stable name for the kind of failure, see [`LookupError::code`](crate::LookupError::code).
+    pub fn code(&self) -> &'static str {
+        match self {
+            FetchError::Http(_) => "http",
+            FetchError::BadData { .. } => "bad_data",
+            FetchError::ChecksumMismatch { .. } => "checksum_mismatch",
+            FetchError::BadManifest { .. } => "bad_manifest",
+            FetchError::BadRange { .. } => "bad_range",
+        }
+    }
 }
 
 /// Extends the bundled dataset with rates HMRC has published since the release,
@@ -38,10 +70,16 @@ pub enum FetchError {
 /// are re-fetched after a 24-hour TTL to pick up HMRC's rare in-month amendments.
 ///
 /// See [`Updater::refreshed`] for the recommended usage pattern.
+/// Clones share the connection pool and [`Metrics`].
+#[derive(Clone)]
 pub struct Updater {
     agent: ureq::Agent,
     base_url: String,
     cache_dir: Option<PathBuf>,
+    retries: u32,
+    backoff: Duration,
+    checksums: BTreeMap<String, [u8; 32]>,
+    metrics: Option<Metrics>,
 }
 
 impl Default for Updater {
@@ -63,6 +101,10 @@ impl Updater {
             agent,
             base_url: DEFAULT_BASE_URL.into(),
             cache_dir: default_cache_dir(),
+            retries: 0,
+            backoff: Duration::ZERO,
+            checksums: BTreeMap::new(),
+            metrics: None,
         }
     }
 
@@ -78,6 +120,64 @@ impl Updater {
         self
     }
 
+    /// Retries a request that failed in transport or with a 5xx status up to `retries` times,
+    /// waiting `backoff` before the first retry and twice as long before each one after.
+    /// A 404 is an answer ("not published yet") and never retried. The default is no retries.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Updater {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Requires the file HMRC serves as `name`, e.g. `monthly_xml_2025-08.xml`,
+    /// to have this SHA-256, checked before it is merged or cached.
+    ///
+    /// A mismatching download is [`FetchError::ChecksumMismatch`];
+    /// a mismatching cache file is treated as corrupt, i.e. skipped and refetched.
+    pub fn with_checksum(mut self, name: impl Into<String>, sha256: [u8; 32]) -> Updater {
+        self.checksums.insert(name.into(), sha256);
+        self
+    }
+
+    /// [`Updater::with_checksum`] for every line of `sha256sum` output,
+    /// `<hex digest>  <file name>`; blank lines are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Updater;
+    ///
+    /// let manifest = "\
+    /// 1f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c5b6a7988  monthly_xml_2025-08.xml
+    /// ";
+    /// let updater = Updater::new().with_sha256sums(manifest)?;
+    /// # Ok::<(), hmrc_rates::FetchError>(())
+    /// ```
+    pub fn with_sha256sums(mut self, manifest: &str) -> Result<Updater, FetchError> {
+        for (i, line) in manifest.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let bad = || FetchError::BadManifest { line: i + 1 };
+            let (digest, name) = line.split_once(char::is_whitespace).ok_or_else(bad)?;
+            // `sha256sum` marks binary-mode entries with a leading `*`
+            let name = name.trim_start().trim_start_matches('*').trim_end();
+            let digest = parse_sha256(digest).ok_or_else(bad)?;
+            if name.is_empty() {
+                return Err(bad());
+            }
+            self.checksums.insert(name.into(), digest);
+        }
+        Ok(self)
+    }
+
+    /// Counts every download in `metrics`: a success for each file fetched or found
+    /// unpublished, a failure by [`FetchError::code`] otherwise. Cache hits count neither.
+    pub fn with_metrics(mut self, metrics: Metrics) -> Updater {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Bundled data plus whatever the disk cache holds.
     /// Never touches the network.
     /// Unreadable or corrupt cache files are treated as absent.
@@ -126,13 +226,7 @@ impl Updater {
                 candidate = candidate.next();
                 continue; // already have an immutable copy
             }
-            let name = format!("monthly_xml_{candidate}.xml");
-            let entries = self.obtain(&name, amendable, |bytes| {
-                validated_monthly(bytes, candidate)
-            })?;
-            if let Some(entries) = entries {
-                rates.set_period(RateType::Monthly, candidate.key(), entries);
-            }
+            self.merge_month(&mut rates, candidate, amendable)?;
             candidate = candidate.next();
         }
 
@@ -163,7 +257,7 @@ impl Updater {
                 let have = periods.binary_search(&period).is_ok();
                 if !have || amendable {
                     let entries = self.obtain(&name, amendable, |bytes| {
-                        dedup(parse::parse_rates_csv(bytes)?)
+                        dedup(parse::parse_rates_csv(bytes)?, rates.duplicate_rates())
                     })?;
                     if let Some(entries) = entries {
                         rates.set_period(rate_type, period.key(), entries);
@@ -176,9 +270,186 @@ impl Updater {
         Ok(rates)
     }
 
+    /// Fetches one month into `rates`, replacing any table held for it.
+    ///
+    /// Goes through the disk cache like [`Updater::refreshed`].
+    /// Returns `false` when HMRC has not published the month (yet).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::{Rates, Updater, YearMonth};
+    ///
+    /// let mut rates = Rates::new();
+    /// let month = YearMonth::new(2026, 11).unwrap();
+    /// if !Updater::new().fetch_month(&mut rates, month)? {
+    ///     eprintln!("{month} is not published yet");
+    /// }
+    /// # Ok::<(), hmrc_rates::FetchError>(())
+    /// ```
+    pub fn fetch_month(
+        &self,
+        rates: &mut Rates,
+        year_month: YearMonth,
+    ) -> Result<bool, FetchError> {
+        let current = YearMonth::from(chrono::Utc::now().date_naive());
+        self.merge_month(rates, year_month, year_month >= current)
+    }
+
+    /// [`Updater::fetch_month`] for async code, such as a tokio service refreshing its rates.
+    ///
+    /// The download, retries and cache run on tokio's blocking pool, so the runtime's
+    /// workers never wait on the network; the month is merged into `rates` once it validates.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), hmrc_rates::FetchError> {
+    /// use hmrc_rates::{Rates, Updater, YearMonth};
+    ///
+    /// let mut rates = Rates::new();
+    /// let month = YearMonth::new(2026, 11).unwrap();
+    /// let published = Updater::new().fetch_month_async(&mut rates, month).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If called outside a tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn fetch_month_async(
+        &self,
+        rates: &mut Rates,
+        year_month: YearMonth,
+    ) -> Result<bool, FetchError> {
+        let current = YearMonth::from(chrono::Utc::now().date_naive());
+        let (amendable, rule) = (year_month >= current, rates.duplicate_rates());
+        let updater = self.clone();
+        let month =
+            tokio::task::spawn_blocking(move || updater.obtain_month(year_month, amendable, rule))
+                .await
+                .map_err(|e| match e.try_into_panic() {
+                    Ok(panic) => std::panic::resume_unwind(panic),
+                    Err(e) => FetchError::Http(Box::new(ureq::Error::Io(std::io::Error::other(e)))),
+                })??;
+        Ok(self.set_fetched(rates, year_month, month))
+    }
+
+    /// Fetches the newest published month into `rates` and returns it.
+    ///
+    /// HMRC publishes next month's rates ahead, so next month is tried first,
+    /// then the current one. `None` if neither is published.
+    pub fn fetch_latest(&self, rates: &mut Rates) -> Result<Option<YearMonth>, FetchError> {
+        let current = YearMonth::from(chrono::Utc::now().date_naive());
+        for candidate in [current.next(), current] {
+            if self.merge_month(rates, candidate, true)? {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Fetches every month from `from` to `to`, inclusive, into `rates`, oldest first,
+    /// and returns the months HMRC has published; for bootstrapping a deployment
+    /// without the bundle, or with one trimmed by `HMRC_RATES_BUNDLE_FROM`.
+    ///
+    /// Each month goes through the disk cache like [`Updater::fetch_month`].
+    /// The first failure stops the run, keeping the months merged before it;
+    /// pair with [`Updater::with_retries`] for long runs.
+    ///
+    /// # Errors
+    ///
+    /// [`FetchError::BadRange`] before any request when `from` is after `to`,
+    /// `to` is after next month, which HMRC cannot have published yet,
+    /// or the range spans more than a century.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use hmrc_rates::{Rates, Updater, YearMonth};
+    ///
+    /// let mut rates = Rates::empty();
+    /// let updater = Updater::new().with_retries(3, Duration::from_secs(1));
+    /// let from = YearMonth::new(2016, 1).unwrap();
+    /// let to = YearMonth::new(2025, 12).unwrap();
+    /// let fetched = updater.fetch_range(&mut rates, from, to)?;
+    /// # Ok::<(), hmrc_rates::FetchError>(())
+    /// ```
+    pub fn fetch_range(
+        &self,
+        rates: &mut Rates,
+        from: YearMonth,
+        to: YearMonth,
+    ) -> Result<Vec<YearMonth>, FetchError> {
+        self.fetch_range_with(rates, from, to, |_, _, _| {})
+    }
+
+    /// [`Updater::fetch_range`], calling `progress` after each month
+    /// with the month, how many are done and how many the range holds.
+    pub fn fetch_range_with(
+        &self,
+        rates: &mut Rates,
+        from: YearMonth,
+        to: YearMonth,
+        mut progress: impl FnMut(YearMonth, usize, usize),
+    ) -> Result<Vec<YearMonth>, FetchError> {
+        let current = YearMonth::from(chrono::Utc::now().date_naive());
+        let total = to
+            .key()
+            .checked_sub(from.key())
+            .and_then(|months| usize::try_from(months).ok())
+            .map(|months| months + 1)
+            .filter(|&total| total <= MAX_RANGE_MONTHS && to <= current.next())
+            .ok_or(FetchError::BadRange { from, to })?;
+        let mut published = Vec::new();
+        let mut month = from;
+        for done in 1..=total {
+            if self.merge_month(rates, month, month >= current)? {
+                published.push(month);
+            }
+            progress(month, done, total);
+            month = month.next();
+        }
+        Ok(published)
+    }
+
+    fn merge_month(
+        &self,
+        rates: &mut Rates,
+        year_month: YearMonth,
+        amendable: bool,
+    ) -> Result<bool, FetchError> {
+        let month = self.obtain_month(year_month, amendable, rates.duplicate_rates())?;
+        Ok(self.set_fetched(rates, year_month, month))
+    }
+
+    /// One month's validated table; `None` when it is not published yet.
+    fn obtain_month(
+        &self,
+        year_month: YearMonth,
+        amendable: bool,
+        rule: DuplicateRates,
+    ) -> Result<Option<Month>, FetchError> {
+        self.obtain(&monthly_name(year_month), amendable, |bytes| {
+            validated_monthly(bytes, year_month, rule)
+        })
+    }
+
+    /// Sets a month from [`Updater::obtain_month`] into `rates`; `false` if there is none.
+    fn set_fetched(&self, rates: &mut Rates, year_month: YearMonth, month: Option<Month>) -> bool {
+        let Some(month) = month else {
+            return false;
+        };
+        let url = format!("{}/{}", self.base_url, monthly_name(year_month));
+        rates.set_month(month, Source::Url { url });
+        true
+    }
+
     /// A validated value from cache (when fresh) or network;
     /// `None` = 404, i.e. not published yet.
-    /// Corrupt cache falls through to the network,
+    /// Corrupt cache, or cache failing its checksum, falls through to the network;
     /// only network-validated bytes are cached.
     fn obtain<T>(
         &self,
@@ -187,19 +458,62 @@ impl Updater {
         validate: impl Fn(&[u8]) -> Result<T, parse::ParseError>,
     ) -> Result<Option<T>, FetchError> {
         if let Some(bytes) = self.fresh_cache_bytes(name, amendable) {
-            if let Ok(value) = validate(&bytes) {
+            let value = match self.verify(name, &bytes) {
+                Ok(()) => validate(&bytes).ok(),
+                Err(_) => None,
+            };
+            if let Some(value) = value {
+                event!(debug!(name, "cache hit"));
                 return Ok(Some(value));
             }
+            event!(warn!(name, "corrupt cache file, refetching"));
         }
+        let result = self.download(name, validate);
+        if let Some(metrics) = &self.metrics {
+            metrics.record_fetch(&result);
+        }
+        result
+    }
+
+    /// [`Updater::obtain`] from the network, caching what validates.
+    fn download<T>(
+        &self,
+        name: &str,
+        validate: impl Fn(&[u8]) -> Result<T, parse::ParseError>,
+    ) -> Result<Option<T>, FetchError> {
         let url = format!("{}/{}", self.base_url, name);
-        let bytes = match self.agent.get(&url).call() {
-            Ok(mut response) => response
-                .body_mut()
-                .read_to_vec()
-                .map_err(|e| FetchError::Http(Box::new(e)))?,
-            Err(ureq::Error::StatusCode(404)) => return Ok(None),
-            Err(e) => return Err(FetchError::Http(Box::new(e))),
+        event!(debug!(%url, "fetching"));
+        let mut delay = self.backoff;
+        let mut attempt = 0;
+        let bytes = loop {
+            let error = match self.agent.get(&url).call() {
+                Ok(mut response) => match response.body_mut().read_to_vec() {
+                    Ok(bytes) => break bytes,
+                    Err(e) => e,
+                },
+                Err(ureq::Error::StatusCode(404)) => {
+                    event!(debug!(%url, "not published yet"));
+                    return Ok(None);
+                }
+                Err(e) => e,
+            };
+            let transient = match error {
+                ureq::Error::StatusCode(status) => status >= 500,
+                ureq::Error::Io(_)
+                | ureq::Error::Timeout(_)
+                | ureq::Error::HostNotFound
+                | ureq::Error::ConnectionFailed => true,
+                _ => false,
+            };
+            if !transient || attempt == self.retries {
+                return Err(FetchError::Http(Box::new(error)));
+            }
+            attempt += 1;
+            event!(warn!(%url, attempt, error = %error, "retrying"));
+            std::thread::sleep(delay);
+            delay = delay.saturating_mul(2);
         };
+        self.verify(name, &bytes)?;
         let value = validate(&bytes).map_err(|e| self.bad_data(name, e))?;
         self.store(name, &bytes);
         Ok(Some(value))
@@ -230,23 +544,28 @@ impl Updater {
         };
         let mut files: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
         files.sort();
-        for path in files {
+        for path in &files {
             let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
                 continue;
             };
-            let Ok(bytes) = fs::read(&path) else { continue };
-            self.apply_file(rates, name, &bytes);
+            let Ok(bytes) = fs::read(path) else { continue };
+            if self.verify(name, &bytes).is_ok() {
+                self.apply_file(rates, path, name, &bytes);
+            }
         }
+        event!(debug!(dir = %dir.display(), files = files.len(), "disk cache applied"));
     }
 
-    fn apply_file(&self, rates: &mut Rates, name: &str, bytes: &[u8]) -> Option<()> {
+    fn apply_file(&self, rates: &mut Rates, path: &Path, name: &str, bytes: &[u8]) -> Option<()> {
         if let Some(rest) = name
             .strip_prefix("monthly_xml_")
             .and_then(|r| r.strip_suffix(".xml"))
         {
             let year_month: YearMonth = rest.parse().ok()?;
-            let entries = validated_monthly(bytes, year_month).ok()?;
-            rates.set_period(RateType::Monthly, year_month.key(), entries);
+            rates.set_month(
+                validated_monthly(bytes, year_month, rates.duplicate_rates()).ok()?,
+                file_source(path),
+            );
             return Some(());
         }
         for (rate_type, prefix) in [
@@ -258,7 +577,8 @@ impl Updater {
                 .and_then(|r| r.strip_suffix(".csv"))
             {
                 let year_end = YearEnd::from_year_month(rest.parse().ok()?)?;
-                let entries = dedup(parse::parse_rates_csv(bytes).ok()?).ok()?;
+                let entries =
+                    dedup(parse::parse_rates_csv(bytes).ok()?, rates.duplicate_rates()).ok()?;
                 rates.set_period(rate_type, year_end.key(), entries);
                 return Some(());
             }
@@ -289,6 +609,22 @@ impl Updater {
         Some(self.cache_dir.as_deref()?.join(name))
     }
 
+    /// Checks `bytes` against the SHA-256 expected for `name`, if one was given.
+    fn verify(&self, name: &str, bytes: &[u8]) -> Result<(), FetchError> {
+        let Some(expected) = self.checksums.get(name) else {
+            return Ok(());
+        };
+        let actual: [u8; 32] = Sha256::digest(bytes).into();
+        if actual == *expected {
+            return Ok(());
+        }
+        Err(FetchError::ChecksumMismatch {
+            url: format!("{}/{}", self.base_url, name),
+            expected: *expected,
+            actual,
+        })
+    }
+
     fn bad_data(&self, name: &str, e: parse::ParseError) -> FetchError {
         FetchError::BadData {
             url: format!("{}/{}", self.base_url, name),
@@ -303,24 +639,39 @@ fn default_cache_dir() -> Option<PathBuf> {
     Some(strategy.cache_dir().join("hmrc-rates").join("v1"))
 }
 
-fn dedup(raw: Vec<ParsedRate>) -> Result<Vec<Entry>, parse::ParseError> {
-    Ok(parse::dedup_majority(raw)?
-        .into_iter()
-        .map(|r| Entry {
-            mantissa: r.mantissa,
-            code: r.code,
-            scale: r.scale,
-        })
-        .collect())
+/// The file name HMRC publishes `year_month`'s monthly XML under.
+fn monthly_name(year_month: YearMonth) -> String {
+    format!("monthly_xml_{year_month}.xml")
+}
+
+/// `digest` as lowercase hex.
+fn hex(digest: &[u8; 32]) -> String {
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// A SHA-256 written as 64 hex digits, either case.
+fn parse_sha256(hex: &str) -> Option<[u8; 32]> {
+    if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut digest = [0; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(core::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(digest)
 }
 
 /// Parse, period-check and dedup one monthly XML payload.
-fn validated_monthly(bytes: &[u8], expected: YearMonth) -> Result<Vec<Entry>, parse::ParseError> {
-    let ((y, m), raw) = parse::parse_monthly_xml(bytes)?;
-    if YearMonth::new(y, m) != Some(expected) {
-        return Err(parse::ParseError("period mismatch".into()));
+fn validated_monthly(
+    bytes: &[u8],
+    expected: YearMonth,
+    rule: DuplicateRates,
+) -> Result<Month, parse::ParseError> {
+    let month = month_table(parse::parse_monthly_xml(bytes)?, rule)?;
+    if month.year_month != expected {
+        return Err(parse::ParseError::new("period mismatch"));
     }
-    dedup(raw)
+    Ok(month)
 }
 
 /// The first period missing from an ascending run, or `None` for an empty series.
@@ -0,0 +1,54 @@
+// `iso_currency` interop: the crate's codes as `iso_currency::Currency` values and back.
+
+use iso_currency::ParseCurrencyError as ParseIsoError;
+
+use crate::rates::{Rates, Table};
+use crate::types::{Currency, RateType};
+
+impl From<iso_currency::Currency> for Currency {
+    fn from(currency: iso_currency::Currency) -> Currency {
+        Currency::from_iso_code(currency.code())
+    }
+}
+
+/// Fails for the codes HMRC publishes that ISO 4217 does not list (or no longer lists).
+impl TryFrom<Currency> for iso_currency::Currency {
+    type Error = ParseIsoError;
+
+    fn try_from(currency: Currency) -> Result<iso_currency::Currency, ParseIsoError> {
+        iso_currency::Currency::from_code(currency.as_str()).ok_or(ParseIsoError)
+    }
+}
+
+impl Currency {
+    /// The ISO 4217 entry for this code, with its name, numeric code and exponent.
+    ///
+    /// `None` for the codes HMRC publishes that ISO 4217 does not list.
+    ///
+    /// ```
+    /// use hmrc_rates::Currency;
+    ///
+    /// let jpy: Currency = "JPY".parse().unwrap();
+    /// assert_eq!(jpy.iso().and_then(|iso| iso.exponent()), Some(0));
+    /// ```
+    pub fn iso(&self) -> Option<iso_currency::Currency> {
+        iso_currency::Currency::try_from(*self).ok()
+    }
+}
+
+impl Rates {
+    /// [`Rates::currencies`] as ISO 4217 entries, leaving out codes ISO does not list.
+    pub fn iso_currencies(
+        &self,
+        table: RateType,
+    ) -> impl Iterator<Item = iso_currency::Currency> + use<'_> {
+        self.currencies(table).filter_map(|currency| currency.iso())
+    }
+}
+
+impl<'a> Table<'a> {
+    /// [`Table::currencies`] as ISO 4217 entries, leaving out codes ISO does not list.
+    pub fn iso_currencies(&self) -> impl Iterator<Item = iso_currency::Currency> + use<'a> {
+        self.currencies().filter_map(|currency| currency.iso())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::rates::Rates;
+
+/// JSON snapshots of a whole [`Rates`], for frontends and for diffing deployments.
+///
+/// The schema is the `serde` form and is stable across releases:
+///
+/// ```json
+/// {
+///   "monthly": {"2025-08": {"EUR": "1.1547", "USD": "1.3541"}},
+///   "spot": {"2025-03": {"USD": "1.2954"}},
+///   "average": {"2025-03": {"USD": "1.2761"}},
+///   "weekly": [{"start": "2014-01-08", "end": "2014-01-14", "rates": {"TRY": "3.5418"}}]
+/// }
+/// ```
+///
+/// Rates are decimal strings, so no precision is lost in JavaScript.
+/// Periods and currencies are emitted in order, so equal rates give byte-identical JSON.
+/// Spot and average keys are the month a year ends in, `"YYYY-03"` or `"YYYY-12"`.
+impl Rates {
+    /// Every held period as one JSON document, bundled and loaded alike.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let json = Rates::new().to_json()?;
+    /// let rates = Rates::from_json(&json)?;
+    /// assert_eq!(rates.months().count(), Rates::new().months().count());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Only the rates in a [`Rates::to_json`] document.
+    ///
+    /// Unknown fields, malformed rates and overlapping weeks are rejected.
+    pub fn from_json(json: &str) -> Result<Rates, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
@@ -16,47 +16,193 @@
 //! ```
 //!
 //! Rates are HMRC's figures, i.e. currency units per £1.
-//! Conversion divides exactly, and the crate never rounds.
+//! Conversion divides exactly, and nothing is rounded except by the `*_rounded` methods.
+//! The conversion helpers on [`Rates`] return [`Gbp`],
+//! an exact sterling amount that cannot be confused with a foreign one.
+//! Rounding is a separate, explicit step: [`Gbp::round`] with a [`Rounding`].
 //!
 //! Lookups are strict.
 //! An unpublished period is an error, never a silently substituted older rate.
-//! Fallback is explicit, see [`Rates::monthly_rate_or_earlier`].
+//! Fallback is explicit and bounded, see [`Rates::monthly_rate_or_earlier`]
+//! and [`LookupPolicy`].
 //!
 //! # Features
 //!
 //! - `std`, `bundled` (default): the full history compiled in,
 //!   no parsing or I/O at startup, ~450 KB of read-only data.
-//! - `http`: a blocking `Updater` that fetches newly published periods,
-//!   with an on-disk cache.
-//! - `serde`: compact string forms (`"2026-07"`, `"USD"`, `"monthly"`).
+//!   Build with `HMRC_RATES_BUNDLE_FROM=YYYY-MM` to compile in only periods ending from that month,
+//!   or `HMRC_RATES_DATA_DIR=/path` to compile in your own directory in the `data/` layout.
+//! - `load`: HMRC monthly XML streamed from any `std::io::Read`
+//!   or read from a directory of downloads, and the monthly CSV layout.
+//!   Also `CsvConverter`, which appends GBP amounts to a CSV of transactions.
+//! - `async`: the same parse driven from a tokio `AsyncRead`; with `http`, `Updater::fetch_month_async`.
+//! - `parallel`: directory loads parse their files on a rayon pool,
+//!   and `Rates::par_convert_many` converts large slices on it.
+//! - `zip`: HMRC's yearly ZIP bundles of monthly XML.
+//! - `toml`: `Rates::load_overrides`, monthly rate corrections from a TOML file,
+//!   recorded as manual in their provenance.
+//! - `xlsx`: `Rates::export_xlsx`, an Excel workbook with a sheet of rates per month.
+//! - `feeds`: Bank of England and ECB daily rates as a [`RateSource`], see [`feeds`](mod@feeds).
+//! - `http`: a blocking `Updater` that fetches newly published periods or a range of months,
+//!   with an on-disk cache, and an `AutoRefresher` that does so in the background.
+//!   With `http` or `serve`, `Metrics` counts fetches and conversions in the Prometheus text format.
+//! - `serde`: compact string forms (`"2026-07"`, `"USD"`, `"monthly"`);
+//!   [`Rates`] and [`Table`] as maps of exact decimal strings.
+//!   With `std`, [`serde`](mod@serde) has `deserialize_with` helpers that convert to GBP.
+//! - `json`: [`Rates::to_json`] and [`Rates::from_json`] over that form;
+//!   with `load`, trade-tariff API responses via `Rates::load_api_json`.
+//! - `serve`: a JSON HTTP service over [`Rates`] with a Prometheus `/metrics` endpoint,
+//!   see [`serve`](mod@serve).
+//! - `polars`: whole-column GBP conversion of Polars series, see [`polars`](mod@polars).
+//! - `sqlx`, `diesel`: [`Gbp`] maps to `NUMERIC` columns
+//!   on any backend where `Decimal` does (sqlx's `rust_decimal`, `rust_decimal`'s `db-diesel2-*`).
+//! - `rusty-money`: [`Gbp`] and [`ForeignMoney`] to and from `rusty_money::Money`,
+//!   and a month's rates as a `rusty_money::Exchange`.
+//! - `iso-currency`: [`Currency`] to and from `iso_currency::Currency`, with ISO names and exponents,
+//!   and `iso_currencies()` beside the currency listings.
+//! - `testing`: fixture [`Rates`] for downstream tests, see [`testing`](mod@testing).
+//! - `tracing`: events for loads (files, months added, time taken), monthly lookups
+//!   (the month used and how far a fallback went) and the `http` disk cache.
 //! - `cli`: the `hmrc-rates` binary.
 //!
 //! The core is `no_std` + `alloc`
 //! with only `bundled` enabled the crate builds on `wasm32-unknown-unknown`.
+//! `serde` is `no_std` too: a runtime without the bundle can deserialize [`Rates`] instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+/// `tracing::$level!(..)` with the `tracing` feature; nothing at all without it.
+macro_rules! event {
+    ($level:ident!($($arg:tt)*)) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::$level!($($arg)*);
+    };
+}
+
+mod alias;
+mod builder;
+mod clock;
+mod conversion;
+mod country;
+mod diff;
 mod error;
+mod gbp;
+mod money;
+mod number;
+mod pretty;
+mod provenance;
 mod rate;
 mod rates;
+mod redenom;
+mod search;
+mod snapshot;
+mod source;
+mod stats;
 mod store;
+mod summary;
 mod types;
+mod validate;
 
 #[cfg(feature = "bundled")]
 mod bundled;
 
-#[cfg(any(feature = "http", all(test, feature = "std")))]
+#[cfg(any(feature = "load", all(test, feature = "std")))]
 mod parse;
 
+#[cfg(feature = "load")]
+mod load;
+
+#[cfg(feature = "load")]
+mod transactions;
+
+#[cfg(feature = "toml")]
+mod overrides;
+
+#[cfg(feature = "feeds")]
+pub mod feeds;
+
 #[cfg(feature = "http")]
 mod http;
 
-pub use error::LookupError;
+#[cfg(feature = "http")]
+mod refresh;
+
+#[cfg(any(feature = "serve", feature = "http"))]
+mod metrics;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
+#[cfg(feature = "polars")]
+pub mod polars;
+
+#[cfg(feature = "rusty-money")]
+mod rusty;
+
+#[cfg(feature = "iso-currency")]
+mod iso;
+
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde;
+
+#[cfg(feature = "serve")]
+pub mod serve;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use alias::Aliases;
+pub use builder::RatesBuilder;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock};
+pub use conversion::Conversion;
+pub use country::Country;
+pub use diff::{MonthDiff, RateChange};
+pub use error::{
+    ArithmeticError, ConvertError, InvalidRate, LookupError, MergeConflict, PenceError,
+    SnapshotError,
+};
+pub use gbp::{FormatOptions, FormattedGbp, Gbp, ParseGbpError, Rounding};
+pub use money::{ForeignMoney, FormattedMoney, MoneyFormat, ParseMoneyError};
+pub use number::NumberFormat;
+pub use pretty::TableFormat;
+pub use provenance::{Provenance, Source};
 pub use rate::Rate;
-pub use rates::{Rates, Table};
-pub use types::{Currency, ParseYearMonthError, Period, RateType, YearEnd, YearMonth};
+pub use rates::{
+    DuplicateRates, LookupPolicy, MergePolicy, MonthlyRates, NegativeAmounts, Rates, Table,
+};
+pub use redenom::Redenomination;
+pub use source::{RateSource, SourceChain};
+pub use stats::RateStats;
+pub use summary::Summary;
+
+#[cfg(feature = "bundled")]
+pub use rates::convert;
+pub use types::{
+    Currency, ParseCurrencyError, ParseYearMonthError, Period, RateType, YearEnd, YearMonth,
+};
+pub use validate::{ValidationIssue, ValidationReport};
+
+#[cfg(feature = "load")]
+pub use load::{LoadError, LoadReport, ParseWarning, ParseWarningKind};
+
+#[cfg(feature = "load")]
+pub use transactions::{CsvConverter, CsvError};
 
 #[cfg(feature = "http")]
 pub use http::{FetchError, Updater};
+
+#[cfg(feature = "http")]
+pub use refresh::{AutoRefresher, RatesHandle};
+
+#[cfg(any(feature = "serve", feature = "http"))]
+pub use metrics::Metrics;
+
+#[cfg(feature = "xlsx")]
+pub use xlsx::XlsxError;
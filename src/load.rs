@@ -0,0 +1,676 @@
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::parse::{self, ParsedRate};
+use crate::provenance::Source;
+use crate::rates::{DuplicateRates, Rates};
+use crate::store::{CountryRow, Entry};
+use crate::types::{RateType, YearMonth};
+
+/// Why loading HMRC rate files failed.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// Reading the input failed.
+    #[error("reading HMRC rates failed: {0}")]
+    Io(#[from] std::io::Error),
+    /// The input was read, but failed validation.
+    ///
+    /// `offset` is how many bytes of XML had been read when it failed,
+    /// where the parse streams; [`LoadError::File`] around it names the file.
+    #[error("malformed HMRC rates: {reason}{}", .offset.map(|o| format!(" (at byte {o})")).unwrap_or_default())]
+    BadData { reason: String, offset: Option<u64> },
+    /// The ZIP archive itself is unreadable.
+    #[cfg(feature = "zip")]
+    #[error("reading HMRC ZIP archive failed: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    /// Loading one file of a directory or archive failed.
+    #[error("{}: {source}", .path.display())]
+    File {
+        path: PathBuf,
+        #[source]
+        source: Box<LoadError>,
+    },
+}
+
+impl LoadError {
+    /// A stable name for the kind of failure, see [`LookupError::code`](crate::LookupError::code);
+    /// a [`LoadError::File`] reports the failure inside it.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LoadError::Io(_) => "io",
+            LoadError::BadData { .. } => "bad_data",
+            #[cfg(feature = "zip")]
+            LoadError::Zip(_) => "zip",
+            LoadError::File { source, .. } => source.code(),
+        }
+    }
+}
+
+/// A malformed record that a lenient load skipped, or one whose rate lost to another row
+/// for its currency under [`Rates::duplicate_rates`]; see [`Rates::load_reader_lenient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseWarning {
+    /// The record's 1-based position among the document's `exchangeRate` elements.
+    pub record: usize,
+    /// Whether the record was skipped or overruled.
+    pub kind: ParseWarningKind,
+    /// What was wrong with it, or which rate it lost to.
+    pub reason: String,
+}
+
+/// What happened to the record a [`ParseWarning`] names.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseWarningKind {
+    /// The record was malformed and left out.
+    Skipped,
+    /// The record was valid, but another rate for its currency was kept.
+    Overruled,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = match self.kind {
+            ParseWarningKind::Skipped => "skipped",
+            ParseWarningKind::Overruled => "overruled",
+        };
+        write!(
+            f,
+            "{verb} exchangeRate record {}: {}",
+            self.record, self.reason
+        )
+    }
+}
+
+impl From<parse::ParseError> for LoadError {
+    fn from(e: parse::ParseError) -> LoadError {
+        LoadError::BadData {
+            reason: e.reason,
+            offset: e.offset,
+        }
+    }
+}
+
+impl Rates {
+    /// Only the rates in one HMRC monthly XML document, streamed from `reader`.
+    ///
+    /// Without the bundled data: for files you ship or archive yourself.
+    /// To layer a file over the bundle, use [`Rates::load_reader`].
+    pub fn from_reader(reader: impl Read) -> Result<Rates, LoadError> {
+        let mut rates = Rates::empty();
+        rates.load_reader(reader)?;
+        Ok(rates)
+    }
+
+    /// Adds the month in one HMRC monthly XML document, streamed from `reader`.
+    ///
+    /// The document is parsed as it is read, nothing is buffered whole,
+    /// so file handles, response bodies and decompressors all work.
+    /// The month replaces any table already held for it.
+    /// Returns the month loaded; on error `self` is unchanged.
+    ///
+    /// Files may be UTF-8, with or without a BOM, or ISO-8859-1/Windows-1252 as their
+    /// declaration says; text that is not UTF-8 in an older file declaring it is read
+    /// as Windows-1252. Namespaced elements, CDATA and padding around values are fine.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let file = std::fs::File::open("monthly_xml_2026-07.xml")?;
+    /// let month = rates.load_reader(file)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn load_reader(&mut self, reader: impl Read) -> Result<YearMonth, LoadError> {
+        let year_month = self.set_month(
+            monthly_entries(BufReader::new(reader), self.duplicate_rates())?,
+            Source::Reader,
+        );
+        Ok(year_month)
+    }
+
+    /// Only the rates in one HMRC monthly CSV file, see [`Rates::load_csv`].
+    pub fn from_csv(
+        reader: impl Read,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Rates, LoadError> {
+        let mut rates = Rates::empty();
+        rates.load_csv(reader, year_month)?;
+        Ok(rates)
+    }
+
+    /// Adds `year_month` from HMRC's monthly CSV layout, the alternative to the XML download.
+    ///
+    /// Columns are found by header (`Currency Code`, `Currency Units per £1`), others are ignored.
+    /// The file does not name its month reliably, so the caller does.
+    /// The month replaces any table already held for it; on error `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let mut rates = Rates::new();
+    /// let file = std::fs::File::open("exrates-monthly-0726.csv")?;
+    /// rates.load_csv(file, YearMonth::new(2026, 7).unwrap())?;
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    pub fn load_csv(
+        &mut self,
+        mut reader: impl Read,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<(), LoadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let entries = dedup(parse::parse_rates_csv(&bytes)?, self.duplicate_rates())?;
+        let key = year_month.into().key();
+        self.set_period(RateType::Monthly, key, entries);
+        self.record_source(key, Source::Reader);
+        Ok(())
+    }
+
+    /// Only the rates in one trade-tariff API response, see [`Rates::load_api_json`].
+    #[cfg(feature = "json")]
+    pub fn from_api_json(json: &str) -> Result<Rates, LoadError> {
+        let mut rates = Rates::empty();
+        rates.load_api_json(json)?;
+        Ok(rates)
+    }
+
+    /// Adds the month in one trade-tariff API exchange-rate response.
+    ///
+    /// The API serves the monthly rates as JSON:API, one `exchange_rate` resource
+    /// per currency in `included`, each with its `currency_code`, `rate`
+    /// and `validity_start_date`. Other resources and attributes are ignored.
+    /// Every rate must fall in the same month, which is returned;
+    /// it replaces any table already held. On error `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let json = r#"{"data": {}, "included": [{"type": "exchange_rate", "attributes":
+    ///     {"currency_code": "USD", "rate": "1.3541", "validity_start_date": "2025-08-01"}}]}"#;
+    /// let rates = Rates::from_api_json(json)?;
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn load_api_json(&mut self, json: &str) -> Result<YearMonth, LoadError> {
+        let (year_month, entries) = api_entries(json, self.duplicate_rates())?;
+        self.set_period(RateType::Monthly, year_month.key(), entries);
+        self.record_source(year_month.key(), Source::Reader);
+        Ok(year_month)
+    }
+
+    /// Only the usable rates in one monthly XML document, see [`Rates::load_reader_lenient`].
+    pub fn from_reader_lenient(reader: impl Read) -> Result<(Rates, Vec<ParseWarning>), LoadError> {
+        let mut rates = Rates::empty();
+        let (_, warnings) = rates.load_reader_lenient(reader)?;
+        Ok((rates, warnings))
+    }
+
+    /// [`Rates::load_reader`], skipping records with a bad code or an unparseable rate.
+    ///
+    /// One bad row then costs only that currency, not the whole month.
+    /// Each skipped record is reported, so nothing disappears silently.
+    /// The document must still be well-formed XML with a valid period
+    /// and at least one usable record, and duplicate rates must still agree.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let file = std::fs::File::open("third-party-2026-07.xml")?;
+    /// let (month, warnings) = rates.load_reader_lenient(file)?;
+    /// for warning in &warnings {
+    ///     eprintln!("{month}: {warning}");
+    /// }
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    pub fn load_reader_lenient(
+        &mut self,
+        reader: impl Read,
+    ) -> Result<(YearMonth, Vec<ParseWarning>), LoadError> {
+        let mut parser = parse::MonthlyXml::lenient();
+        parser.read(BufReader::new(reader))?;
+        let mut warnings: Vec<ParseWarning> = parser
+            .take_skipped()
+            .into_iter()
+            .map(|(record, e)| ParseWarning {
+                record,
+                kind: ParseWarningKind::Skipped,
+                reason: e.reason,
+            })
+            .collect();
+        let mut month = month_table(parser.finish()?, self.duplicate_rates())?;
+        warnings.append(&mut month.overruled);
+        warnings.sort_by_key(|w| w.record);
+        let year_month = self.set_month(month, Source::Reader);
+        Ok((year_month, warnings))
+    }
+
+    /// [`Rates::load_reader`] for an async reader, such as a response body or `tokio::fs::File`.
+    ///
+    /// The document is parsed as it arrives, without blocking the runtime.
+    /// Fetching stays with you: any async HTTP client's body stream will do.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), hmrc_rates::LoadError> {
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let file = tokio::fs::File::open("monthly_xml_2026-07.xml").await?;
+    /// let month = rates.load_reader_async(file).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn load_reader_async(
+        &mut self,
+        reader: impl tokio::io::AsyncRead + Unpin,
+    ) -> Result<YearMonth, LoadError> {
+        let mut reader = quick_xml::Reader::from_reader(tokio::io::BufReader::new(reader));
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+        let mut parser = parse::MonthlyXml::default();
+        loop {
+            buf.clear();
+            let event = reader
+                .read_event_into_async(&mut buf)
+                .await
+                .map_err(|e| parse::ParseError::new(format!("XML error: {e}")));
+            let more = event.and_then(|event| parser.feed(event));
+            if !more.map_err(|e| e.at(reader.buffer_position()))? {
+                break;
+            }
+        }
+        let month = month_table(parser.finish()?, self.duplicate_rates())?;
+        let year_month = self.set_month(month, Source::Reader);
+        Ok(year_month)
+    }
+
+    /// Only the rates in a directory of HMRC monthly files, see [`Rates::load_dir`].
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Rates, LoadError> {
+        let mut rates = Rates::empty();
+        rates.load_dir(dir)?;
+        Ok(rates)
+    }
+
+    /// Adds every `exrates-monthly-*.xml` file in `dir`, as HMRC names its downloads.
+    ///
+    /// Lets a deployment ship newer files next to the binary instead of rebuilding.
+    /// Files load in name order; the month comes from each document, not its name.
+    /// With the `parallel` feature they are parsed on the rayon pool.
+    /// Subdirectories and other files are ignored.
+    /// Returns the months loaded, in load order; on error `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let months = rates.load_dir("/etc/hmrc-rates")?;
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<Vec<YearMonth>, LoadError> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        // Parse everything first: `self` only changes once every file is valid
+        let mut months = Vec::new();
+        for (path, result) in parse_dir(dir.as_ref(), self.duplicate_rates())? {
+            match result {
+                Ok(month) => months.push((path, month)),
+                Err(e) => {
+                    return Err(LoadError::File {
+                        path,
+                        source: Box::new(e),
+                    });
+                }
+            }
+        }
+        let months: Vec<YearMonth> = months
+            .into_iter()
+            .map(|(path, month)| self.set_month(month, file_source(&path)))
+            .collect();
+        event!(debug!(
+            dir = %dir.as_ref().display(),
+            months = months.len(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "directory loaded"
+        ));
+        Ok(months)
+    }
+
+    /// [`Rates::load_dir`], keeping every file that parses and reporting the rest.
+    ///
+    /// For a directory that is better partly loaded than not at all:
+    /// a corrupt download then costs only its own month.
+    /// Fails as a whole only when the directory itself cannot be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let report = rates.load_dir_partial("/etc/hmrc-rates")?;
+    /// for failure in report.failures() {
+    ///     eprintln!("not loaded: {failure}");
+    /// }
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    pub fn load_dir_partial(&mut self, dir: impl AsRef<Path>) -> Result<LoadReport, LoadError> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        let mut report = LoadReport::default();
+        for (path, result) in parse_dir(dir.as_ref(), self.duplicate_rates())? {
+            match result {
+                Ok(month) => {
+                    let month = self.set_month(month, file_source(&path));
+                    report.loaded.push(month);
+                }
+                Err(e) => {
+                    event!(warn!(path = %path.display(), error = %e, "file skipped"));
+                    report.failures.push(LoadError::File {
+                        path,
+                        source: Box::new(e),
+                    });
+                }
+            }
+        }
+        event!(debug!(
+            dir = %dir.as_ref().display(),
+            months = report.loaded.len(),
+            skipped = report.failures.len(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "directory loaded"
+        ));
+        Ok(report)
+    }
+
+    /// Only the rates in an HMRC ZIP bundle, see [`Rates::load_zip`].
+    #[cfg(feature = "zip")]
+    pub fn from_zip(archive: impl Read + std::io::Seek) -> Result<Rates, LoadError> {
+        let mut rates = Rates::empty();
+        rates.load_zip(archive)?;
+        Ok(rates)
+    }
+
+    /// Adds every monthly XML file inside a ZIP archive, such as HMRC's yearly bundles.
+    ///
+    /// Every `.xml` entry is loaded, in name order, wherever it sits in the archive.
+    /// Returns the months loaded, in load order; on error `self` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::Rates;
+    ///
+    /// let mut rates = Rates::new();
+    /// let zip = std::fs::File::open("exrates-monthly-2019.zip")?;
+    /// let months = rates.load_zip(zip)?;
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn load_zip(
+        &mut self,
+        archive: impl Read + std::io::Seek,
+    ) -> Result<Vec<YearMonth>, LoadError> {
+        let mut archive = zip::ZipArchive::new(archive)?;
+        let mut names: Vec<String> = archive
+            .file_names()
+            .filter(|name| name.ends_with(".xml"))
+            .map(String::from)
+            .collect();
+        names.sort();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let mut rates = self.clone();
+        let mut months = Vec::with_capacity(names.len());
+        for name in names {
+            let loaded = archive
+                .by_name(&name)
+                .map_err(LoadError::from)
+                .and_then(|file| rates.load_reader(file));
+            match loaded {
+                Ok(month) => {
+                    rates.record_source(month.key(), Source::ZipEntry { name });
+                    months.push(month);
+                }
+                Err(e) => {
+                    return Err(LoadError::File {
+                        path: name.into(),
+                        source: Box::new(e),
+                    });
+                }
+            }
+        }
+        *self = rates;
+        event!(debug!(
+            months = months.len(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "zip loaded"
+        ));
+        Ok(months)
+    }
+}
+
+/// What [`Rates::load_dir_partial`] loaded and what it had to leave out.
+#[derive(Debug, Default)]
+pub struct LoadReport {
+    loaded: Vec<YearMonth>,
+    failures: Vec<LoadError>,
+}
+
+impl LoadReport {
+    /// The months loaded, in load order.
+    pub fn loaded(&self) -> &[YearMonth] {
+        &self.loaded
+    }
+
+    /// One [`LoadError::File`] per file that was skipped, in name order.
+    pub fn failures(&self) -> &[LoadError] {
+        &self.failures
+    }
+
+    /// `true` if every file loaded.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+type Parsed = Result<Month, LoadError>;
+
+/// Every `exrates-monthly-*.xml` file in `dir`, parsed, in name order.
+fn parse_dir(dir: &Path, rule: DuplicateRates) -> Result<Vec<(PathBuf, Parsed)>, LoadError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let monthly = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("exrates-monthly-") && n.ends_with(".xml"));
+        if monthly && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let parse = |path: PathBuf| {
+        let parsed = File::open(&path)
+            .map_err(LoadError::from)
+            .and_then(|file| monthly_entries(BufReader::new(file), rule));
+        (path, parsed)
+    };
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        Ok(paths.into_par_iter().map(parse).collect())
+    }
+    #[cfg(not(feature = "parallel"))]
+    Ok(paths.into_iter().map(parse).collect())
+}
+
+/// One monthly document, parsed and ready to insert.
+fn monthly_entries(input: impl std::io::BufRead, rule: DuplicateRates) -> Parsed {
+    Ok(month_table(parse::parse_monthly_xml_from(input)?, rule)?)
+}
+
+/// A monthly document checked and converted to table rows.
+pub(crate) struct Month {
+    pub year_month: YearMonth,
+    pub entries: Vec<Entry>,
+    pub countries: Vec<CountryRow>,
+    /// Rows whose rate lost to another for their currency.
+    pub overruled: Vec<ParseWarning>,
+}
+
+pub(crate) fn month_table(
+    doc: parse::MonthlyDoc,
+    rule: DuplicateRates,
+) -> Result<Month, parse::ParseError> {
+    let (year, month) = doc.period;
+    let year_month = YearMonth::new(year, month)
+        .ok_or_else(|| parse::ParseError::new(format!("bad month {year}-{month}")))?;
+    let countries = doc
+        .countries
+        .into_iter()
+        .map(|c| CountryRow {
+            code: c.country_code.into(),
+            name: c.country_name.into(),
+            currency: c.code,
+            currency_name: c.currency_name.into(),
+        })
+        .collect();
+    let (rates, overruled) = parse::dedup(&doc.rates, duplicates(rule))?;
+    let overruled = overruled
+        .into_iter()
+        .map(|o| ParseWarning {
+            record: doc.records.get(o.index).copied().unwrap_or(o.index + 1),
+            kind: ParseWarningKind::Overruled,
+            reason: format!(
+                "{} at {} lost to {}",
+                String::from_utf8_lossy(&o.rate.code),
+                parse::decimal(o.rate),
+                parse::decimal(o.kept)
+            ),
+        })
+        .collect();
+    Ok(Month {
+        year_month,
+        entries: entries(rates),
+        countries,
+        overruled,
+    })
+}
+
+impl Rates {
+    /// Inserts a parsed month with its country listing; returns the month.
+    pub(crate) fn set_month(&mut self, month: Month, source: Source) -> YearMonth {
+        event!(trace!(month = %month.year_month, rates = month.entries.len(), "month added"));
+        let key = month.year_month.key();
+        self.set_period(RateType::Monthly, key, month.entries);
+        self.set_countries(key, month.countries);
+        self.record_source(key, source);
+        month.year_month
+    }
+}
+
+pub(crate) fn file_source(path: &Path) -> Source {
+    Source::File {
+        path: path.display().to_string(),
+    }
+}
+
+#[cfg(feature = "json")]
+fn api_entries(json: &str, rule: DuplicateRates) -> Result<(YearMonth, Vec<Entry>), LoadError> {
+    #[derive(serde::Deserialize)]
+    struct Response {
+        #[serde(default)]
+        included: Vec<Resource>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Resource {
+        #[serde(rename = "type")]
+        kind: String,
+        #[serde(default)]
+        attributes: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let bad = |reason: String| LoadError::BadData {
+        reason,
+        offset: None,
+    };
+    let response: Response =
+        serde_json::from_str(json).map_err(|e| bad(format!("bad API JSON: {e}")))?;
+    let mut year_month = None;
+    let mut raw = Vec::new();
+    for resource in response.included {
+        if resource.kind != "exchange_rate" {
+            continue;
+        }
+        let text = |name: &str| match resource.attributes.get(name) {
+            Some(serde_json::Value::String(s)) => Ok(s.clone()),
+            Some(serde_json::Value::Number(n)) => Ok(n.to_string()),
+            _ => Err(bad(format!("exchange_rate without a {name}"))),
+        };
+        let start = text("validity_start_date")?;
+        let month = start
+            .parse::<chrono::NaiveDate>()
+            .map_err(|_| bad(format!("bad validity_start_date {start:?}")))?
+            .into();
+        if let Some(other) = year_month.filter(|m| *m != month) {
+            return Err(bad(format!("rates for both {other} and {month}")));
+        }
+        year_month = Some(month);
+        let (mantissa, scale) = parse::parse_rate_decimal(&text("rate")?)?;
+        raw.push(ParsedRate {
+            code: parse::parse_code(&text("currency_code")?)?,
+            mantissa,
+            scale,
+        });
+    }
+    let year_month = year_month.ok_or_else(|| bad("no exchange_rate resources".into()))?;
+    Ok((year_month, dedup(raw, rule)?))
+}
+
+/// HMRC files repeat a currency for each country using it; keep one rate by `rule`.
+pub(crate) fn dedup(
+    raw: Vec<ParsedRate>,
+    rule: DuplicateRates,
+) -> Result<Vec<Entry>, parse::ParseError> {
+    Ok(entries(parse::dedup(&raw, duplicates(rule))?.0))
+}
+
+fn duplicates(rule: DuplicateRates) -> parse::Duplicates {
+    match rule {
+        DuplicateRates::Majority => parse::Duplicates::Majority,
+        DuplicateRates::KeepFirst => parse::Duplicates::First,
+        DuplicateRates::KeepLast => parse::Duplicates::Last,
+        DuplicateRates::Reject => parse::Duplicates::Reject,
+    }
+}
+
+fn entries(rates: Vec<ParsedRate>) -> Vec<Entry> {
+    rates
+        .into_iter()
+        .map(|r| Entry {
+            mantissa: r.mantissa,
+            code: r.code,
+            scale: r.scale,
+        })
+        .collect()
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::NaiveDate;
+
+use crate::conversion::Conversion;
+use crate::rates::Rates;
+use crate::types::{Currency, Period, YearMonth};
+
+/// Upper bounds, in months, of the fallback distance histogram's buckets.
+const FALLBACK_BUCKETS: [u64; 6] = [0, 1, 2, 3, 6, 12];
+
+/// Counters for the service and the fetcher, rendered in the Prometheus text format.
+///
+/// Clones share their counts: hand one to `Updater::with_metrics`
+/// and the same to `serve::router_with_metrics` to report both on one `/metrics` endpoint.
+///
+/// | Metric | Kind | Counts |
+/// | --- | --- | --- |
+/// | `hmrc_rates_conversions_total{currency}` | counter | conversions recorded, by currency |
+/// | `hmrc_rates_lookup_fallback_months` | histogram | months between the requested and the used monthly table |
+/// | `hmrc_rates_fetch_successes_total` | counter | files the fetcher downloaded, or found unpublished |
+/// | `hmrc_rates_fetch_failures_total{code}` | counter | failed downloads, by `FetchError::code` |
+/// | `hmrc_rates_months_loaded` | gauge | monthly tables held |
+/// | `hmrc_rates_data_age_days` | gauge | days since the newest monthly table's month ended |
+///
+/// The data age is zero or negative while the newest table covers today:
+/// alert on `hmrc_rates_data_age_days > 0` to hear of stale rates.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use hmrc_rates::{Metrics, Rates, YearMonth};
+/// use rust_decimal::Decimal;
+///
+/// let rates = Rates::new();
+/// let metrics = Metrics::new();
+/// let month = YearMonth::new(2025, 8).unwrap();
+/// let conversion = rates.convert_detailed(Decimal::from(100), "USD", month)?;
+/// metrics.record_conversion(month, &conversion);
+///
+/// let text = metrics.render(&rates, NaiveDate::from_ymd_opt(2025, 8, 15).unwrap());
+/// assert!(text.contains("hmrc_rates_conversions_total{currency=\"USD\"} 1\n"));
+/// # Ok::<(), hmrc_rates::ConvertError>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    conversions: Mutex<BTreeMap<Currency, u64>>,
+    /// Per bucket, not cumulative; the last one is `+Inf`.
+    fallback: [AtomicU64; FALLBACK_BUCKETS.len() + 1],
+    fallback_sum: AtomicU64,
+    fetch_successes: AtomicU64,
+    fetch_failures: Mutex<BTreeMap<&'static str, u64>>,
+}
+
+impl Metrics {
+    /// Metrics with every count at zero.
+    pub fn new() -> Metrics {
+        Metrics::default()
+    }
+
+    /// Counts `conversion`, made for `requested`, by currency, and for a monthly rate
+    /// how many months from `requested` its table is, as a [`LookupPolicy`](crate::LookupPolicy)
+    /// fallback finds it.
+    pub fn record_conversion(&self, requested: YearMonth, conversion: &Conversion) {
+        *lock(&self.inner.conversions)
+            .entry(conversion.currency())
+            .or_default() += 1;
+        if let Period::YearMonth(used) = conversion.period() {
+            let distance = u64::from((used.key() - requested.key()).unsigned_abs());
+            let bucket = FALLBACK_BUCKETS
+                .iter()
+                .position(|&bound| distance <= bound)
+                .unwrap_or(FALLBACK_BUCKETS.len());
+            self.inner.fallback[bucket].fetch_add(1, Ordering::Relaxed);
+            self.inner
+                .fallback_sum
+                .fetch_add(distance, Ordering::Relaxed);
+        }
+    }
+
+    /// Counts one download's outcome.
+    #[cfg(feature = "http")]
+    pub(crate) fn record_fetch<T>(&self, result: &Result<T, crate::http::FetchError>) {
+        match result {
+            Ok(_) => {
+                self.inner.fetch_successes.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => {
+                *lock(&self.inner.fetch_failures)
+                    .entry(e.code())
+                    .or_default() += 1
+            }
+        }
+    }
+
+    /// Every metric in the Prometheus text exposition format,
+    /// with the gauges read from `rates` as of `today`.
+    pub fn render(&self, rates: &Rates, today: NaiveDate) -> String {
+        let mut out = String::new();
+        help(
+            &mut out,
+            "hmrc_rates_conversions_total",
+            "counter",
+            "Conversions to GBP, by currency.",
+        );
+        for (currency, count) in lock(&self.inner.conversions).iter() {
+            let _ = writeln!(
+                out,
+                "hmrc_rates_conversions_total{{currency=\"{currency}\"}} {count}"
+            );
+        }
+
+        help(
+            &mut out,
+            "hmrc_rates_lookup_fallback_months",
+            "histogram",
+            "Months between the requested and the used monthly table.",
+        );
+        let mut count = 0;
+        for (n, bucket) in self.inner.fallback.iter().enumerate() {
+            count += bucket.load(Ordering::Relaxed);
+            let bound = FALLBACK_BUCKETS
+                .get(n)
+                .map_or_else(|| "+Inf".into(), u64::to_string);
+            let _ = writeln!(
+                out,
+                "hmrc_rates_lookup_fallback_months_bucket{{le=\"{bound}\"}} {count}"
+            );
+        }
+        let sum = self.inner.fallback_sum.load(Ordering::Relaxed);
+        let _ = writeln!(out, "hmrc_rates_lookup_fallback_months_sum {sum}");
+        let _ = writeln!(out, "hmrc_rates_lookup_fallback_months_count {count}");
+
+        help(
+            &mut out,
+            "hmrc_rates_fetch_successes_total",
+            "counter",
+            "Files downloaded from HMRC, or found not yet published.",
+        );
+        let successes = self.inner.fetch_successes.load(Ordering::Relaxed);
+        let _ = writeln!(out, "hmrc_rates_fetch_successes_total {successes}");
+        help(
+            &mut out,
+            "hmrc_rates_fetch_failures_total",
+            "counter",
+            "Failed downloads from HMRC, by error code.",
+        );
+        for (code, count) in lock(&self.inner.fetch_failures).iter() {
+            let _ = writeln!(
+                out,
+                "hmrc_rates_fetch_failures_total{{code=\"{code}\"}} {count}"
+            );
+        }
+
+        help(
+            &mut out,
+            "hmrc_rates_months_loaded",
+            "gauge",
+            "Monthly tables held.",
+        );
+        let _ = writeln!(out, "hmrc_rates_months_loaded {}", rates.months().count());
+        let end = rates
+            .latest_month()
+            .and_then(|month| Period::YearMonth(month).end());
+        if let Some(end) = end {
+            help(
+                &mut out,
+                "hmrc_rates_data_age_days",
+                "gauge",
+                "Days since the newest monthly table's month ended.",
+            );
+            let _ = writeln!(out, "hmrc_rates_data_age_days {}", (today - end).num_days());
+        }
+        out
+    }
+}
+
+impl core::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
+}
+
+fn help(out: &mut String, name: &str, kind: &str, text: &str) {
+    let _ = writeln!(out, "# HELP {name} {text}\n# TYPE {name} {kind}");
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
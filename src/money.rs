@@ -0,0 +1,339 @@
+use core::fmt;
+use core::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::error::ConvertError;
+use crate::gbp::{Gbp, Rounding};
+use crate::number::NumberFormat;
+use crate::source::RateSource;
+use crate::types::{Currency, YearMonth};
+
+/// An exact amount in one foreign currency, e.g. a line of an expense export.
+///
+/// Keeps the amount and its currency together instead of a loose `(Decimal, &str)` pair.
+/// Parses `"1234.56 USD"` and symbol forms such as `"€1234.56"`;
+/// displays with the symbol where one is unambiguous (`€`, `$`, `¥` for yen, ...)
+/// and the code otherwise, `{:#}` always with the code. Never rounds.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{ForeignMoney, Rates, YearMonth};
+///
+/// let invoice: ForeignMoney = "1234.56 eur".parse()?;
+/// assert_eq!(invoice.to_string(), "€1234.56");
+/// assert_eq!(format!("{invoice:#}"), "1234.56 EUR");
+/// assert_eq!("€1234.56".parse::<ForeignMoney>()?, invoice);
+///
+/// let gbp = invoice.to_gbp(&Rates::new(), YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForeignMoney {
+    amount: Decimal,
+    currency: Currency,
+}
+
+/// Symbols that name one currency, by code. Longest first, so `CN¥` wins over `¥`.
+const SYMBOLS: &[(&str, [u8; 3])] = &[
+    ("CN¥", *b"CNY"),
+    ("A$", *b"AUD"),
+    ("C$", *b"CAD"),
+    ("£", *b"GBP"),
+    ("€", *b"EUR"),
+    ("$", *b"USD"),
+    ("¥", *b"JPY"),
+    ("₹", *b"INR"),
+    ("₩", *b"KRW"),
+    ("₪", *b"ILS"),
+    ("₦", *b"NGN"),
+    ("₺", *b"TRY"),
+];
+
+impl ForeignMoney {
+    /// `amount` of `currency`.
+    pub fn new(amount: Decimal, currency: Currency) -> ForeignMoney {
+        ForeignMoney { amount, currency }
+    }
+
+    /// The amount, in [`ForeignMoney::currency`].
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// The currency of the amount.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The amount laid out for one market's invoices, see [`MoneyFormat`].
+    pub fn format_with(self, format: MoneyFormat) -> FormattedMoney {
+        FormattedMoney {
+            money: self,
+            format,
+        }
+    }
+
+    /// Rounded to the currency's [minor units](Currency::minor_units) with `rounding`.
+    pub fn round(self, rounding: Rounding) -> ForeignMoney {
+        ForeignMoney::new(
+            rounding.apply(self.amount, self.currency.minor_units()),
+            self.currency,
+        )
+    }
+
+    /// Converts to GBP at `source`'s monthly rate for `year_month`, exactly;
+    /// see [`RateSource::to_gbp`] for how it fails.
+    ///
+    /// Any [`RateSource`] works: [`Rates`](crate::Rates), a [`SourceChain`](crate::SourceChain), a test double.
+    pub fn to_gbp(
+        &self,
+        source: &(impl RateSource + ?Sized),
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Gbp, ConvertError> {
+        source.to_gbp(self.amount, self.currency.as_str(), year_month.into())
+    }
+}
+
+/// How [`ForeignMoney::format_with`] lays an amount out: separators, where the
+/// symbol goes, and optionally rounding to the currency's minor units.
+///
+/// The presets cover the common invoicing layouts; build others from a
+/// [`NumberFormat`]. A currency without an unambiguous symbol is always written
+/// with its code. Symbols after the amount, and codes, are set off by a no-break space.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{ForeignMoney, MoneyFormat, NumberFormat, Rounding};
+/// use rust_decimal::Decimal;
+///
+/// let invoice = ForeignMoney::new(Decimal::new(123456, 2), "EUR".parse()?);
+/// assert_eq!(invoice.format_with(MoneyFormat::UK).to_string(), "€1,234.56");
+/// assert_eq!(invoice.format_with(MoneyFormat::FRENCH).to_string(), "1\u{202f}234,56\u{a0}€");
+/// assert_eq!(invoice.format_with(MoneyFormat::EUROPEAN).to_string(), "1.234,56\u{a0}€");
+///
+/// let swiss = MoneyFormat::new(NumberFormat::SWISS).with_code().minor_units(Rounding::HalfUp);
+/// let fee = ForeignMoney::new(Decimal::new(-98765, 3), "CHF".parse()?);
+/// assert_eq!(fee.format_with(swiss).to_string(), "-CHF\u{a0}98.77");
+/// # Ok::<(), hmrc_rates::ParseCurrencyError>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MoneyFormat {
+    number: NumberFormat,
+    after: bool,
+    code: bool,
+    minor_units: Option<Rounding>,
+}
+
+impl MoneyFormat {
+    /// `€1,234.56`, as in the UK, Ireland and the US.
+    pub const UK: MoneyFormat = MoneyFormat::new(NumberFormat::UK);
+
+    /// `1 234,56 €`, as in France.
+    pub const FRENCH: MoneyFormat = MoneyFormat::new(NumberFormat::FRENCH).symbol_after();
+
+    /// `1.234,56 €`, as in Germany, Italy and Spain.
+    pub const EUROPEAN: MoneyFormat = MoneyFormat::new(NumberFormat::EUROPEAN).symbol_after();
+
+    /// The layout for a locale such as `"fr-FR"`, `"de_CH.UTF-8"` or `"en"`,
+    /// or `None` for a locale without one here.
+    ///
+    /// Matches the language and region exactly, ignoring case, scripts and POSIX
+    /// codesets and modifiers; a bare language takes its home layout. Regions not
+    /// listed get `None` rather than a guess, as their conventions often differ
+    /// (`en-IN` groups in lakhs, `de-AT` puts the symbol first). Covers English in
+    /// the UK, Ireland, the US, Australia and New Zealand, Welsh and Irish
+    /// ([`MoneyFormat::UK`]), French in France, Belgium and Canada
+    /// ([`MoneyFormat::FRENCH`]), German, Italian and Spanish in their home countries
+    /// ([`MoneyFormat::EUROPEAN`]; Mexican and US Spanish as `UK`), and Swiss German
+    /// and Italian, which write `CHF 1'234.50`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::MoneyFormat;
+    ///
+    /// assert_eq!(MoneyFormat::for_locale("fr-CA"), Some(MoneyFormat::FRENCH));
+    /// assert_eq!(MoneyFormat::for_locale("de_DE.UTF-8"), Some(MoneyFormat::EUROPEAN));
+    /// assert_eq!(MoneyFormat::for_locale("de-AT"), None);
+    /// assert_eq!(MoneyFormat::for_locale("tlh"), None);
+    /// ```
+    pub fn for_locale(locale: &str) -> Option<MoneyFormat> {
+        let tag = locale.trim().split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().filter(|l| !l.is_empty())?;
+        let region = subtags
+            .find(|s| s.len() == 2 || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())));
+        LOCALES.iter().find_map(|&(key, format)| {
+            let (l, r) = key
+                .split_once('-')
+                .map_or((key, None), |(l, r)| (l, Some(r)));
+            let same_region = match (r, region) {
+                (None, None) => true,
+                (Some(r), Some(region)) => r.eq_ignore_ascii_case(region),
+                _ => false,
+            };
+            (same_region && l.eq_ignore_ascii_case(language)).then_some(format)
+        })
+    }
+
+    /// `number`'s separators, the symbol before the amount, every digit kept.
+    pub const fn new(number: NumberFormat) -> MoneyFormat {
+        MoneyFormat {
+            number,
+            after: false,
+            code: false,
+            minor_units: None,
+        }
+    }
+
+    /// The symbol or code after the amount.
+    pub const fn symbol_after(mut self) -> MoneyFormat {
+        self.after = true;
+        self
+    }
+
+    /// The currency code instead of the symbol, e.g. `CHF 1'234.50`.
+    pub const fn with_code(mut self) -> MoneyFormat {
+        self.code = true;
+        self
+    }
+
+    /// Rounded to the currency's [minor units](Currency::minor_units) with `rounding`.
+    pub const fn minor_units(mut self, rounding: Rounding) -> MoneyFormat {
+        self.minor_units = Some(rounding);
+        self
+    }
+}
+
+/// The locales [`MoneyFormat::for_locale`] knows, by language or `language-REGION`.
+const LOCALES: &[(&str, MoneyFormat)] = &[
+    ("cy", MoneyFormat::UK),
+    ("cy-GB", MoneyFormat::UK),
+    ("de", MoneyFormat::EUROPEAN),
+    ("de-CH", MoneyFormat::new(NumberFormat::SWISS).with_code()),
+    ("de-DE", MoneyFormat::EUROPEAN),
+    ("en", MoneyFormat::UK),
+    ("en-AU", MoneyFormat::UK),
+    ("en-GB", MoneyFormat::UK),
+    ("en-IE", MoneyFormat::UK),
+    ("en-NZ", MoneyFormat::UK),
+    ("en-US", MoneyFormat::UK),
+    ("es", MoneyFormat::EUROPEAN),
+    ("es-ES", MoneyFormat::EUROPEAN),
+    ("es-MX", MoneyFormat::UK),
+    ("es-US", MoneyFormat::UK),
+    ("fr", MoneyFormat::FRENCH),
+    ("fr-BE", MoneyFormat::FRENCH),
+    ("fr-CA", MoneyFormat::FRENCH),
+    ("fr-FR", MoneyFormat::FRENCH),
+    ("ga", MoneyFormat::UK),
+    ("ga-IE", MoneyFormat::UK),
+    ("it", MoneyFormat::EUROPEAN),
+    ("it-CH", MoneyFormat::new(NumberFormat::SWISS).with_code()),
+    ("it-IT", MoneyFormat::EUROPEAN),
+];
+
+/// A [`ForeignMoney`] laid out by [`MoneyFormat`], see [`ForeignMoney::format_with`].
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedMoney {
+    money: ForeignMoney,
+    format: MoneyFormat,
+}
+
+impl fmt::Display for FormattedMoney {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let MoneyFormat {
+            number,
+            after,
+            code,
+            minor_units,
+        } = self.format;
+        let money = match minor_units {
+            Some(rounding) => self.money.round(rounding),
+            None => self.money,
+        };
+        let symbol = SYMBOLS
+            .iter()
+            .find(|(_, c)| *c == money.currency.code())
+            .map(|(symbol, _)| *symbol)
+            .filter(|_| !code);
+        let (label, space) = match symbol {
+            Some(symbol) => (symbol, if after { "\u{a0}" } else { "" }),
+            None => (money.currency.as_str(), "\u{a0}"),
+        };
+        if money.amount.is_sign_negative() && !money.amount.is_zero() {
+            f.write_str("-")?;
+        }
+        if !after {
+            write!(f, "{label}{space}")?;
+        }
+        number.write_abs(f, money.amount)?;
+        if after {
+            write!(f, "{space}{label}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Input that is neither `"<amount> <code>"` nor a known symbol and an amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMoneyError;
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid amount, expected a number and a currency code like \"1234.56 USD\"")
+    }
+}
+
+impl core::error::Error for ParseMoneyError {}
+
+/// Parses `"1234.56 USD"` (any case, any whitespace between)
+/// or a symbol before the amount, with an optional leading `-`: `"-€5"`, `"€-5"`.
+impl FromStr for ForeignMoney {
+    type Err = ParseMoneyError;
+
+    fn from_str(s: &str) -> Result<ForeignMoney, ParseMoneyError> {
+        let s = s.trim();
+        let mut parts = s.split_whitespace();
+        if let (Some(amount), Some(code), None) = (parts.next(), parts.next(), parts.next()) {
+            let amount = amount.parse().map_err(|_| ParseMoneyError)?;
+            let currency = code.parse().map_err(|_| ParseMoneyError)?;
+            return Ok(ForeignMoney::new(amount, currency));
+        }
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (amount, code) = SYMBOLS
+            .iter()
+            .find_map(|(symbol, code)| Some((rest.strip_prefix(symbol)?, *code)))
+            .ok_or(ParseMoneyError)?;
+        let amount: Decimal = amount.parse().map_err(|_| ParseMoneyError)?;
+        let amount = if negative { -amount } else { amount };
+        Ok(ForeignMoney::new(amount, Currency::from_code(code)))
+    }
+}
+
+impl fmt::Display for ForeignMoney {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = SYMBOLS
+            .iter()
+            .find(|(_, code)| *code == self.currency.code())
+            .map(|(symbol, _)| *symbol);
+        match symbol {
+            Some(symbol) if !f.alternate() => {
+                let sign = if self.amount.is_sign_negative() && !self.amount.is_zero() {
+                    "-"
+                } else {
+                    ""
+                };
+                write!(f, "{sign}{symbol}{}", self.amount.abs())
+            }
+            _ => write!(f, "{} {}", self.amount, self.currency),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use rust_decimal::Decimal;
+
+/// How amounts in text write their decimal point and thousands separator,
+/// see [`Rates::convert_str_with`](crate::Rates::convert_str_with).
+///
+/// Separators must sit between groups of three digits, so `"1.234,56"` reads as
+/// 1234.56 in [`NumberFormat::EUROPEAN`] but `"12.34,56"` is rejected, never guessed.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::NumberFormat;
+/// use rust_decimal::Decimal;
+///
+/// assert_eq!(NumberFormat::EUROPEAN.parse("-1.234,56"), Some(Decimal::new(-123456, 2)));
+/// assert_eq!(NumberFormat::UK.parse("1,234.56"), Some(Decimal::new(123456, 2)));
+/// assert_eq!(NumberFormat::UK.parse("1234.56"), Some(Decimal::new(123456, 2)));
+/// assert_eq!(NumberFormat::UK.parse("1,23.4"), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NumberFormat {
+    decimal: char,
+    grouping: Option<char>,
+}
+
+impl NumberFormat {
+    /// `1234.56` only: what [`Rates::convert_str`](crate::Rates::convert_str) accepts.
+    pub const PLAIN: NumberFormat = NumberFormat::new('.', None);
+
+    /// `1,234.56` or `1234.56`.
+    pub const UK: NumberFormat = NumberFormat::new('.', Some(','));
+
+    /// `1.234,56` or `1234,56`, as in most of the EU.
+    pub const EUROPEAN: NumberFormat = NumberFormat::new(',', Some('.'));
+
+    /// `1'234.56`, as in Switzerland.
+    pub const SWISS: NumberFormat = NumberFormat::new('.', Some('\''));
+
+    /// `1 234,56` with a narrow no-break space, as in France.
+    pub const FRENCH: NumberFormat = NumberFormat::new(',', Some('\u{202f}'));
+
+    /// A custom layout; `grouping` must differ from `decimal` to be of any use.
+    pub const fn new(decimal: char, grouping: Option<char>) -> NumberFormat {
+        NumberFormat { decimal, grouping }
+    }
+
+    /// Writes `amount` in this layout, every digit kept, grouped if the layout groups;
+    /// [`NumberFormat::parse`] reads it back.
+    ///
+    /// ```
+    /// use hmrc_rates::NumberFormat;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let amount = Decimal::new(-123456789, 3);
+    /// assert_eq!(NumberFormat::EUROPEAN.format(amount), "-123.456,789");
+    /// assert_eq!(NumberFormat::UK.format(amount), "-123,456.789");
+    /// assert_eq!(NumberFormat::PLAIN.format(amount), "-123456.789");
+    /// ```
+    pub fn format(&self, amount: Decimal) -> String {
+        let mut out = String::new();
+        if amount.is_sign_negative() && !amount.is_zero() {
+            out.push('-');
+        }
+        let _ = self.write_abs(&mut out, amount);
+        out
+    }
+
+    /// `amount` without its sign, in this layout.
+    pub(crate) fn write_abs(&self, out: &mut impl Write, amount: Decimal) -> fmt::Result {
+        let digits = alloc::format!("{}", amount.abs());
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+        for (i, digit) in whole.chars().enumerate() {
+            if let Some(grouping) = self
+                .grouping
+                .filter(|_| i > 0 && (whole.len() - i) % 3 == 0)
+            {
+                out.write_char(grouping)?;
+            }
+            out.write_char(digit)?;
+        }
+        if let Some(fraction) = fraction {
+            out.write_char(self.decimal)?;
+            out.write_str(fraction)?;
+        }
+        Ok(())
+    }
+
+    /// Reads an amount with an optional leading `-` or `+`; `None` unless it is well formed.
+    pub fn parse(&self, text: &str) -> Option<Decimal> {
+        let text = text.trim();
+        let (sign, unsigned) = match text.strip_prefix(['-', '+']) {
+            Some(rest) => (&text[..1], rest),
+            None => ("", text),
+        };
+        let (whole, fraction) = match unsigned.split_once(self.decimal) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let mut plain = String::from(sign);
+        match self.grouping.filter(|&g| whole.contains(g)) {
+            Some(grouping) => {
+                let mut groups = whole.split(grouping);
+                let first = groups.next()?;
+                if !(1..=3).contains(&first.len()) {
+                    return None;
+                }
+                plain.push_str(first);
+                for group in groups {
+                    if group.len() != 3 {
+                        return None;
+                    }
+                    plain.push_str(group);
+                }
+            }
+            None => plain.push_str(whole),
+        }
+        if let Some(fraction) = fraction {
+            plain.push('.');
+            plain.push_str(fraction);
+        }
+        let digits = plain.trim_start_matches(['-', '+']);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return None;
+        }
+        plain.parse().ok()
+    }
+}
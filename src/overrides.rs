@@ -0,0 +1,108 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use rust_decimal::Decimal;
+
+use crate::load::LoadError;
+use crate::rates::Rates;
+use crate::types::{Currency, YearMonth};
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default, rename = "override")]
+    overrides: Vec<Override>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Override {
+    month: String,
+    currency: String,
+    rate: toml::Value,
+    /// Free text for reviewers, such as the erratum it applies; not kept.
+    #[serde(default, rename = "note")]
+    _note: Option<String>,
+}
+
+impl Rates {
+    /// Sets the monthly rates listed in a TOML overrides file over the data already held,
+    /// for HMRC errata and internal corrections kept in reviewable config.
+    ///
+    /// Each `[[override]]` has a `month` (`"YYYY-MM"`), a `currency` code and a `rate`
+    /// in units per £1, plus an optional `note` that is ignored. Write rates as strings
+    /// to keep every digit as typed; numbers are accepted too. Each one goes through
+    /// [`Rates::insert_rate`], so the months they touch report [`Source::Manual`](crate::Source::Manual)
+    /// as their [`Rates::provenance`]. Later entries win over earlier ones.
+    ///
+    /// Returns the months changed, ascending. On error `self` is unchanged.
+    /// Apply overrides after loading or fetching: a later load of the month replaces them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, Source, YearMonth};
+    ///
+    /// let mut rates = Rates::new();
+    /// let months = rates.load_overrides(r#"
+    ///     [[override]]
+    ///     month = "2025-08"
+    ///     currency = "USD"
+    ///     rate = "1.3540"
+    ///     note = "HMRC erratum of 12 August"
+    /// "#)?;
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// assert_eq!(months, [aug]);
+    /// assert_eq!(*rates.provenance(aug).unwrap().source(), Source::Manual);
+    /// # Ok::<(), hmrc_rates::LoadError>(())
+    /// ```
+    pub fn load_overrides(&mut self, toml: &str) -> Result<Vec<YearMonth>, LoadError> {
+        let bad = |reason: String| LoadError::BadData {
+            reason,
+            offset: None,
+        };
+        let file: File =
+            toml::from_str(toml).map_err(|e| bad(format!("bad overrides TOML: {e}")))?;
+        let mut rates = self.clone();
+        let mut months = BTreeSet::new();
+        for (n, entry) in (1..).zip(file.overrides) {
+            let at = |reason: String| bad(format!("override {n}: {reason}"));
+            let month: YearMonth = entry
+                .month
+                .parse()
+                .map_err(|_| at(format!("bad month {:?}", entry.month)))?;
+            let currency: Currency = entry
+                .currency
+                .parse()
+                .map_err(|_| at(format!("bad currency {:?}", entry.currency)))?;
+            let text = match &entry.rate {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Integer(i) => i.to_string(),
+                toml::Value::Float(f) => f.to_string(),
+                other => return Err(at(format!("bad rate of type {}", other.type_str()))),
+            };
+            let rate: Decimal = text.parse().map_err(|_| at(format!("bad rate {text:?}")))?;
+            rates
+                .insert_rate(month, currency, rate)
+                .map_err(|e| at(e.to_string()))?;
+            months.insert(month);
+        }
+        *self = rates;
+        Ok(months.into_iter().collect())
+    }
+
+    /// [`Rates::load_overrides`] from a file; errors name it.
+    pub fn load_overrides_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<YearMonth>, LoadError> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .map_err(LoadError::from)
+            .and_then(|toml| self.load_overrides(&toml))
+            .map_err(|e| LoadError::File {
+                path: path.into(),
+                source: Box::new(e),
+            })
+    }
+}
@@ -1,24 +1,47 @@
 // Shared by build.rs via #[path]; must not use `crate::` paths
 // Parses the canonical committed/downloaded formats into (code, mantissa, scale) rows
 
+// build.rs and the library each use their own subset: the weekly series, majority
+// dedup and file-stem parsing are build-time only, lenient parsing is runtime only.
+#![allow(dead_code)]
+
 #[path = "date.rs"]
 pub(crate) mod date;
 
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseError(pub String);
+pub struct ParseError {
+    pub reason: String,
+    /// Bytes of XML read before the failure, where a streaming parse knows it.
+    pub offset: Option<u64>,
+}
+
+impl ParseError {
+    pub fn new(reason: impl Into<String>) -> ParseError {
+        ParseError {
+            reason: reason.into(),
+            offset: None,
+        }
+    }
+
+    /// Records where the input stopped, unless an inner reader already did.
+    pub fn at(mut self, offset: u64) -> ParseError {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.reason)
     }
 }
 
 impl std::error::Error for ParseError {}
 
 fn err<T>(reason: impl Into<String>) -> Result<T, ParseError> {
-    Err(ParseError(reason.into()))
+    Err(ParseError::new(reason))
 }
 
 /// One parsed rate: `mantissa / 10^scale` currency units per £1.
@@ -29,8 +52,27 @@ pub struct ParsedRate {
     pub scale: u8,
 }
 
+/// The country and names an HMRC monthly record lists alongside its rate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCountry {
+    pub country_code: String, // HMRC's own two letters, not always ISO 3166
+    pub country_name: String,
+    pub currency_name: String,
+    pub code: [u8; 3],
+}
+
+/// One monthly document: its period, raw (possibly duplicated) rates
+/// and the countries listed, where the file names them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthlyDoc {
+    pub period: (i32, u32),
+    pub rates: Vec<ParsedRate>,
+    /// Each rate's 1-based `exchangeRate` position; a lenient parse skips some.
+    pub records: Vec<usize>,
+    pub countries: Vec<ParsedCountry>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(dead_code)] // consumed by build.rs; the weekly series is never fetched at runtime
 pub struct WeeklyRow {
     pub date: (i32, u32, u32), // ISO year, month, day
     pub rate: ParsedRate,
@@ -59,7 +101,7 @@ pub fn parse_rate_decimal(s: &str) -> Result<(u64, u8), ParseError> {
         mantissa = mantissa
             .checked_mul(10)
             .and_then(|m| m.checked_add(u64::from(b - b'0')))
-            .ok_or_else(|| ParseError(format!("rate '{s}' overflows")))?;
+            .ok_or_else(|| ParseError::new(format!("rate '{s}' overflows")))?;
     }
     if mantissa == 0 {
         return err(format!("rate '{s}' is not positive"));
@@ -84,9 +126,24 @@ const MONTHS: [&str; 12] = [
     "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
 ];
 
+const LONG_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
 fn parse_dmy(s: &str) -> Result<(i32, u32, u32), ParseError> {
-    // "01/Jul/2026"
-    let mut parts = s.trim().split('/');
+    // "01/Jul/2026"; older files also use "01/07/2026", "01-Jul-2026" or "01/July/2026"
+    let mut parts = s.trim().split(['/', '-']);
     let (Some(d), Some(m), Some(y), None) =
         (parts.next(), parts.next(), parts.next(), parts.next())
     else {
@@ -94,15 +151,23 @@ fn parse_dmy(s: &str) -> Result<(i32, u32, u32), ParseError> {
     };
     let day: u32 = d
         .parse()
-        .map_err(|_| ParseError(format!("bad day in '{s}'")))?;
-    let month = MONTHS
-        .iter()
-        .position(|name| name.eq_ignore_ascii_case(m))
-        .ok_or_else(|| ParseError(format!("bad month in '{s}'")))? as u32
-        + 1;
+        .map_err(|_| ParseError::new(format!("bad day in '{s}'")))?;
+    let month = match m.parse::<u32>() {
+        Ok(month @ 1..=12) => month,
+        Ok(_) => return err(format!("bad month in '{s}'")),
+        Err(_) => {
+            MONTHS
+                .iter()
+                .chain(&LONG_MONTHS)
+                .position(|name| name.eq_ignore_ascii_case(m))
+                .ok_or_else(|| ParseError::new(format!("bad month in '{s}'")))? as u32
+                % 12
+                + 1
+        }
+    };
     let year: i32 = y
         .parse()
-        .map_err(|_| ParseError(format!("bad year in '{s}'")))?;
+        .map_err(|_| ParseError::new(format!("bad year in '{s}'")))?;
     Ok((year, month, day))
 }
 
@@ -111,7 +176,7 @@ fn parse_dmy(s: &str) -> Result<(i32, u32, u32), ParseError> {
 pub fn parse_month_period(period: &str) -> Result<(i32, u32), ParseError> {
     let (start, end) = period
         .split_once(" to ")
-        .ok_or_else(|| ParseError(format!("bad Period '{period}'")))?;
+        .ok_or_else(|| ParseError::new(format!("bad Period '{period}'")))?;
     let (sy, sm, sd) = parse_dmy(start)?;
     let (ey, em, ed) = parse_dmy(end)?;
     if sd != 1 {
@@ -123,79 +188,226 @@ pub fn parse_month_period(period: &str) -> Result<(i32, u32), ParseError> {
     Ok((sy, sm))
 }
 
-/// Parses HMRC monthly XML; returns the period and raw (possibly duplicated) rates.
-pub fn parse_monthly_xml(bytes: &[u8]) -> Result<((i32, u32), Vec<ParsedRate>), ParseError> {
-    use quick_xml::events::Event;
+/// Parses HMRC monthly XML.
+///
+/// UTF-8 with or without a BOM, or ISO-8859-1/Windows-1252 as declared; text that is
+/// not valid UTF-8 in a file declaring UTF-8 (or nothing) is read as Windows-1252,
+/// as older downloads are. Elements match by local name, whatever their namespace,
+/// and values may sit in CDATA sections, padded with whitespace.
+pub fn parse_monthly_xml(bytes: &[u8]) -> Result<MonthlyDoc, ParseError> {
+    parse_monthly_xml_from(bytes)
+}
 
-    let text = std::str::from_utf8(bytes).map_err(|_| ParseError("XML is not UTF-8".into()))?;
-    let mut reader = quick_xml::Reader::from_str(text);
-    reader.config_mut().trim_text(true);
+/// [`parse_monthly_xml`] streaming from a reader, one event at a time.
+pub fn parse_monthly_xml_from(input: impl std::io::BufRead) -> Result<MonthlyDoc, ParseError> {
+    let mut parser = MonthlyXml::default();
+    parser.read(input)?;
+    parser.finish()
+}
 
-    let mut period: Option<(i32, u32)> = None;
-    let mut rates = Vec::new();
-    let mut field: Option<&'static str> = None;
-    let mut code: Option<[u8; 3]> = None;
-    let mut rate: Option<(u64, u8)> = None;
-
-    loop {
-        match reader.read_event() {
-            Err(e) => return err(format!("XML error: {e}")),
-            Ok(Event::Eof) => break,
-            Ok(Event::Start(el)) => match el.local_name().as_ref() {
+/// Monthly XML as a push parser, so any event source (sync or async) can drive it.
+/// The source must trim text, see [`MonthlyXml::read`].
+#[derive(Default)]
+pub struct MonthlyXml {
+    period: Option<(i32, u32)>,
+    rates: Vec<ParsedRate>,
+    rate_records: Vec<usize>,
+    countries: Vec<ParsedCountry>,
+    field: Option<&'static str>,
+    code: Option<[u8; 3]>,
+    rate: Option<(u64, u8)>,
+    country_code: Option<String>,
+    country_name: String,
+    currency_name: String,
+    closed: bool,
+    lenient: bool,
+    encoding: Encoding,
+    records: usize,
+    problem: Option<ParseError>,
+    skipped: Vec<(usize, ParseError)>,
+}
+
+impl MonthlyXml {
+    /// A parser that skips malformed `exchangeRate` records instead of failing,
+    /// see [`MonthlyXml::take_skipped`]. The document itself must still be sound.
+    pub fn lenient() -> MonthlyXml {
+        MonthlyXml {
+            lenient: true,
+            ..MonthlyXml::default()
+        }
+    }
+
+    /// Feeds every event from `input` until the document ends.
+    pub fn read(&mut self, input: impl std::io::BufRead) -> Result<(), ParseError> {
+        let mut reader = quick_xml::Reader::from_reader(input);
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let event = reader
+                .read_event_into(&mut buf)
+                .map_err(|e| ParseError::new(format!("XML error: {e}")));
+            let more = event.and_then(|event| self.feed(event));
+            if !more.map_err(|e| e.at(reader.buffer_position()))? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The records a lenient parser skipped so far, by 1-based position.
+    pub fn take_skipped(&mut self) -> Vec<(usize, ParseError)> {
+        std::mem::take(&mut self.skipped)
+    }
+
+    /// Consumes one event; `false` once the document has ended.
+    pub fn feed(&mut self, event: quick_xml::events::Event<'_>) -> Result<bool, ParseError> {
+        use quick_xml::events::Event;
+
+        match event {
+            Event::Eof => return Ok(false),
+            Event::Start(el) => match el.local_name().as_ref() {
                 b"exchangeRateMonthList" => {
                     for attr in el.attributes() {
-                        let attr = attr.map_err(|e| ParseError(format!("bad attribute: {e}")))?;
-                        if attr.key.as_ref() == b"Period" {
-                            let value = attr
-                                .unescape_value()
-                                .map_err(|e| ParseError(format!("bad Period: {e}")))?;
-                            period = Some(parse_month_period(&value)?);
+                        let attr =
+                            attr.map_err(|e| ParseError::new(format!("bad attribute: {e}")))?;
+                        if attr.key.local_name().as_ref() == b"Period" {
+                            let value = self
+                                .encoding
+                                .unescape(&attr.value)
+                                .map_err(|e| ParseError::new(format!("bad Period: {e}")))?;
+                            self.period = Some(parse_month_period(&value)?);
                         }
                     }
-                    if period.is_none() {
+                    if self.period.is_none() {
                         return err("exchangeRateMonthList has no Period attribute");
                     }
                 }
                 b"exchangeRate" => {
-                    code = None;
-                    rate = None;
+                    self.records += 1;
+                    self.code = None;
+                    self.rate = None;
+                    self.country_code = None;
+                    self.country_name.clear();
+                    self.currency_name.clear();
+                    self.problem = None;
                 }
-                b"currencyCode" => field = Some("code"),
-                b"rateNew" => field = Some("rate"),
-                _ => field = None,
+                b"countryCode" => self.field = Some("country_code"),
+                b"countryName" => self.field = Some("country_name"),
+                b"currencyName" => self.field = Some("currency_name"),
+                b"currencyCode" => self.field = Some("code"),
+                b"rateNew" | b"rate" => self.field = Some("rate"), // `rate` in older files
+                _ => self.field = None,
             },
-            Ok(Event::Text(t)) => {
-                let value = t
-                    .unescape()
-                    .map_err(|e| ParseError(format!("bad text: {e}")))?;
-                match field {
-                    Some("code") => code = Some(parse_code(&value)?),
-                    Some("rate") => rate = Some(parse_rate_decimal(&value)?),
-                    _ => {}
+            Event::Decl(decl) => {
+                if let Some(label) = decl.encoding() {
+                    let label =
+                        label.map_err(|e| ParseError::new(format!("bad declaration: {e}")))?;
+                    self.encoding = Encoding::for_label(&String::from_utf8_lossy(&label))?;
+                }
+            }
+            Event::Text(t) => {
+                if self.period.is_none()
+                    && (t.starts_with(b"\xff\xfe") || t.starts_with(b"\xfe\xff"))
+                {
+                    return err(format!("XML is UTF-16; {}", Encoding::SUPPORTED));
                 }
+                let value = self
+                    .encoding
+                    .unescape(&t)
+                    .map_err(|e| ParseError::new(format!("bad text: {e}")))?;
+                self.text(&value)?;
+            }
+            Event::CData(t) => {
+                let value = self.encoding.decode(&t);
+                self.text(value.trim())?;
             }
-            Ok(Event::End(el)) => {
+            Event::End(el) => {
                 if el.local_name().as_ref() == b"exchangeRate" {
-                    let (Some(c), Some((mantissa, scale))) = (code.take(), rate.take()) else {
-                        return err("exchangeRate record missing currencyCode or rateNew");
-                    };
-                    rates.push(ParsedRate {
-                        code: c,
-                        mantissa,
-                        scale,
-                    });
+                    match (self.problem.take(), self.code.take(), self.rate.take()) {
+                        (None, Some(code), Some((mantissa, scale))) => {
+                            self.rates.push(ParsedRate {
+                                code,
+                                mantissa,
+                                scale,
+                            });
+                            self.rate_records.push(self.records);
+                            if let Some(country_code) = self.country_code.take() {
+                                self.countries.push(ParsedCountry {
+                                    country_code,
+                                    country_name: std::mem::take(&mut self.country_name),
+                                    currency_name: std::mem::take(&mut self.currency_name),
+                                    code,
+                                });
+                            }
+                        }
+                        (problem, ..) => {
+                            let problem = problem.unwrap_or_else(|| {
+                                ParseError::new(
+                                    "exchangeRate record missing currencyCode or rateNew",
+                                )
+                            });
+                            if !self.lenient {
+                                return Err(problem);
+                            }
+                            self.skipped.push((self.records, problem));
+                        }
+                    }
+                }
+                if el.local_name().as_ref() == b"exchangeRateMonthList" {
+                    self.closed = true;
                 }
-                field = None;
+                self.field = None;
             }
-            Ok(_) => {}
+            _ => {}
         }
+        Ok(true)
     }
 
-    let period = period.ok_or_else(|| ParseError("no exchangeRateMonthList element".into()))?;
-    if rates.is_empty() {
-        return err("no exchangeRate records");
+    /// Stores a text or CDATA value for the field being read.
+    fn text(&mut self, value: &str) -> Result<(), ParseError> {
+        let parsed = match self.field {
+            Some("code") => parse_code(value).map(|code| self.code = Some(code)),
+            Some("rate") => parse_rate_decimal(value).map(|rate| self.rate = Some(rate)),
+            Some("country_code") => {
+                self.country_code = Some(value.to_owned());
+                Ok(())
+            }
+            Some("country_name") => {
+                self.country_name = value.to_owned();
+                Ok(())
+            }
+            Some("currency_name") => {
+                self.currency_name = value.to_owned();
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        match parsed {
+            Err(e) if self.lenient => self.problem = self.problem.take().or(Some(e)),
+            parsed => parsed?,
+        }
+        Ok(())
+    }
+
+    /// The whole document, once every event is fed.
+    pub fn finish(self) -> Result<MonthlyDoc, ParseError> {
+        let period = self
+            .period
+            .ok_or_else(|| ParseError::new("no exchangeRateMonthList element"))?;
+        // A truncated download can end cleanly between records
+        if !self.closed {
+            return err("document ends before </exchangeRateMonthList>");
+        }
+        if self.rates.is_empty() {
+            return err("no exchangeRate records");
+        }
+        Ok(MonthlyDoc {
+            period,
+            rates: self.rates,
+            records: self.rate_records,
+            countries: self.countries,
+        })
     }
-    Ok((period, rates))
 }
 
 /// Parses the canonical average/spot CSV (`...,Currency Code,...,Currency Units per £1`).
@@ -204,7 +416,7 @@ pub fn parse_rates_csv(bytes: &[u8]) -> Result<Vec<ParsedRate>, ParseError> {
     let mut reader = csv::Reader::from_reader(text.as_bytes());
     let headers = reader
         .headers()
-        .map_err(|e| ParseError(format!("bad CSV: {e}")))?;
+        .map_err(|e| ParseError::new(format!("bad CSV: {e}")))?;
 
     let col = |needle: &str| {
         headers
@@ -212,13 +424,13 @@ pub fn parse_rates_csv(bytes: &[u8]) -> Result<Vec<ParsedRate>, ParseError> {
             .position(|h| h.trim().eq_ignore_ascii_case(needle) || h.trim().starts_with(needle))
     };
     let code_col =
-        col("Currency Code").ok_or_else(|| ParseError("no 'Currency Code' column".into()))?;
+        col("Currency Code").ok_or_else(|| ParseError::new("no 'Currency Code' column"))?;
     let rate_col = col("Currency Units per")
-        .ok_or_else(|| ParseError("no 'Currency Units per £1' column".into()))?;
+        .ok_or_else(|| ParseError::new("no 'Currency Units per £1' column"))?;
 
     let mut rates = Vec::new();
     for record in reader.records() {
-        let record = record.map_err(|e| ParseError(format!("bad CSV row: {e}")))?;
+        let record = record.map_err(|e| ParseError::new(format!("bad CSV row: {e}")))?;
         let code = record.get(code_col).unwrap_or("");
         let rate = record.get(rate_col).unwrap_or("");
         if code.trim().is_empty() && rate.trim().is_empty() {
@@ -238,13 +450,12 @@ pub fn parse_rates_csv(bytes: &[u8]) -> Result<Vec<ParsedRate>, ParseError> {
 }
 
 /// Parses the normalized weekly amendments CSV (`Date,Country,Currency Name,Currency Code,Rate`).
-#[allow(dead_code)] // consumed by build.rs; the weekly series is never fetched at runtime
 pub fn parse_weekly_csv(bytes: &[u8]) -> Result<Vec<WeeklyRow>, ParseError> {
     let text = decode_utf8_lossy_bom(bytes);
     let mut reader = csv::Reader::from_reader(text.as_bytes());
     let headers = reader
         .headers()
-        .map_err(|e| ParseError(format!("bad CSV: {e}")))?;
+        .map_err(|e| ParseError::new(format!("bad CSV: {e}")))?;
     let find = |name: &str| {
         headers
             .iter()
@@ -258,7 +469,7 @@ pub fn parse_weekly_csv(bytes: &[u8]) -> Result<Vec<WeeklyRow>, ParseError> {
 
     let mut rows = Vec::new();
     for record in reader.records() {
-        let record = record.map_err(|e| ParseError(format!("bad CSV row: {e}")))?;
+        let record = record.map_err(|e| ParseError::new(format!("bad CSV row: {e}")))?;
         let date = parse_iso_date(record.get(date_col).unwrap_or(""))?;
         let code = parse_code(record.get(code_col).unwrap_or(""))?;
         let (mantissa, scale) = parse_rate_decimal(record.get(rate_col).unwrap_or(""))?;
@@ -286,69 +497,147 @@ fn parse_iso_date(s: &str) -> Result<(i32, u32, u32), ParseError> {
     };
     let year: i32 = y
         .parse()
-        .map_err(|_| ParseError(format!("bad ISO date '{s}'")))?;
+        .map_err(|_| ParseError::new(format!("bad ISO date '{s}'")))?;
     let month: u32 = m
         .parse()
-        .map_err(|_| ParseError(format!("bad ISO date '{s}'")))?;
+        .map_err(|_| ParseError::new(format!("bad ISO date '{s}'")))?;
     let day: u32 = d
         .parse()
-        .map_err(|_| ParseError(format!("bad ISO date '{s}'")))?;
+        .map_err(|_| ParseError::new(format!("bad ISO date '{s}'")))?;
     if !(1..=12).contains(&month) || day == 0 || day > date::days_in_month(year, month) {
         return err(format!("invalid date '{s}'"));
     }
     Ok((year, month, day))
 }
 
+/// How [`dedup`] picks one rate for a currency listed at different rates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    /// See [`dedup_majority`].
+    #[default]
+    Majority,
+    First,
+    Last,
+    Reject,
+}
+
+/// A row [`dedup`] dropped for disagreeing with the rate kept for its currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overruled {
+    /// The row's position in the input.
+    pub index: usize,
+    pub rate: ParsedRate,
+    pub kept: ParsedRate,
+}
+
+/// Dedups multi-country rows (EUR appears ~19 times) by `rule`, returning the
+/// rates sorted by code and the rows that disagreed with the one kept, by position.
+pub fn dedup(
+    rates: &[ParsedRate],
+    rule: Duplicates,
+) -> Result<(Vec<ParsedRate>, Vec<Overruled>), ParseError> {
+    let same = |a: &ParsedRate, b: &ParsedRate| (a.mantissa, a.scale) == (b.mantissa, b.scale);
+    let mut order: Vec<usize> = (0..rates.len()).collect();
+    order.sort_by_key(|&i| rates[i].code); // stable: each code's rows stay in input order
+    let mut out = Vec::new();
+    let mut overruled = Vec::new();
+    for group in order.chunk_by(|&a, &b| rates[a].code == rates[b].code) {
+        let rows: Vec<ParsedRate> = group.iter().map(|&i| rates[i]).collect();
+        let (first, last) = (rows[0], rows[rows.len() - 1]);
+        let kept = match rule {
+            Duplicates::Majority => majority(&rows)?,
+            Duplicates::First => first,
+            Duplicates::Last => last,
+            Duplicates::Reject => match rows.iter().find(|r| !same(r, &first)) {
+                Some(other) => {
+                    return err(format!(
+                        "'{}' is listed at both {} and {}",
+                        String::from_utf8_lossy(&first.code),
+                        decimal(first),
+                        decimal(*other)
+                    ));
+                }
+                None => first,
+            },
+        };
+        for (&index, rate) in group.iter().zip(&rows) {
+            if !same(rate, &kept) {
+                overruled.push(Overruled {
+                    index,
+                    rate: *rate,
+                    kept,
+                });
+            }
+        }
+        out.push(kept);
+    }
+    Ok((out, overruled))
+}
+
 /// Dedups multi-country rows (EUR appears ~19 times).
 /// Conflicts resolve by majority.
 /// Ties resolve to the most precise value when the tied values agree
 /// after rounding to the lower precision.
 /// (E.g. USA 1.5958134 vs Liberia 1.595813), otherwise error.
 /// Output is sorted by code.
-pub fn dedup_majority(mut rates: Vec<ParsedRate>) -> Result<Vec<ParsedRate>, ParseError> {
-    rates.sort_unstable_by_key(|r| (r.code, r.mantissa, r.scale));
-    let mut out: Vec<ParsedRate> = Vec::with_capacity(rates.len());
-    for group in rates.chunk_by(|a, b| a.code == b.code) {
-        let mut distinct: Vec<(ParsedRate, usize)> = Vec::new();
-        for rate in group {
-            match distinct
-                .iter_mut()
-                .find(|(r, _)| (r.mantissa, r.scale) == (rate.mantissa, rate.scale))
-            {
-                Some((_, count)) => *count += 1,
-                None => distinct.push((*rate, 1)),
-            }
+pub fn dedup_majority(rates: Vec<ParsedRate>) -> Result<Vec<ParsedRate>, ParseError> {
+    Ok(dedup(&rates, Duplicates::Majority)?.0)
+}
+
+/// The majority value of one currency's rows, see [`dedup_majority`].
+fn majority(group: &[ParsedRate]) -> Result<ParsedRate, ParseError> {
+    let mut distinct: Vec<(ParsedRate, usize)> = Vec::new();
+    for rate in group {
+        match distinct
+            .iter_mut()
+            .find(|(r, _)| (r.mantissa, r.scale) == (rate.mantissa, rate.scale))
+        {
+            Some((_, count)) => *count += 1,
+            None => distinct.push((*rate, 1)),
         }
-        let best_count = distinct.iter().map(|(_, c)| *c).max().unwrap_or(0);
-        let mut tied: Vec<ParsedRate> = distinct
-            .iter()
-            .filter(|(_, c)| *c == best_count)
-            .map(|(r, _)| *r)
-            .collect();
-        tied.sort_unstable_by_key(|r| r.scale);
-        let winner = match tied.as_slice() {
-            [single] => *single,
-            [] => continue,
-            _ => {
-                // Pairwise agreement: every more-precise value must round to
-                // every less-precise one (equal-scale conflicts can never agree)
-                let consistent = tied.iter().enumerate().all(|(i, a)| {
-                    tied[i + 1..]
-                        .iter()
-                        .all(|b| rounded_to(b.mantissa, b.scale, a.scale) == Some(a.mantissa))
-                });
-                if !consistent {
-                    let code_str = String::from_utf8_lossy(&group[0].code).into_owned();
-                    return err(format!(
-                        "conflicting duplicate rates for '{code_str}' with no majority"
-                    ));
-                }
-                tied[tied.len() - 1] // most precise of the agreeing values
+    }
+    let best_count = distinct.iter().map(|(_, c)| *c).max().unwrap_or(0);
+    let mut tied: Vec<ParsedRate> = distinct
+        .iter()
+        .filter(|(_, c)| *c == best_count)
+        .map(|(r, _)| *r)
+        .collect();
+    tied.sort_unstable_by_key(|r| r.scale);
+    match tied.as_slice() {
+        [single] => Ok(*single),
+        [] => err("no rates to dedup"),
+        _ => {
+            // Pairwise agreement: every more-precise value must round to
+            // every less-precise one (equal-scale conflicts can never agree)
+            let consistent = tied.iter().enumerate().all(|(i, a)| {
+                tied[i + 1..]
+                    .iter()
+                    .all(|b| rounded_to(b.mantissa, b.scale, a.scale) == Some(a.mantissa))
+            });
+            if !consistent {
+                let code_str = String::from_utf8_lossy(&group[0].code).into_owned();
+                return err(format!(
+                    "conflicting duplicate rates for '{code_str}' with no majority"
+                ));
             }
-        };
-        out.push(winner);
+            Ok(tied[tied.len() - 1]) // most precise of the agreeing values
+        }
+    }
+}
+
+/// `rate`'s value as written, e.g. `1.3541`.
+pub fn decimal(rate: ParsedRate) -> String {
+    let digits = format!(
+        "{:0>width$}",
+        rate.mantissa,
+        width = usize::from(rate.scale) + 1
+    );
+    let (int, frac) = digits.split_at(digits.len() - usize::from(rate.scale));
+    if frac.is_empty() {
+        int.to_owned()
+    } else {
+        format!("{int}.{frac}")
     }
-    Ok(out)
 }
 
 /// Rounds `mantissa` at `scale` half-up to `target` decimal places.
@@ -361,13 +650,67 @@ fn rounded_to(mantissa: u64, scale: u8, target: u8) -> Option<u64> {
     u64::try_from((u128::from(mantissa) + divisor / 2) / divisor).ok()
 }
 
+/// How a monthly document's bytes map to text, from its XML declaration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Encoding {
+    /// UTF-8, except that text which is not valid UTF-8 is read as Windows-1252.
+    #[default]
+    Utf8,
+    /// ISO-8859-1 or Windows-1252, both read as Windows-1252 like browsers do.
+    Windows1252,
+}
+
+/// Windows-1252's characters for 0x80 to 0x9F; the five it leaves undefined keep their byte.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+
+impl Encoding {
+    const SUPPORTED: &str = "only UTF-8, ISO-8859-1 and Windows-1252 are read";
+
+    fn for_label(label: &str) -> Result<Encoding, ParseError> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Ok(Encoding::Utf8),
+            "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "l1" | "windows-1252"
+            | "cp1252" | "us-ascii" | "ascii" => Ok(Encoding::Windows1252),
+            _ => err(format!(
+                "XML declares encoding '{label}'; {}",
+                Encoding::SUPPORTED
+            )),
+        }
+    }
+
+    fn decode<'a>(self, bytes: &'a [u8]) -> std::borrow::Cow<'a, str> {
+        if self == Encoding::Utf8 {
+            if let Ok(text) = std::str::from_utf8(bytes) {
+                return text.into();
+            }
+        }
+        bytes
+            .iter()
+            .map(|&b| match b {
+                0x80..=0x9f => WINDOWS_1252[usize::from(b - 0x80)],
+                _ => char::from(b),
+            })
+            .collect::<String>()
+            .into()
+    }
+
+    fn unescape(self, bytes: &[u8]) -> Result<String, quick_xml::escape::EscapeError> {
+        let text = self.decode(bytes);
+        Ok(quick_xml::escape::unescape(&text)?.into_owned())
+    }
+}
+
 fn decode_utf8_lossy_bom(bytes: &[u8]) -> std::borrow::Cow<'_, str> {
     let bytes = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
     String::from_utf8_lossy(bytes)
 }
 
 /// Parses a `"YYYY-MM"` data-file stem into (year, month).
-#[allow(dead_code)] // consumed by build.rs; the library parses names via YearMonth::from_str
 pub fn parse_year_month(s: &str) -> Option<(i32, u32)> {
     let (y, m) = s.rsplit_once('-')?;
     let year: i32 = y.parse().ok()?;
@@ -423,18 +766,50 @@ mod tests {
         assert!(parse_month_period("01/Aug/2025 to 30/Aug/2025").is_err()); // wrong end
         assert!(parse_month_period("01/Feb/2015 to 29/Feb/2015").is_err()); // not a leap year
         assert!(parse_month_period("01/Aug/2025").is_err());
+        assert!(parse_month_period("01/13/2025 to 31/13/2025").is_err());
+        assert!(parse_month_period("01/Augu/2025 to 31/Augu/2025").is_err());
+    }
+
+    #[test]
+    fn legacy_monthly_xml_variants() {
+        for period in [
+            "01/02/2014 to 28/02/2014",
+            "01-Feb-2014 to 28-Feb-2014",
+            "01/February/2014 to 28/February/2014",
+        ] {
+            assert_eq!(parse_month_period(period).unwrap(), (2014, 2), "{period}");
+        }
+        let xml = r#"<exchangeRateMonthList Period="01/12/2013 to 31/12/2013">
+              <exchangeRate><currencyCode>USD</currencyCode><rate>1.6375</rate></exchangeRate>
+            </exchangeRateMonthList>"#;
+        let doc = parse_monthly_xml(xml.as_bytes()).unwrap();
+        assert_eq!(doc.period, (2013, 12));
+        assert_eq!(doc.rates, vec![rate(b"USD", 16375, 4)]);
+        assert!(doc.countries.is_empty()); // nothing named, nothing invented
     }
 
     #[test]
     fn monthly_xml_happy_path() {
         let xml = r#"<?xml version="1.0"?>
             <exchangeRateMonthList Period="01/Aug/2025 to 31/Aug/2025">
-              <exchangeRate><countryName>USA</countryName><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>
+              <exchangeRate><countryName>USA</countryName><countryCode>US</countryCode><currencyName>Dollar </currencyName><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>
               <exchangeRate><currencyCode>eur</currencyCode><rateNew>1.1547</rateNew></exchangeRate>
             </exchangeRateMonthList>"#;
-        let ((year, month), rates) = parse_monthly_xml(xml.as_bytes()).unwrap();
-        assert_eq!((year, month), (2025, 8));
-        assert_eq!(rates, vec![rate(b"USD", 13541, 4), rate(b"EUR", 11547, 4)]);
+        let doc = parse_monthly_xml(xml.as_bytes()).unwrap();
+        assert_eq!(doc.period, (2025, 8));
+        assert_eq!(
+            doc.rates,
+            vec![rate(b"USD", 13541, 4), rate(b"EUR", 11547, 4)]
+        );
+        assert_eq!(
+            doc.countries,
+            vec![ParsedCountry {
+                country_code: "US".into(),
+                country_name: "USA".into(),
+                currency_name: "Dollar".into(),
+                code: *b"USD",
+            }]
+        );
     }
 
     #[test]
@@ -446,6 +821,57 @@ mod tests {
             <exchangeRate><currencyCode>USD</currencyCode></exchangeRate>
             </exchangeRateMonthList>"#;
         assert!(parse_monthly_xml(missing_rate).is_err());
+        let truncated = br#"<exchangeRateMonthList Period="01/Aug/2025 to 31/Aug/2025">
+            <exchangeRate><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>"#;
+        assert!(parse_monthly_xml(truncated).is_err());
+    }
+
+    #[test]
+    fn monthly_xml_encodings_namespaces_and_cdata() {
+        let record = |name: &[u8]| {
+            [
+                &b"<h:exchangeRate><h:countryName>"[..],
+                name,
+                b"</h:countryName><h:countryCode>CI</h:countryCode>\
+                  <h:currencyCode><![CDATA[ XOF ]]></h:currencyCode>\
+                  <h:rateNew>\n  <![CDATA[ 763.0434 ]]>\n</h:rateNew></h:exchangeRate>",
+            ]
+            .concat()
+        };
+        let doc = |declaration: &str, name: &[u8]| {
+            [
+                declaration.as_bytes(),
+                br#"<h:exchangeRateMonthList xmlns:h="urn:hmrc" h:Period="01/Aug/2025 to 31/Aug/2025">"#,
+                &record(name),
+                b"</h:exchangeRateMonthList>",
+            ]
+            .concat()
+        };
+        let latin1 = r#"<?xml version="1.0" encoding="ISO-8859-1"?>"#;
+        for xml in [
+            doc(latin1, b"C\xf4te d'Ivoire"),
+            doc(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                b"C\xf4te d'Ivoire",
+            ), // mislabelled
+            [
+                &b"\xef\xbb\xbf"[..],
+                &doc("", "C\u{f4}te d'Ivoire".as_bytes()),
+            ]
+            .concat(),
+        ] {
+            let doc = parse_monthly_xml(&xml).unwrap();
+            assert_eq!(doc.rates, vec![rate(b"XOF", 7630434, 4)]);
+            assert_eq!(doc.countries[0].country_name, "C\u{f4}te d'Ivoire");
+        }
+        let cp1252 = parse_monthly_xml(&doc(latin1, b"\x93Quoted\x94")).unwrap();
+        assert_eq!(cp1252.countries[0].country_name, "\u{201c}Quoted\u{201d}");
+
+        let koi8 = doc(r#"<?xml version="1.0" encoding="KOI8-R"?>"#, b"");
+        let e = parse_monthly_xml(&koi8).unwrap_err();
+        assert!(e.reason.contains("'KOI8-R'"), "{e}");
+        let e = parse_monthly_xml(b"\xff\xfe<\0e\0/\0>\0").unwrap_err();
+        assert!(e.reason.contains("UTF-16"), "{e}");
     }
 
     #[test]
@@ -512,6 +938,24 @@ mod tests {
         assert!(dedup_majority(rows).is_err());
     }
 
+    #[test]
+    fn dedup_rules_and_overruled_rows() {
+        let rows = [
+            rate(b"USD", 13541, 4),
+            rate(b"EUR", 11547, 4),
+            rate(b"USD", 136, 2),
+            rate(b"USD", 13541, 4),
+        ];
+        let (kept, overruled) = dedup(&rows, Duplicates::Last).unwrap();
+        assert_eq!(kept, vec![rate(b"EUR", 11547, 4), rate(b"USD", 13541, 4)]);
+        assert_eq!(overruled.iter().map(|o| o.index).collect::<Vec<_>>(), [2]);
+        let (_, overruled) = dedup(&rows[..3], Duplicates::First).unwrap();
+        assert_eq!(overruled[0].kept, rate(b"USD", 13541, 4));
+        let e = dedup(&rows, Duplicates::Reject).unwrap_err();
+        assert_eq!(e.reason, "'USD' is listed at both 1.3541 and 1.36");
+        assert_eq!(decimal(rate(b"JPY", 5, 3)), "0.005");
+    }
+
     #[test]
     fn dedup_tie_survives_extreme_mantissas() {
         // u64::MAX at scale 9 rounds cleanly to scale 1 — must not overflow
@@ -0,0 +1,118 @@
+//! Column-wise GBP conversion for [Polars](https://pola.rs) data frames.
+//!
+//! [`gbp_series`] converts whole columns in one call: each distinct month and currency
+//! is resolved once, so tens of millions of rows cost a handful of rate lookups
+//! and one pass of exact decimal arithmetic.
+//!
+//! ```
+//! use polars::prelude::*;
+//! use hmrc_rates::{Rates, Rounding};
+//!
+//! let df = df!(
+//!     "amount" => [Some(100i64), Some(250), None],
+//!     "currency" => ["USD", "eur", "USD"],
+//!     "date" => [20_300i32, 20_310, 20_300],
+//! )?;
+//! let dates = df.column("date")?.as_materialized_series().cast(&DataType::Date)?;
+//! let gbp = hmrc_rates::polars::gbp_series(
+//!     &Rates::new(),
+//!     df.column("amount")?.as_materialized_series(),
+//!     df.column("currency")?.as_materialized_series(),
+//!     &dates,
+//!     2,
+//!     Rounding::HalfUp,
+//! )?;
+//! assert_eq!(gbp.dtype(), &DataType::Decimal(38, 2));
+//! assert_eq!(gbp.null_count(), 1);
+//! # Ok::<(), PolarsError>(())
+//! ```
+
+use ::polars::prelude::*;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::gbp::Rounding;
+use crate::source::{RateCache, RateSource};
+use crate::types::YearMonth;
+
+/// Days from 0001-01-01 to 1970-01-01, Polars' `Date` epoch.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Converts `amounts` in `currencies` to GBP at each row's monthly rate from `source`.
+///
+/// - `amounts`: a `Decimal` or integer column. Floats are refused, cast them to `Decimal` first.
+/// - `currencies`: a `String` column of codes, any case, or aliases `source` resolves.
+/// - `dates`: a `Date` column; each row uses the rate for its month.
+///
+/// The result is a `Decimal(38, decimal_places)` column named `gbp`,
+/// rounded with `rounding`: a Polars decimal column has one scale, so the rounding is yours to pick.
+/// A null in any input gives a null row.
+/// Lookups stay strict: the first row without a rate fails the whole call, naming the row.
+pub fn gbp_series(
+    source: &(impl RateSource + ?Sized),
+    amounts: &Series,
+    currencies: &Series,
+    dates: &Series,
+    decimal_places: u32,
+    rounding: Rounding,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        amounts.len() == currencies.len() && amounts.len() == dates.len(),
+        ShapeMismatch: "amounts, currencies and dates have {}, {} and {} rows",
+        amounts.len(), currencies.len(), dates.len()
+    );
+    polars_ensure!(
+        decimal_places <= Decimal::MAX_SCALE,
+        InvalidOperation: "at most {} decimal places, got {decimal_places}", Decimal::MAX_SCALE
+    );
+    let amounts = match amounts.dtype() {
+        DataType::Decimal(..) => amounts.clone(),
+        dtype if dtype.is_integer() => amounts.strict_cast(&DataType::Decimal(38, 0))?,
+        dtype => polars_bail!(
+            InvalidOperation: "amounts must be Decimal or integer, got {dtype}; cast to Decimal first"
+        ),
+    };
+    let amounts = amounts.decimal()?;
+    let scale = amounts.scale() as u32;
+    let (codes, dates) = (currencies.str()?, dates.date()?);
+
+    let mut rates = RateCache::new(source);
+    let mut gbp = Vec::with_capacity(amounts.len());
+    let rows = amounts
+        .physical()
+        .iter()
+        .zip(codes.iter())
+        .zip(dates.physical().iter());
+    for (row, ((amount, code), days)) in rows.enumerate() {
+        let (Some(amount), Some(code), Some(days)) = (amount, code, days) else {
+            gbp.push(None);
+            continue;
+        };
+        let amount = Decimal::try_from_i128_with_scale(amount, scale)
+            .map_err(|e| polars_err!(ComputeError: "row {row}: amount out of range: {e}"))?;
+        let date = days
+            .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .ok_or_else(|| polars_err!(ComputeError: "row {row}: date out of range"))?;
+        let year_month = YearMonth::from(date);
+        let rate = rates
+            .rate(code, year_month)
+            .map_err(|e| polars_err!(ComputeError: "row {row}: {e}"))?;
+        let exact = source
+            .convert_at_rate(amount, rate)
+            .map_err(|e| polars_err!(ComputeError: "row {row}: {e}"))?
+            .gbp()
+            .amount();
+        let rounded = rounding.apply(exact, decimal_places);
+        polars_ensure!(
+            rounded.scale() == decimal_places,
+            ComputeError: "row {row}: {rounded} does not fit {decimal_places} decimal places"
+        );
+        gbp.push(Some(rounded.mantissa()));
+    }
+    Ok(
+        Int128Chunked::from_iter_options("gbp".into(), gbp.into_iter())
+            .into_decimal_unchecked(38, decimal_places as usize)
+            .into_series(),
+    )
+}
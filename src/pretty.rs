@@ -0,0 +1,109 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::error::LookupError;
+use crate::rates::Rates;
+use crate::types::YearMonth;
+
+/// The layout of [`Rates::format_month`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum TableFormat {
+    /// Boxed with `+`, `-` and `|`, for terminals and logs.
+    #[default]
+    Ascii,
+    /// A GitHub-flavoured Markdown table, rates right-aligned.
+    Markdown,
+}
+
+pub(crate) const HEADER: [&str; 3] = ["Currency", "Country", "Rate"];
+
+impl Rates {
+    /// A month's monthly table as aligned text: one row per country HMRC lists,
+    /// by currency code, then a bare row for any currency no listed country uses.
+    ///
+    /// Rates are the published units per £1. Fails as [`Rates::monthly`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, TableFormat, YearMonth};
+    ///
+    /// let text = Rates::new().format_month(YearMonth::new(2025, 8).unwrap(), TableFormat::Ascii)?;
+    /// assert!(text.lines().any(|line| line.starts_with("| JPY      | Japan")));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn format_month(
+        &self,
+        year_month: impl Into<YearMonth>,
+        format: TableFormat,
+    ) -> Result<String, LookupError> {
+        Ok(render(&self.month_rows(year_month.into())?, format))
+    }
+
+    /// A month's rows for [`Rates::format_month`] and exports: currency, country, rate.
+    pub(crate) fn month_rows(
+        &self,
+        year_month: YearMonth,
+    ) -> Result<Vec<[String; 3]>, LookupError> {
+        let table = self.monthly(year_month)?;
+        let mut rows: Vec<[String; 3]> = Vec::with_capacity(table.len());
+        for (currency, rate) in table.iter() {
+            let rate = rate.units_per_gbp().to_string();
+            let mut countries: Vec<_> = self.countries_using(currency, year_month).collect();
+            countries.sort_by_key(|country| country.name());
+            if countries.is_empty() {
+                rows.push([currency.to_string(), String::new(), rate]);
+                continue;
+            }
+            for country in countries {
+                rows.push([currency.to_string(), country.name().into(), rate.clone()]);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+fn render(rows: &[[String; 3]], format: TableFormat) -> String {
+    let mut widths = HEADER.map(|title| title.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let [w0, w1, w2] = widths;
+    let line = |out: &mut String, cells: [&str; 3]| {
+        let _ = writeln!(
+            out,
+            "| {:<w0$} | {:<w1$} | {:>w2$} |",
+            cells[0], cells[1], cells[2]
+        );
+    };
+    let rule = match format {
+        TableFormat::Ascii => alloc::format!(
+            "+{:-<a$}+{:-<b$}+{:-<c$}+",
+            "",
+            "",
+            "",
+            a = w0 + 2,
+            b = w1 + 2,
+            c = w2 + 2
+        ),
+        TableFormat::Markdown => alloc::format!("| {:-<w0$} | {:-<w1$} | {:->w2$} |", "", "", ":"),
+    };
+    let mut out = String::new();
+    let boxed = format == TableFormat::Ascii;
+    if boxed {
+        let _ = writeln!(out, "{rule}");
+    }
+    line(&mut out, HEADER);
+    let _ = writeln!(out, "{rule}");
+    for row in rows {
+        line(&mut out, [&row[0], &row[1], &row[2]]);
+    }
+    if boxed {
+        let _ = writeln!(out, "{rule}");
+    }
+    out
+}
@@ -0,0 +1,95 @@
+use alloc::format;
+use alloc::string::String;
+
+use chrono::{DateTime, Utc};
+
+use crate::rates::Rates;
+use crate::types::YearMonth;
+
+/// Where a month's monthly table came from, see [`Rates::provenance`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Source {
+    /// Compiled in with `bundled`, from this file of the data directory, e.g. `monthly/2025-08.xml`.
+    Bundled { file: String },
+    /// A file on disk: a directory load, or the `http` disk cache read at startup.
+    File { path: String },
+    /// An entry of a ZIP archive loaded with `load_zip`.
+    ZipEntry { name: String },
+    /// Fetched by `Updater` from this URL, over the network or from its fresh disk-cache copy.
+    Url { url: String },
+    /// Streamed from a reader that has no name: `load_reader`, `load_csv`, `load_api_json`.
+    Reader,
+    /// Set rate by rate with [`Rates::insert_rate`] or [`Rates::with_rate`].
+    Manual,
+    /// Restored from a snapshot, a delta or the serde form, none of which carries provenance.
+    Restored,
+}
+
+/// A month's [`Source`] and when it was loaded, see [`Rates::provenance`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Provenance {
+    source: Source,
+    loaded_at: Option<DateTime<Utc>>,
+}
+
+impl Provenance {
+    /// `source`, loaded now as far as a `no_std` build can tell.
+    pub(crate) fn now(source: Source) -> Provenance {
+        #[cfg(feature = "std")]
+        let loaded_at = Some(Utc::now());
+        #[cfg(not(feature = "std"))]
+        let loaded_at = None;
+        Provenance { source, loaded_at }
+    }
+
+    /// Where the table came from.
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// When it was put into this dataset, by the system clock; `None` for bundled and
+    /// restored months, and without `std`, where there is no clock.
+    pub fn loaded_at(&self) -> Option<DateTime<Utc>> {
+        self.loaded_at
+    }
+}
+
+impl Rates {
+    /// Where the monthly table for `year_month` came from, for audit trails;
+    /// `None` if no table is held for it.
+    ///
+    /// Tracked for every way a monthly table gets in, and kept through clones
+    /// and [`Rates::merge`]. A later load of the same month replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, Source, YearMonth};
+    ///
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// let provenance = Rates::new().provenance(aug).unwrap();
+    /// assert_eq!(
+    ///     *provenance.source(),
+    ///     Source::Bundled { file: "monthly/2025-08.xml".into() }
+    /// );
+    /// ```
+    pub fn provenance(&self, year_month: impl Into<YearMonth>) -> Option<Provenance> {
+        let year_month = year_month.into();
+        let key = year_month.key();
+        self.monthly(year_month).ok()?;
+        if let Some(provenance) = self.recorded_provenance(key) {
+            return Some(provenance.clone());
+        }
+        let source = match self.is_bundled_month(key) {
+            true => Source::Bundled {
+                file: format!("monthly/{year_month}.xml"),
+            },
+            false => Source::Restored,
+        };
+        Some(Provenance {
+            source,
+            loaded_at: None,
+        })
+    }
+}
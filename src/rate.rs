@@ -1,11 +1,20 @@
 use rust_decimal::Decimal;
 
+use crate::error::ArithmeticError;
+use crate::gbp::Rounding;
 use crate::types::{Currency, Period};
 
 /// A resolved HMRC rate: currency units per £1, with exact `Decimal` arithmetic.
 ///
-/// The crate never rounds.
-/// Callers apply whatever rounding their tax context requires.
+/// Conversions are exact unless you call a `*_rounded` method such as
+/// [`Rate::from_gbp_rounded`]; otherwise callers apply the rounding their tax context requires.
+///
+/// `Decimal` holds 28 significant digits, so quotients that do not terminate are cut
+/// there, and results too large for it panic. The `checked_` conversions fail instead:
+/// with [`ArithmeticError::Overflow`], or [`ArithmeticError::PrecisionLoss`] when
+/// an inexact result keeps fewer than [`Rate::GUARANTEED_PLACES`] decimal places,
+/// as happens to amounts in the billions of billions.
+/// Their round trips back are bounded by [`Rate::round_trip_drift`].
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rate {
@@ -15,6 +24,9 @@ pub struct Rate {
 }
 
 impl Rate {
+    /// The decimal places a `checked_` conversion's result keeps, unless it is exact.
+    pub const GUARANTEED_PLACES: u32 = 12;
+
     pub(crate) fn new(units_per_gbp: Decimal, currency: Currency, period: Period) -> Rate {
         Rate {
             units_per_gbp,
@@ -44,15 +56,152 @@ impl Rate {
     /// println!("£{}", gbp.round_dp(2));
     /// # Ok::<(), hmrc_rates::LookupError>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the result does not fit a `Decimal`; see [`Rate::checked_to_gbp`].
     pub fn to_gbp(&self, amount: Decimal) -> Decimal {
         amount / self.units_per_gbp
     }
 
+    /// [`Rate::to_gbp`], failing instead of overflowing or keeping fewer than
+    /// [`Rate::GUARANTEED_PLACES`] decimal places of an inexact quotient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{ArithmeticError, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let usd = Rates::new().monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?;
+    /// assert_eq!(usd.checked_to_gbp(Decimal::from(2500)), Ok(usd.to_gbp(Decimal::from(2500))));
+    /// assert!(matches!(
+    ///     usd.checked_to_gbp(Decimal::MAX),
+    ///     Err(ArithmeticError::PrecisionLoss { .. })
+    /// ));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn checked_to_gbp(&self, amount: Decimal) -> Result<Decimal, ArithmeticError> {
+        checked_div(amount, self.units_per_gbp)
+    }
+
     /// Converts a GBP amount to this rate's currency (`gbp * units_per_gbp`).
+    ///
+    /// # Panics
+    ///
+    /// If the result does not fit a `Decimal`; see [`Rate::checked_from_gbp`].
     pub fn from_gbp(&self, gbp: Decimal) -> Decimal {
         gbp * self.units_per_gbp
     }
 
+    /// [`Rate::from_gbp`], failing as [`Rate::checked_to_gbp`] does.
+    pub fn checked_from_gbp(&self, gbp: Decimal) -> Result<Decimal, ArithmeticError> {
+        checked_mul(gbp, self.units_per_gbp)
+    }
+
+    /// The most `self.from_gbp(gbp)` can differ from `amount`, in this rate's currency,
+    /// when `gbp` is a successful [`Rate::checked_to_gbp`] of `amount` rounded to
+    /// `decimal_places` with `rounding`.
+    ///
+    /// Rounding to pence moves the sterling figure by up to half a penny, or a whole one
+    /// with [`Rounding::TowardZero`] and [`Rounding::AwayFromZero`]; the way back scales
+    /// that by the rate. The bound also covers the digits cut after
+    /// [`Rate::GUARANTEED_PLACES`], and holds as well for the unrounded result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, Rounding, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let jpy = Rates::new().monthly_rate("JPY", YearMonth::new(2025, 8).unwrap())?;
+    /// let amount = Decimal::from(123_456);
+    /// let gbp = jpy.checked_to_gbp(amount)?.round_dp(2);
+    /// let drift = (jpy.from_gbp(gbp) - amount).abs();
+    /// assert!(drift <= jpy.round_trip_drift(2, Rounding::HalfEven));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn round_trip_drift(&self, decimal_places: u32, rounding: Rounding) -> Decimal {
+        let step = match rounding {
+            Rounding::HalfEven | Rounding::HalfUp => place(5, decimal_places + 1),
+            Rounding::TowardZero | Rounding::AwayFromZero => place(1, decimal_places),
+        };
+        self.units_per_gbp * (step + place(5, Rate::GUARANTEED_PLACES + 1))
+    }
+
+    /// [`Rate::from_gbp`], rounded to the currency's [minor units](Currency::minor_units)
+    /// with `rounding`: yen to whole yen, dinars to fils.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, Rounding, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let jpy = rates.monthly_rate("JPY", YearMonth::new(2025, 8).unwrap())?;
+    /// let yen = jpy.from_gbp_rounded(Decimal::from(100), Rounding::HalfEven);
+    /// assert_eq!(yen.scale(), 0);
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn from_gbp_rounded(&self, gbp: Decimal, rounding: Rounding) -> Decimal {
+        rounding.apply(self.from_gbp(gbp), self.currency.minor_units())
+    }
+
+    /// Converts an amount in this rate's currency to `target`'s currency, through GBP.
+    ///
+    /// Computes `amount * target.units_per_gbp / self.units_per_gbp` in one step.
+    /// The intermediate GBP value is never materialized, so no precision is lost to it.
+    /// Both rates should come from the same period: the crate does not check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{YearMonth, Rates};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let table = rates.monthly(YearMonth::new(2025, 8).unwrap())?;
+    /// let (usd, eur) = (table.rate("USD")?, table.rate("EUR")?);
+    /// let euros = usd.to_currency(Decimal::from(100), &eur);
+    /// println!("$100 = €{}", euros.round_dp(2));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the result does not fit a `Decimal`; see [`Rate::checked_to_currency`].
+    pub fn to_currency(&self, amount: Decimal, target: &Rate) -> Decimal {
+        amount * target.units_per_gbp / self.units_per_gbp
+    }
+
+    /// [`Rate::to_currency`], failing as [`Rate::checked_to_gbp`] does at either step.
+    pub fn checked_to_currency(
+        &self,
+        amount: Decimal,
+        target: &Rate,
+    ) -> Result<Decimal, ArithmeticError> {
+        checked_div(
+            checked_mul(amount, target.units_per_gbp)?,
+            self.units_per_gbp,
+        )
+        .map_err(|e| e.with_amount(amount))
+    }
+
+    /// [`Rate::to_currency`], rounded to the target currency's
+    /// [minor units](Currency::minor_units) with `rounding`.
+    pub fn to_currency_rounded(
+        &self,
+        amount: Decimal,
+        target: &Rate,
+        rounding: Rounding,
+    ) -> Decimal {
+        rounding.apply(
+            self.to_currency(amount, target),
+            target.currency.minor_units(),
+        )
+    }
+
     /// The currency this rate quotes against GBP.
     pub fn currency(&self) -> Currency {
         self.currency
@@ -65,3 +214,45 @@ impl Rate {
         self.period
     }
 }
+
+/// `units` × 10^-`scale`, or the smallest `Decimal` step where that is finer still.
+fn place(units: i64, scale: u32) -> Decimal {
+    Decimal::try_new(units, scale).unwrap_or(Decimal::new(1, 28))
+}
+
+fn checked_div(amount: Decimal, divisor: Decimal) -> Result<Decimal, ArithmeticError> {
+    let quotient = amount
+        .checked_div(divisor)
+        .ok_or(ArithmeticError::Overflow { amount })?;
+    let exact = || {
+        quotient
+            .checked_mul(divisor)
+            .is_some_and(|product| product == amount && uncut(product, quotient, divisor))
+    };
+    match quotient.scale() >= Rate::GUARANTEED_PLACES || exact() {
+        true => Ok(quotient),
+        false => Err(ArithmeticError::PrecisionLoss {
+            amount,
+            result: quotient,
+        }),
+    }
+}
+
+fn checked_mul(amount: Decimal, factor: Decimal) -> Result<Decimal, ArithmeticError> {
+    let product = amount
+        .checked_mul(factor)
+        .ok_or(ArithmeticError::Overflow { amount })?;
+    match product.scale() >= Rate::GUARANTEED_PLACES || uncut(product, amount, factor) {
+        true => Ok(product),
+        false => Err(ArithmeticError::PrecisionLoss {
+            amount,
+            result: product,
+        }),
+    }
+}
+
+/// `true` if `product` is `a * b` in full: one that had to be cut comes back with
+/// less scale than its factors add up to.
+fn uncut(product: Decimal, a: Decimal, b: Decimal) -> bool {
+    a.is_zero() || b.is_zero() || product.scale() == a.scale() + b.scale()
+}
@@ -1,11 +1,22 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use chrono::{Datelike, NaiveDate};
 use rust_decimal::Decimal;
 
-use crate::error::LookupError;
+use crate::alias::Aliases;
+use crate::conversion::Conversion;
+use crate::country::Country;
+use crate::error::{ArithmeticError, ConvertError, InvalidRate, LookupError, MergeConflict};
+use crate::gbp::{Gbp, Rounding};
+use crate::number::NumberFormat;
+use crate::provenance::{Provenance, Source};
 use crate::rate::Rate;
-use crate::store::{self, Entry, Series, WeekIdx, Weeks};
+use crate::redenom::Redenomination;
+use crate::source::RateCache;
+use crate::store::{self, Countries, Entry, Series, Shared, WeekIdx, Weeks};
 use crate::types::{Currency, Period, RateType, YearEnd, YearMonth};
 
 // chrono counts day 1 = 0001-01-01; our day 0 = 1970-01-01
@@ -33,30 +44,216 @@ fn gbp_identity(code: &str, period: Period) -> Option<Rate> {
         .then(|| Rate::new(Decimal::ONE, Currency::GBP, period))
 }
 
+/// Published codes one edit (insert, delete or substitute) from `input`, at most three.
+fn suggest(input: &str, codes: impl IntoIterator<Item = [u8; 3]>) -> Box<[Currency]> {
+    let input = input.trim().to_ascii_uppercase();
+    let input = input.as_bytes();
+    codes
+        .into_iter()
+        .filter(|code| one_edit_apart(input, code))
+        .take(3)
+        .map(Currency::from_code)
+        .collect()
+}
+
+/// `code` normalized, or [`LookupError::InvalidCurrencyCode`] with suggestions from `codes`.
+///
+/// `codes` is only called on error: gathering a whole series' codes costs far more than a lookup.
+fn valid_code<I: IntoIterator<Item = [u8; 3]>>(
+    code: &str,
+    codes: impl FnOnce() -> I,
+) -> Result<[u8; 3], LookupError> {
+    Currency::normalize(code).ok_or_else(|| LookupError::InvalidCurrencyCode {
+        code: code.trim().into(),
+        suggestions: suggest(code, codes()),
+    })
+}
+
+pub(crate) fn one_edit_apart(a: &[u8], b: &[u8]) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let prefix = short.iter().zip(long).take_while(|(x, y)| x == y).count();
+    match long.len() - short.len() {
+        0 => short.len() > prefix && short[prefix + 1..] == long[prefix + 1..],
+        1 => short[prefix..] == long[prefix + 1..],
+        _ => false,
+    }
+}
+
+/// Splits `"<amount> <code>"`; whether the code is published is the lookup's call.
+fn parse_amount_and_code(input: &str, format: NumberFormat) -> Option<(Decimal, &str)> {
+    let mut parts = input.split_whitespace();
+    let (Some(amount), Some(code), None) = (parts.next(), parts.next(), parts.next()) else {
+        return None;
+    };
+    let amount = match format {
+        NumberFormat::PLAIN => amount.parse().ok()?,
+        _ => format.parse(amount)?,
+    };
+    Currency::normalize(code)?;
+    Some((amount, code))
+}
+
+/// How [`Rates::monthly_rate_with`] treats a month HMRC has not published.
+///
+/// Every fallback is bounded: the crate never reaches arbitrarily far for a rate.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum LookupPolicy {
+    /// The requested month or an error; what [`Rates::monthly_rate`] does by default.
+    #[default]
+    Exact,
+    /// The nearest earlier published month, at most this many months back.
+    Earlier(u32),
+    /// The nearest published month in either direction, at most this many months away.
+    /// Ties resolve to the earlier month.
+    Nearest(u32),
+    /// For a month after the newest published one, that newest month
+    /// if it is at most this many months earlier; otherwise like [`LookupPolicy::Exact`].
+    ///
+    /// For invoices dated ahead of HMRC's publication, without papering over
+    /// holes inside the data. `LatestWithin(u32::MAX)` always takes the newest month.
+    LatestWithin(u32),
+}
+
+/// What conversions on [`Rates`] do with a negative amount, see [`Rates::set_negative_amounts`].
+///
+/// Conversion is symmetric in sign: a negative amount converts to exactly the negation
+/// of its absolute value's result, rounded or not, as every [`Rounding`] treats both signs
+/// alike. Converting the absolute value and reattaching the sign is what `Allow` does.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum NegativeAmounts {
+    /// Converted like any other amount, for refunds and credit notes; the default.
+    #[default]
+    Allow,
+    /// Rejected with [`ConvertError::NegativeAmount`], for data where a negative is a mistake.
+    Reject,
+}
+
+/// What loads into [`Rates`] do with a currency a monthly file lists more than once
+/// at different rates, see [`Rates::set_duplicate_rates`].
+///
+/// Repeats at one rate, like the euro listed for each eurozone country, always collapse to it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum DuplicateRates {
+    /// The rate most rows give; a tie goes to the most precise value when the others
+    /// are it rounded, and fails otherwise. The default, as HMRC's own repeats need it.
+    #[default]
+    Majority,
+    /// The first row's rate.
+    KeepFirst,
+    /// The last row's rate, e.g. for a correction appended to the file.
+    KeepLast,
+    /// Fail the load.
+    Reject,
+}
+
+/// What [`Rates::merge`] does with a period both sides hold with different rates.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum MergePolicy {
+    /// Keep this side's table.
+    KeepExisting,
+    /// Take the other side's table.
+    #[default]
+    Overwrite,
+    /// Fail with a [`MergeConflict`], leaving this side unchanged.
+    ErrorOnConflict,
+}
+
 /// All HMRC rate tables: bundled data plus (with the `http` feature) fetched periods.
 ///
-/// `Send + Sync`: cloning is cheap, bundled data is shared statics.
+/// `Send + Sync`, and cloning is O(1): clones share the tables, bundled or loaded,
+/// and one is copied only when a clone that shares it is written to.
+/// Aliases and redenominations belong to each clone,
+/// so differently configured views can share one loaded dataset.
 ///
 /// Start with [`Rates::new`].
 #[derive(Clone)]
 pub struct Rates {
+    tables: Shared<Tables>,
+    codes: Shared<CodeMap>,
+    policy: LookupPolicy,
+    negatives: NegativeAmounts,
+    duplicates: DuplicateRates,
+    observer: Option<Observer>,
+}
+
+/// Sees every conversion to GBP, see [`Rates::set_observer`].
+type Observer = Arc<dyn Fn(&Conversion) + Send + Sync>;
+
+#[derive(Clone)]
+struct Tables {
     monthly: Series,
     spot: Series,
     average: Series,
     weeks: Weeks,
+    countries: Countries,
+    /// Monthly keys set at runtime, by where they came from; see [`Rates::provenance`].
+    provenance: BTreeMap<i32, Provenance>,
+}
+
+/// How requested codes map onto published ones: aliases, then redenominations.
+#[derive(Clone)]
+struct CodeMap {
+    aliases: Aliases,
+    redenominations: Vec<Redenomination>,
+}
+
+impl CodeMap {
+    const fn new() -> CodeMap {
+        CodeMap {
+            aliases: Aliases::new(),
+            redenominations: Vec::new(),
+        }
+    }
+
+    fn redenomination(&self, code: [u8; 3]) -> Option<&Redenomination> {
+        self.redenominations.iter().find(|r| r.involves(code))
+    }
 }
 
+static NO_CODES: CodeMap = CodeMap::new();
+
 impl core::fmt::Debug for Rates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Rates")
-            .field("months", &self.monthly.keys().len())
-            .field("spot_periods", &self.spot.keys().len())
-            .field("average_periods", &self.average.keys().len())
-            .field("weeks", &self.weeks.index().len())
+            .field("months", &self.tables.monthly.keys().len())
+            .field("spot_periods", &self.tables.spot.keys().len())
+            .field("average_periods", &self.tables.average.keys().len())
+            .field("weeks", &self.tables.weeks.index().len())
             .finish()
     }
 }
 
+/// Converts `amount` to GBP at the bundled monthly rate, see [`Rates::bundled`].
+///
+/// A shortcut for simple programs; the result is exact, round it yourself.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::YearMonth;
+/// use rust_decimal::Decimal;
+///
+/// let gbp = hmrc_rates::convert(Decimal::from(100), "USD", YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+///
+/// # Panics
+///
+/// If the result does not fit a `Decimal`; [`Rates::checked_convert`] fails instead.
+#[cfg(feature = "bundled")]
+pub fn convert(
+    amount: Decimal,
+    code: impl AsRef<str>,
+    year_month: impl Into<YearMonth>,
+) -> Result<Gbp, LookupError> {
+    let rate = Rates::bundled().monthly_rate(code, year_month)?;
+    Ok(Gbp::new(rate.to_gbp(amount)))
+}
+
 #[cfg(feature = "bundled")]
 impl Default for Rates {
     fn default() -> Rates {
@@ -78,37 +275,420 @@ impl Rates {
     /// assert!(rates.months().count() > 100);
     /// ```
     #[cfg(feature = "bundled")]
-    pub fn new() -> Rates {
-        Rates {
+    pub const fn new() -> Rates {
+        static TABLES: Tables = Tables {
             monthly: Series::new(crate::bundled::MONTHLY),
             spot: Series::new(crate::bundled::SPOT),
             average: Series::new(crate::bundled::AVERAGE),
             weeks: Weeks::new(crate::bundled::WEEKLY),
+            countries: Countries::new(crate::bundled::COUNTRIES),
+            provenance: BTreeMap::new(),
+        };
+        Rates {
+            tables: Shared::new(&TABLES),
+            codes: Shared::new(&NO_CODES),
+            policy: LookupPolicy::Exact,
+            negatives: NegativeAmounts::Allow,
+            duplicates: DuplicateRates::Majority,
+            observer: None,
         }
     }
 
-    /// A `Rates` with no data at all.
-    #[cfg(test)]
-    pub(crate) fn empty() -> Rates {
-        Rates {
+    /// The bundled dataset as a process-wide `&'static`, for code that keeps no `Rates` of its own.
+    ///
+    /// The same data as [`Rates::new`], built at compile time rather than on first use.
+    /// It never sees [`Updater`](crate::Updater) fetches or loaded files.
+    #[cfg(feature = "bundled")]
+    pub fn bundled() -> &'static Rates {
+        static BUNDLED: Rates = Rates::new();
+        &BUNDLED
+    }
+
+    /// A `Rates` with no data at all, to fill with [`Rates::with_rate`] or a loader.
+    pub const fn empty() -> Rates {
+        static TABLES: Tables = Tables {
             monthly: Series::new(store::EMPTY_SERIES),
             spot: Series::new(store::EMPTY_SERIES),
             average: Series::new(store::EMPTY_SERIES),
             weeks: Weeks::new(store::EMPTY_WEEKS),
+            countries: Countries::new(&[]),
+            provenance: BTreeMap::new(),
+        };
+        Rates {
+            tables: Shared::new(&TABLES),
+            codes: Shared::new(&NO_CODES),
+            policy: LookupPolicy::Exact,
+            negatives: NegativeAmounts::Allow,
+            duplicates: DuplicateRates::Majority,
+            observer: None,
         }
     }
 
-    #[cfg(feature = "http")]
+    /// Replaces the currency aliases every lookup resolves first, see [`Aliases`].
+    pub fn set_aliases(&mut self, aliases: Aliases) {
+        self.codes.make_mut().aliases = aliases;
+    }
+
+    /// [`Rates::set_aliases`] as a builder step.
+    pub fn with_aliases(mut self, aliases: Aliases) -> Rates {
+        self.codes.make_mut().aliases = aliases;
+        self
+    }
+
+    /// The currency aliases in use; empty unless set.
+    pub fn aliases(&self) -> &Aliases {
+        &self.codes.aliases
+    }
+
+    /// Replaces the redenominations lookups restate across, see [`Redenomination`].
+    ///
+    /// Both codes of a pair then resolve in every period that publishes either,
+    /// in their own units; `Table::iter` still lists the figures as published.
+    pub fn set_redenominations(
+        &mut self,
+        redenominations: impl IntoIterator<Item = Redenomination>,
+    ) {
+        self.codes.make_mut().redenominations = redenominations.into_iter().collect();
+    }
+
+    /// The redenominations in use; none unless set.
+    pub fn redenominations(&self) -> &[Redenomination] {
+        &self.codes.redenominations
+    }
+
+    /// Calls `observer` with each conversion to GBP this `Rates` makes:
+    /// the input amount, the rate and period used, and the exact result, e.g. for an audit log.
+    ///
+    /// Covers the `convert_*` methods, with [`Rates::convert_between`] reporting its leg
+    /// into GBP, and this type's [`RateSource`](crate::RateSource) conversions, so
+    /// [`ForeignMoney::to_gbp`](crate::ForeignMoney::to_gbp) and the CSV and Polars
+    /// converters given this `Rates` too. Converting with a [`Rate`] you already hold is not observed.
+    /// Clones share the observer; setting one on a clone leaves the others as they were.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use hmrc_rates::{Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let rates = Rates::new().with_observer({
+    ///     let log = Arc::clone(&log);
+    ///     move |conversion| log.lock().unwrap().push(*conversion)
+    /// });
+    /// let gbp = rates.convert_detailed(Decimal::from(100), "USD", YearMonth::new(2025, 8).unwrap())?;
+    /// assert_eq!(log.lock().unwrap()[..], [gbp]);
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn set_observer(&mut self, observer: impl Fn(&Conversion) + Send + Sync + 'static) {
+        self.observer = Some(Arc::new(observer));
+    }
+
+    /// [`Rates::set_observer`] as a builder step.
+    pub fn with_observer(
+        mut self,
+        observer: impl Fn(&Conversion) + Send + Sync + 'static,
+    ) -> Rates {
+        self.set_observer(observer);
+        self
+    }
+
+    /// Removes the observer, if any.
+    pub fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Sets the policy [`Rates::monthly_rate`], and so every monthly conversion, resolves by.
+    ///
+    /// [`LookupPolicy::Exact`] unless set: lookups stay strict until a view opts in,
+    /// and [`Rate::period`] still reveals the month used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{LookupPolicy, Rates};
+    ///
+    /// let next = Rates::new().months().next_back().unwrap().next(); // not published yet
+    /// let lenient = Rates::new().with_lookup_policy(LookupPolicy::Earlier(1));
+    /// assert!(lenient.monthly_rate("USD", next).is_ok());
+    /// assert!(Rates::new().monthly_rate("USD", next).is_err());
+    /// ```
+    pub fn set_lookup_policy(&mut self, policy: LookupPolicy) {
+        self.policy = policy;
+    }
+
+    /// [`Rates::set_lookup_policy`] as a builder step.
+    pub fn with_lookup_policy(mut self, policy: LookupPolicy) -> Rates {
+        self.policy = policy;
+        self
+    }
+
+    /// The policy [`Rates::monthly_rate`] uses; [`LookupPolicy::Exact`] unless set.
+    pub fn lookup_policy(&self) -> LookupPolicy {
+        self.policy
+    }
+
+    /// Sets what conversions do with a negative amount; [`NegativeAmounts::Allow`] unless set.
+    ///
+    /// Applies to the conversion methods on this view and to its [`RateSource`](crate::RateSource)
+    /// conversions, so to the CSV and Polars converters given it. Zero, however signed,
+    /// is never negative. Converting with a [`Rate`] you already hold is not covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{ConvertError, NegativeAmounts, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let mut rates = Rates::new();
+    /// rates.set_negative_amounts(NegativeAmounts::Reject);
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// assert!(matches!(
+    ///     rates.convert_detailed(Decimal::from(-5), "USD", aug),
+    ///     Err(ConvertError::NegativeAmount { .. })
+    /// ));
+    /// ```
+    pub fn set_negative_amounts(&mut self, negatives: NegativeAmounts) {
+        self.negatives = negatives;
+    }
+
+    /// [`Rates::set_negative_amounts`] as a builder step.
+    pub fn with_negative_amounts(mut self, negatives: NegativeAmounts) -> Rates {
+        self.negatives = negatives;
+        self
+    }
+
+    /// What conversions do with a negative amount.
+    pub fn negative_amounts(&self) -> NegativeAmounts {
+        self.negatives
+    }
+
+    /// Sets how later loads resolve a currency a monthly file repeats at different rates;
+    /// [`DuplicateRates::Majority`] unless set.
+    ///
+    /// Applies to monthly XML and CSV files, API responses and `Updater` fetches into
+    /// this `Rates`. Lenient loads also report every row a rule overruled, see
+    /// `Rates::load_reader_lenient` with `load`. Data already held is not revisited.
+    pub fn set_duplicate_rates(&mut self, duplicates: DuplicateRates) {
+        self.duplicates = duplicates;
+    }
+
+    /// [`Rates::set_duplicate_rates`] as a builder step.
+    pub fn with_duplicate_rates(mut self, duplicates: DuplicateRates) -> Rates {
+        self.duplicates = duplicates;
+        self
+    }
+
+    /// How loads resolve a currency repeated at different rates.
+    pub fn duplicate_rates(&self) -> DuplicateRates {
+        self.duplicates
+    }
+
+    /// `self`'s data and duplicate rule with `settings`' aliases, redenominations,
+    /// lookup and negative-amount policies and observer.
+    pub(crate) fn with_settings_of(mut self, settings: &Rates) -> Rates {
+        self.codes = settings.codes.clone();
+        self.policy = settings.policy;
+        self.negatives = settings.negatives;
+        self.observer = settings.observer.clone();
+        self
+    }
+
+    /// Fails for an amount [`Rates::negative_amounts`] does not let through.
+    fn check_amount(&self, amount: Decimal) -> Result<(), ConvertError> {
+        match self.negatives {
+            NegativeAmounts::Reject if amount.is_sign_negative() && !amount.is_zero() => {
+                Err(ConvertError::NegativeAmount { amount })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Converts at `rate`, as the negative-amount policy allows, and shows the result to the observer;
+    /// fails rather than overflowing, as [`Rate::checked_to_gbp`] does.
+    pub(crate) fn converted(
+        &self,
+        amount: Decimal,
+        rate: Rate,
+    ) -> Result<Conversion, ConvertError> {
+        self.check_amount(amount)?;
+        let gbp = rate.checked_to_gbp(amount)?;
+        Ok(self.observed(Conversion::with_gbp(amount, rate, gbp)))
+    }
+
+    fn observed(&self, conversion: Conversion) -> Conversion {
+        if let Some(observer) = &self.observer {
+            observer(&conversion);
+        }
+        conversion
+    }
+
+    /// Replaces a month's country listing; see [`Rates::countries`].
+    #[cfg(feature = "load")]
+    pub(crate) fn set_countries(&mut self, key: i32, rows: Vec<store::CountryRow>) {
+        self.tables.make_mut().countries.set(key, rows);
+    }
+
+    /// The store behind a key-addressed series; `None` for the weekly one.
+    pub(crate) fn series(&self, table: RateType) -> Option<&Series> {
+        match table {
+            RateType::Monthly => Some(&self.tables.monthly),
+            RateType::Spot => Some(&self.tables.spot),
+            RateType::Average => Some(&self.tables.average),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn week_store(&self) -> &Weeks {
+        &self.tables.weeks
+    }
+
+    pub(crate) fn set_weeks(&mut self, weeks: Weeks) {
+        self.tables.make_mut().weeks = weeks;
+    }
+
     pub(crate) fn set_period(&mut self, table: RateType, key: i32, entries: Vec<Entry>) {
         match table {
-            RateType::Monthly => self.monthly.set(key, entries),
-            RateType::Spot => self.spot.set(key, entries),
-            RateType::Average => self.average.set(key, entries),
+            RateType::Monthly => self.tables.make_mut().monthly.set(key, entries),
+            RateType::Spot => self.tables.make_mut().spot.set(key, entries),
+            RateType::Average => self.tables.make_mut().average.set(key, entries),
             _ => {}
         }
     }
 
-    /// The monthly rate for `code`, strictly for that month.
+    /// Records where the monthly table for `key` just came from.
+    pub(crate) fn record_source(&mut self, key: i32, source: Source) {
+        let provenance = Provenance::now(source);
+        self.tables.make_mut().provenance.insert(key, provenance);
+    }
+
+    /// What was recorded for the monthly `key`: `None` for a bundled or a restored month.
+    pub(crate) fn recorded_provenance(&self, key: i32) -> Option<&Provenance> {
+        self.tables.provenance.get(&key)
+    }
+
+    /// `true` if the monthly table for `key` is the compiled-in one.
+    pub(crate) fn is_bundled_month(&self, key: i32) -> bool {
+        self.tables.monthly.table(key).is_some() && !self.tables.monthly.is_overlaid(key)
+    }
+
+    /// Sets one currency's monthly rate for `year_month`, keeping the rest of that month.
+    ///
+    /// For HMRC corrections and errata, and for small synthetic tables in tests.
+    /// Adds the month if it is not held yet.
+    /// Fails for a rate that is not positive or has too many digits, and for GBP,
+    /// which is always 1.
+    pub fn insert_rate(
+        &mut self,
+        year_month: impl Into<YearMonth>,
+        currency: Currency,
+        rate: Decimal,
+    ) -> Result<(), InvalidRate> {
+        let invalid = InvalidRate { currency, rate };
+        if currency.as_str() == "GBP" {
+            return Err(invalid);
+        }
+        let entry = Entry::from_decimal(currency.code(), rate.normalize()).ok_or(invalid)?;
+        let key = year_month.into().key();
+        let mut entries = self.tables.monthly.table(key).unwrap_or_default().to_vec();
+        match entries.binary_search_by_key(&entry.code, |e| e.code) {
+            Ok(i) => entries[i] = entry,
+            Err(i) => entries.insert(i, entry),
+        }
+        self.tables.make_mut().monthly.set(key, entries);
+        self.record_source(key, Source::Manual);
+        Ok(())
+    }
+
+    /// [`Rates::insert_rate`] as a builder step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let month = YearMonth::new(2025, 8).unwrap();
+    /// let rates = Rates::empty()
+    ///     .with_rate(month, "USD".parse()?, Decimal::new(15, 1))?
+    ///     .with_rate(month, "EUR".parse()?, Decimal::new(12, 1))?;
+    /// assert_eq!(rates.monthly(month)?.len(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_rate(
+        mut self,
+        year_month: impl Into<YearMonth>,
+        currency: Currency,
+        rate: Decimal,
+    ) -> Result<Rates, InvalidRate> {
+        self.insert_rate(year_month, currency, rate)?;
+        Ok(self)
+    }
+
+    /// Adds the monthly, spot and average periods of `other`, resolving clashes by `policy`.
+    ///
+    /// A period only `other` holds is always added; identical tables never clash.
+    /// Tables are taken whole, never mixed currency by currency.
+    /// The weekly amendments are fixed history and are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{MergePolicy, Rates};
+    ///
+    /// let mut rates = Rates::new();
+    /// rates.merge(&Rates::new(), MergePolicy::ErrorOnConflict)?;
+    /// # Ok::<(), hmrc_rates::MergeConflict>(())
+    /// ```
+    pub fn merge(&mut self, other: &Rates, policy: MergePolicy) -> Result<(), MergeConflict> {
+        let series = [
+            (
+                RateType::Monthly,
+                &self.tables.monthly,
+                &other.tables.monthly,
+            ),
+            (RateType::Spot, &self.tables.spot, &other.tables.spot),
+            (
+                RateType::Average,
+                &self.tables.average,
+                &other.tables.average,
+            ),
+        ];
+        let mut incoming = Vec::new();
+        for (table, ours, theirs) in series {
+            for key in theirs.keys() {
+                let Some(entries) = theirs.table(key) else {
+                    continue;
+                };
+                match ours.table(key) {
+                    Some(existing) if existing == entries => {}
+                    Some(_) if policy == MergePolicy::KeepExisting => {}
+                    Some(_) if policy == MergePolicy::ErrorOnConflict => {
+                        let period = match table {
+                            RateType::Monthly => Period::YearMonth(YearMonth::from_key(key)),
+                            _ => Period::YearEnd(YearEnd::from_key(key)),
+                        };
+                        return Err(MergeConflict { table, period });
+                    }
+                    _ => incoming.push((table, key, entries.to_vec())),
+                }
+            }
+        }
+        for (table, key, entries) in incoming {
+            self.set_period(table, key, entries);
+            if table == RateType::Monthly {
+                match other.provenance(YearMonth::from_key(key)) {
+                    Some(provenance) => self.tables.make_mut().provenance.insert(key, provenance),
+                    None => self.tables.make_mut().provenance.remove(&key),
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// The monthly rate for `code`, strictly for that month
+    /// unless a [lookup policy](Rates::set_lookup_policy) is set.
     ///
     /// Accepts anything convertible to [`YearMonth`], including `chrono::NaiveDate`.
     /// `"GBP"` (any case) returns the identity rate for any month.
@@ -127,66 +707,502 @@ impl Rates {
     /// ```
     pub fn monthly_rate(
         &self,
-        code: &str,
+        code: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Rate, LookupError> {
+        self.monthly_rate_with(code, year_month, self.policy)
+    }
+
+    /// Like [`Rates::monthly_rate`], but walks back to the nearest earlier
+    /// published month, at most `max_months_back` steps.
+    ///
+    /// Shorthand for [`LookupPolicy::Earlier`] with [`Rates::monthly_rate_with`].
+    /// [`Rate::period`] reveals which month was actually used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Period, Rates};
+    ///
+    /// let rates = Rates::new();
+    /// let next = rates.months().next_back().unwrap().next(); // not published yet
+    /// assert!(rates.monthly_rate("USD", next).is_err()); // strict lookup fails
+    /// let rate = rates.monthly_rate_or_earlier("USD", next, 1)?;
+    /// assert_ne!(rate.period(), Period::YearMonth(next)); // the substitution is visible
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn monthly_rate_or_earlier(
+        &self,
+        code: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+        max_months_back: u32,
+    ) -> Result<Rate, LookupError> {
+        self.monthly_rate_with(code, year_month, LookupPolicy::Earlier(max_months_back))
+    }
+
+    /// The monthly rate for `code`, resolving unpublished months by `policy`.
+    ///
+    /// Fallback is always opt-in and bounded.
+    /// [`Rate::period`] reveals which month was actually used.
+    /// `"GBP"` resolves for the requested month itself, never a substitute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{LookupPolicy, Period, Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// // Monthly coverage starts in 2014-02; the nearest month to 2014-01 is after it
+    /// let early = YearMonth::new(2014, 1).unwrap();
+    /// let rate = rates.monthly_rate_with("USD", early, LookupPolicy::Nearest(1))?;
+    /// assert_eq!(rate.period(), Period::YearMonth(early.next()));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn monthly_rate_with(
+        &self,
+        code: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+        policy: LookupPolicy,
+    ) -> Result<Rate, LookupError> {
+        let code = code.as_ref();
+        let requested = year_month.into();
+        // GBP resolves for the requested month itself — no substitution
+        if let Some(rate) = gbp_identity(code, Period::YearMonth(requested)) {
+            return Ok(rate);
+        }
+        let aliased = self.codes.aliases.resolve(code);
+        valid_code(aliased.as_ref().map_or(code, AsRef::as_ref), || {
+            self.tables.monthly.codes()
+        })?;
+        match self.resolve_month(requested, policy) {
+            Some(year_month) => {
+                event!(trace!(
+                    code,
+                    %requested,
+                    used = %year_month,
+                    months_away = year_month.key() - requested.key(),
+                    "monthly lookup"
+                ));
+                self.monthly(year_month)?.rate(code)
+            }
+            None => {
+                event!(debug!(code, %requested, ?policy, "no monthly table within policy"));
+                Err(self.period_missing(RateType::Monthly, Period::YearMonth(requested)))
+            }
+        }
+    }
+
+    /// The published month `policy` picks for `requested`, preferring earlier on ties.
+    fn resolve_month(&self, requested: YearMonth, policy: LookupPolicy) -> Option<YearMonth> {
+        let (first, last) = self.tables.monthly.first_last()?;
+        let (back, forward) = match policy {
+            LookupPolicy::Exact => (0, 0),
+            LookupPolicy::Earlier(n) => (n, 0),
+            LookupPolicy::Nearest(n) => (n, n),
+            LookupPolicy::LatestWithin(n) => {
+                let ahead = i64::from(requested.key()) - i64::from(last);
+                if ahead > 0 && ahead <= i64::from(n) {
+                    return Some(YearMonth::from_key(last));
+                }
+                (0, 0)
+            }
+        };
+        let (mut earlier, mut later) = (requested, requested);
+        for step in 0..=back.max(forward) {
+            let earlier_done = step > back || earlier.key() < first;
+            let later_done = step > forward || later.key() > last;
+            if earlier_done && later_done {
+                break; // both directions are exhausted or outside the loaded range
+            }
+            if step <= back && self.tables.monthly.table(earlier.key()).is_some() {
+                return Some(earlier);
+            }
+            if step > 0 && step <= forward && self.tables.monthly.table(later.key()).is_some() {
+                return Some(later);
+            }
+            earlier = earlier.prev();
+            later = later.next();
+        }
+        None
+    }
+
+    /// Converts `amount` from one currency to another at the monthly rates for `year_month`.
+    ///
+    /// Triangulates through GBP with both rates from the same month,
+    /// see [`Rate::checked_to_currency`]. The result is exact, round it yourself;
+    /// one too large for a `Decimal` fails with [`ConvertError::Arithmetic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{YearMonth, Rates};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let month = YearMonth::new(2025, 8).unwrap();
+    /// let euros = rates.convert_between(Decimal::from(100), "USD", "EUR", month)?;
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_between(
+        &self,
+        amount: Decimal,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Decimal, ConvertError> {
+        Ok(self.between(amount, from, to, year_month.into())?.0)
+    }
+
+    /// [`Rates::convert_between`], rounded to `to`'s [minor units](Currency::minor_units).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, Rounding, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let month = YearMonth::new(2025, 8).unwrap();
+    /// let yen = rates.convert_between_rounded(Decimal::from(100), "USD", "JPY", month, Rounding::HalfUp)?;
+    /// assert_eq!(yen.scale(), 0);
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_between_rounded(
+        &self,
+        amount: Decimal,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+        rounding: Rounding,
+    ) -> Result<Decimal, ConvertError> {
+        let (exact, to) = self.between(amount, from, to, year_month.into())?;
+        Ok(rounding.apply(exact, to.currency().minor_units()))
+    }
+
+    /// [`Rates::convert_between`]'s result and the target rate it used.
+    fn between(
+        &self,
+        amount: Decimal,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+        year_month: YearMonth,
+    ) -> Result<(Decimal, Rate), ConvertError> {
+        let from = self.monthly_rate(from, year_month)?;
+        let to = self.monthly_rate(to, year_month)?;
+        self.converted(amount, from)?; // the leg into GBP, for the policy and the observer
+        Ok((from.checked_to_currency(amount, &to)?, to))
+    }
+
+    /// [`Rates::convert_detailed`]'s GBP alone. Like every conversion on `Rates`, it fails
+    /// with [`ConvertError::Arithmetic`] rather than panicking on overflow or keeping
+    /// too few decimal places, see [`Rate::checked_to_gbp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{ConvertError, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// let gbp = rates.checked_convert(Decimal::from(100), "USD", aug)?;
+    /// assert!(matches!(
+    ///     rates.checked_convert(Decimal::MAX, "EUR", aug),
+    ///     Err(ConvertError::Arithmetic(_))
+    /// ));
+    /// # Ok::<(), ConvertError>(())
+    /// ```
+    pub fn checked_convert(
+        &self,
+        amount: Decimal,
+        code: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.monthly_rate(code, year_month)?;
+        Ok(self.converted(amount, rate)?.gbp())
+    }
+
+    /// Converts `amount` to GBP at the monthly rate, keeping the rate and period used.
+    ///
+    /// See [`Conversion`] for an example, and [`Rates::checked_convert`] for how it fails.
+    pub fn convert_detailed(
+        &self,
+        amount: Decimal,
+        code: impl AsRef<str>,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Conversion, ConvertError> {
+        self.converted(amount, self.monthly_rate(code, year_month)?)
+    }
+
+    /// `amount` in every currency of a month's monthly table, for price lists and quotes:
+    /// units per £1 times the amount, exact and unrounded.
+    ///
+    /// The month is resolved once, by this view's [`Rates::lookup_policy`],
+    /// and fails as [`Rates::monthly_rate`] does when there is none. An amount too
+    /// large for any currency fails with [`ConvertError::Arithmetic`], see [`Rate::checked_from_gbp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Gbp, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let prices = Rates::new()
+    ///     .convert_from_gbp_all(Gbp::new(Decimal::from(25)), YearMonth::new(2025, 8).unwrap())?;
+    /// for (currency, amount) in &prices {
+    ///     println!("{currency} {}", amount.round_dp(2));
+    /// }
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_from_gbp_all(
+        &self,
+        amount: Gbp,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<BTreeMap<Currency, Decimal>, ConvertError> {
+        self.check_amount(amount.amount())?;
+        let requested = year_month.into();
+        let year_month = self
+            .resolve_month(requested, self.policy)
+            .ok_or_else(|| self.period_missing(RateType::Monthly, Period::YearMonth(requested)))?;
+        Ok(self
+            .monthly(year_month)?
+            .iter()
+            .map(|(currency, rate)| Ok((currency, rate.checked_from_gbp(amount.amount())?)))
+            .collect::<Result<_, ArithmeticError>>()?)
+    }
+
+    /// Converts many `(amount, code, month)` items to GBP at their monthly rates.
+    ///
+    /// Each distinct month and currency is resolved once, however many items share it.
+    /// Results come back in input order, exact and unrounded; each item fails
+    /// as [`Rates::checked_convert`] would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{YearMonth, Rates};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// let results = rates.convert_many([
+    ///     (Decimal::from(100), "USD", aug),
+    ///     (Decimal::from(250), "usd", aug),
+    ///     (Decimal::from(80), "XXX", aug),
+    /// ]);
+    /// assert!(results[0].is_ok() && results[1].is_ok() && results[2].is_err());
+    /// ```
+    pub fn convert_many<'c, M: Into<YearMonth>>(
+        &self,
+        items: impl IntoIterator<Item = (Decimal, &'c str, M)>,
+    ) -> Vec<Result<Gbp, ConvertError>> {
+        let mut rates = RateCache::new(self);
+        items
+            .into_iter()
+            .map(|(amount, code, year_month)| {
+                let rate = rates.rate(code, year_month.into())?;
+                Ok(self.converted(amount, rate)?.gbp())
+            })
+            .collect()
+    }
+
+    /// [`Rates::convert_many`] over a slice, split into runs converted on the rayon pool.
+    ///
+    /// Each run resolves its distinct months and currencies once.
+    /// Results come back in input order; an observer sees conversions from many threads
+    /// and in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let aug = YearMonth::new(2025, 8).unwrap();
+    /// let ledger = vec![(Decimal::from(100), "USD", aug); 100_000];
+    /// let results = Rates::new().par_convert_many(&ledger);
+    /// assert_eq!(results.len(), ledger.len());
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn par_convert_many<M: Into<YearMonth> + Copy + Sync>(
+        &self,
+        items: &[(Decimal, &str, M)],
+    ) -> Vec<Result<Gbp, ConvertError>> {
+        use rayon::prelude::*;
+        /// Items per run: enough to amortise lookups, few enough to balance threads.
+        const RUN: usize = 4096;
+        items
+            .par_chunks(RUN)
+            .flat_map_iter(|run| self.convert_many(run.iter().copied()))
+            .collect()
+    }
+
+    /// Converts free text like `"1234.56 USD"` to GBP at the monthly rate for `year_month`.
+    ///
+    /// The amount comes first, then the code, separated by whitespace.
+    /// Surrounding whitespace and lowercase codes are accepted.
+    /// The result is exact, round it yourself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{YearMonth, Rates};
+    ///
+    /// let rates = Rates::new();
+    /// let month = YearMonth::new(2025, 8).unwrap();
+    /// let gbp = rates.convert_str(" 1234.56 usd ", month)?;
+    /// assert!(rates.convert_str("USD 1234.56", month).is_err());
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_str(
+        &self,
+        input: &str,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Gbp, ConvertError> {
+        self.convert_str_with(input, year_month, NumberFormat::PLAIN)
+    }
+
+    /// [`Rates::convert_str`] for amounts written in `format`, e.g. `"1.234,56 EUR"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{NumberFormat, Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let month = YearMonth::new(2025, 8).unwrap();
+    /// let gbp = rates.convert_str_with("1.234,56 EUR", month, NumberFormat::EUROPEAN)?;
+    /// assert_eq!(gbp, rates.convert_str("1234.56 EUR", month)?);
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    ///
+    /// An amount too large to convert fails with [`ConvertError::Arithmetic`],
+    /// as for [`Rates::checked_convert`].
+    pub fn convert_str_with(
+        &self,
+        input: &str,
+        year_month: impl Into<YearMonth>,
+        format: NumberFormat,
+    ) -> Result<Gbp, ConvertError> {
+        let (amount, code) =
+            parse_amount_and_code(input, format).ok_or_else(|| ConvertError::InvalidInput {
+                input: input.trim().into(),
+            })?;
+        let rate = self.monthly_rate(code, year_month)?;
+        Ok(self.converted(amount, rate)?.gbp())
+    }
+
+    /// The countries listed in the monthly file for `year_month`, by country code.
+    ///
+    /// Bundled months and loaded XML keep the listing; months from sources that
+    /// do not name countries (CSV, JSON, [`Rates::insert_rate`]) have none of their own.
+    pub fn countries(
+        &self,
+        year_month: impl Into<YearMonth>,
+    ) -> impl ExactSizeIterator<Item = Country<'_>> {
+        let listing = self.tables.countries.month(year_month.into().key());
+        listing.into_iter().map(Country::new)
+    }
+
+    /// One listed country by its HMRC code (any case), see [`Country`].
+    pub fn country(&self, code: &str, year_month: impl Into<YearMonth>) -> Option<Country<'_>> {
+        let code = code.trim();
+        self.countries(year_month)
+            .find(|country| country.code().eq_ignore_ascii_case(code))
+    }
+
+    /// The countries listed with `currency` in `year_month`; each also gives its name.
+    pub fn countries_using(
+        &self,
+        currency: Currency,
         year_month: impl Into<YearMonth>,
-    ) -> Result<Rate, LookupError> {
-        self.monthly_rate_or_earlier(code, year_month, 0)
+    ) -> impl Iterator<Item = Country<'_>> {
+        self.countries(year_month)
+            .filter(move |country| country.currency() == currency)
     }
 
-    /// Like [`Rates::monthly_rate`], but walks back to the nearest earlier
-    /// published month, at most `max_months_back` steps.
-    ///
-    /// This is the crate's only fallback, and it is opt-in.
-    /// [`Rate::period`] reveals which month was actually used.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use hmrc_rates::{Period, Rates};
-    ///
-    /// let rates = Rates::new();
-    /// let next = rates.months().next_back().unwrap().next(); // not published yet
-    /// assert!(rates.monthly_rate("USD", next).is_err()); // strict lookup fails
-    /// let rate = rates.monthly_rate_or_earlier("USD", next, 1)?;
-    /// assert_ne!(rate.period(), Period::YearMonth(next)); // the substitution is visible
-    /// # Ok::<(), hmrc_rates::LookupError>(())
-    /// ```
-    pub fn monthly_rate_or_earlier(
+    /// The monthly rate for a country's currency, e.g. Japan's in 2025-08.
+    pub fn country_rate(
         &self,
         code: &str,
         year_month: impl Into<YearMonth>,
-        max_months_back: u32,
     ) -> Result<Rate, LookupError> {
-        let requested = year_month.into();
-        // GBP resolves for the requested month itself — no substitution
-        if let Some(rate) = gbp_identity(code, Period::YearMonth(requested)) {
-            return Ok(rate);
-        }
-        let mut candidate = requested;
-        for _ in 0..=max_months_back {
-            if self.monthly.table(candidate.key()).is_some() {
-                return self.monthly(candidate)?.rate(code);
-            }
-            candidate = candidate.prev();
-        }
-        Err(self.period_missing(RateType::Monthly, Period::YearMonth(requested)))
+        let year_month = year_month.into();
+        let country =
+            self.country(code, year_month)
+                .ok_or_else(|| LookupError::UnknownCountry {
+                    code: code.trim().into(),
+                    period: Period::YearMonth(year_month),
+                })?;
+        self.monthly_rate(country.currency(), year_month)
     }
 
     /// The whole monthly table for one month.
     pub fn monthly(&self, year_month: impl Into<YearMonth>) -> Result<Table<'_>, LookupError> {
         let year_month = year_month.into();
         let period = Period::YearMonth(year_month);
-        match self.monthly.table(year_month.key()) {
+        match self.tables.monthly.table(year_month.key()) {
             Some(entries) => Ok(Table {
                 rate_type: RateType::Monthly,
                 period,
                 entries,
-                known: Known::Series(&self.monthly),
+                known: Known::Series(&self.tables.monthly),
+                codes: &self.codes,
             }),
             None => Err(self.period_missing(RateType::Monthly, period)),
         }
     }
 
+    /// The newest published month, `None` when no monthly rates are loaded.
+    pub fn latest_month(&self) -> Option<YearMonth> {
+        let (_, last) = self.tables.monthly.first_last()?;
+        Some(YearMonth::from_key(last))
+    }
+
+    /// The newest month's whole table, for showing "current HMRC rates".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Period, Rates};
+    ///
+    /// let rates = Rates::new();
+    /// let current = rates.latest_rates().unwrap();
+    /// assert_eq!(current.period(), Period::YearMonth(rates.latest_month().unwrap()));
+    /// for (currency, rate) in current.iter() {
+    ///     println!("{currency}: {}", rate.units_per_gbp());
+    /// }
+    /// ```
+    pub fn latest_rates(&self) -> Option<Table<'_>> {
+        self.monthly(self.latest_month()?).ok()
+    }
+
+    /// The whole monthly table for `date`, a day or a month, found by [`Rates::lookup_policy`]
+    /// as [`Rates::monthly_rate`] would: the table to cache or display for a date.
+    ///
+    /// [`Table::period`] is the month actually used; [`Rates::monthly`] never substitutes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{LookupPolicy, Period, Rates};
+    ///
+    /// let rates = Rates::new().with_lookup_policy(LookupPolicy::Earlier(1));
+    /// let newest = rates.latest_month().unwrap();
+    /// let after = newest.next();
+    /// let table = rates.rates_for(after)?;
+    /// assert_eq!(table.period(), Period::YearMonth(newest));
+    /// assert_eq!(table.rate("USD")?, rates.monthly_rate("USD", after)?);
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn rates_for(&self, date: impl Into<YearMonth>) -> Result<Table<'_>, LookupError> {
+        let requested = date.into();
+        match self.resolve_month(requested, self.policy) {
+            Some(year_month) => self.monthly(year_month),
+            None => Err(self.period_missing(RateType::Monthly, Period::YearMonth(requested))),
+        }
+    }
+
     /// The spot table for a 31 March / 31 December period.
     ///
     /// # Examples
@@ -199,7 +1215,7 @@ impl Rates {
     /// # Ok::<(), hmrc_rates::LookupError>(())
     /// ```
     pub fn spot(&self, period: YearEnd) -> Result<Table<'_>, LookupError> {
-        self.year_end_table(&self.spot, RateType::Spot, period)
+        self.year_end_table(&self.tables.spot, RateType::Spot, period)
     }
 
     /// The yearly-average table for a 31 March / 31 December period.
@@ -215,7 +1231,56 @@ impl Rates {
     /// # Ok::<(), hmrc_rates::LookupError>(())
     /// ```
     pub fn average(&self, period: YearEnd) -> Result<Table<'_>, LookupError> {
-        self.year_end_table(&self.average, RateType::Average, period)
+        self.year_end_table(&self.tables.average, RateType::Average, period)
+    }
+
+    /// Converts `amount` to GBP at the spot rate for `period`, exactly.
+    ///
+    /// For balances valued at a year end, as in some capital gains and company accounts work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearEnd};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let gbp = rates.convert_spot(Decimal::from(1000), "USD", YearEnd::december(2024))?;
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_spot(
+        &self,
+        amount: Decimal,
+        code: impl AsRef<str>,
+        period: YearEnd,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.spot(period)?.rate(code)?;
+        Ok(self.converted(amount, rate)?.gbp())
+    }
+
+    /// Converts `amount` to GBP at the yearly-average rate for `period`, exactly.
+    ///
+    /// The usual choice for foreign income on Self Assessment:
+    /// [`YearEnd::march`] for the tax year, [`YearEnd::december`] for a calendar year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearEnd};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rates = Rates::new();
+    /// let gbp = rates.convert_average(Decimal::from(1000), "EUR", YearEnd::march(2025))?;
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    pub fn convert_average(
+        &self,
+        amount: Decimal,
+        code: impl AsRef<str>,
+        period: YearEnd,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.average(period)?.rate(code)?;
+        Ok(self.converted(amount, rate)?.gbp())
     }
 
     /// The weekly-amendment table whose validity range contains `date`.
@@ -235,59 +1300,229 @@ impl Rates {
     /// ```
     pub fn weekly(&self, date: NaiveDate) -> Result<Table<'_>, LookupError> {
         let day = date_to_day(date);
-        if let Some((week, entries)) = self.weeks.containing(day) {
+        if let Some((week, entries)) = self.tables.weeks.containing(day) {
             if let Some(period) = week_period(&week) {
                 return Ok(Table {
                     rate_type: RateType::Weekly,
                     period,
                     entries,
-                    known: Known::Weeks(&self.weeks),
+                    known: Known::Weeks(&self.tables.weeks),
+                    codes: &self.codes,
                 });
             }
         }
-        Err(LookupError::PeriodNotAvailable {
-            table: RateType::Weekly,
-            period: Period::Week {
+        Err(self.period_missing(
+            RateType::Weekly,
+            Period::Week {
                 start: date,
                 end: date,
             },
-            available: self.available(RateType::Weekly),
-        })
+        ))
+    }
+
+    /// The first and last loaded period of a series, `None` when it is empty.
+    ///
+    /// The same range [`LookupError::PeriodNotAvailable`] reports.
+    /// Combine with [`Period::start`] and [`Period::end`] for the covered dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Period, RateType, Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let (first, last) = rates.coverage(RateType::Monthly).unwrap();
+    /// assert_eq!(first, Period::YearMonth(YearMonth::new(2014, 2).unwrap()));
+    /// let latest_date = last.end();
+    /// ```
+    pub fn coverage(&self, table: RateType) -> Option<(Period, Period)> {
+        match table {
+            RateType::Monthly => self.tables.monthly.first_last().map(|(f, l)| {
+                (
+                    Period::YearMonth(YearMonth::from_key(f)),
+                    Period::YearMonth(YearMonth::from_key(l)),
+                )
+            }),
+            RateType::Spot | RateType::Average => {
+                let series = if table == RateType::Spot {
+                    &self.tables.spot
+                } else {
+                    &self.tables.average
+                };
+                series.first_last().map(|(f, l)| {
+                    (
+                        Period::YearEnd(YearEnd::from_key(f)),
+                        Period::YearEnd(YearEnd::from_key(l)),
+                    )
+                })
+            }
+            RateType::Weekly => {
+                let idx = self.tables.weeks.index();
+                Some((week_period(idx.first()?)?, week_period(idx.last()?)?))
+            }
+        }
+    }
+
+    /// Every monthly rate held, as `(first day of the month, currency, units per £1)`,
+    /// by month then code; the figures as published, like [`Table::iter`].
+    ///
+    /// For one month, iterate its [`Rates::monthly`] table instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let rates = Rates::new();
+    /// let (first, currency, _) = rates.iter().next().unwrap();
+    /// assert_eq!(first.to_string(), "2014-02-01");
+    /// assert_eq!(currency.as_str(), "AED");
+    /// ```
+    pub fn iter(&self) -> MonthlyRates<'_> {
+        MonthlyRates {
+            series: &self.tables.monthly,
+            months: self.tables.monthly.keys().into_iter(),
+            current: None,
+        }
     }
 
     /// All published months, ascending.
     pub fn months(&self) -> impl DoubleEndedIterator<Item = YearMonth> + use<'_> {
-        self.monthly.keys().into_iter().map(YearMonth::from_key)
+        self.tables
+            .monthly
+            .keys()
+            .into_iter()
+            .map(YearMonth::from_key)
+    }
+
+    /// Each published month from `from` to `to`, inclusive, with its monthly table, ascending;
+    /// dates pick their month. Months without a table are skipped, as in [`Rates::months`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let from = YearMonth::new(2025, 1).unwrap();
+    /// let to = YearMonth::new(2025, 6).unwrap();
+    /// for (month, table) in rates.rates_between(from, to) {
+    ///     println!("{month}: {} currencies", table.len());
+    /// }
+    /// ```
+    pub fn rates_between(
+        &self,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+    ) -> impl DoubleEndedIterator<Item = (YearMonth, Table<'_>)> {
+        let (from, to) = (from.into().key(), to.into().key());
+        self.tables
+            .monthly
+            .keys()
+            .into_iter()
+            .filter(move |key| (from..=to).contains(key))
+            .filter_map(|key| {
+                let year_month = YearMonth::from_key(key);
+                Some((year_month, self.monthly(year_month).ok()?))
+            })
     }
 
     /// All published spot periods, ascending.
     pub fn spot_periods(&self) -> impl DoubleEndedIterator<Item = YearEnd> + use<'_> {
-        self.spot.keys().into_iter().map(YearEnd::from_key)
+        self.tables.spot.keys().into_iter().map(YearEnd::from_key)
     }
 
     /// All published yearly-average periods, ascending.
     pub fn average_periods(&self) -> impl DoubleEndedIterator<Item = YearEnd> + use<'_> {
-        self.average.keys().into_iter().map(YearEnd::from_key)
+        self.tables
+            .average
+            .keys()
+            .into_iter()
+            .map(YearEnd::from_key)
     }
 
     /// All weekly-amendment validity ranges, ascending, as [`Period::Week`] items.
     pub fn weeks(&self) -> impl DoubleEndedIterator<Item = Period> + use<'_> {
-        self.weeks.index().iter().filter_map(week_period)
+        self.tables.weeks.index().iter().filter_map(week_period)
     }
 
     /// Every currency that appears anywhere in the given series, ascending.
     pub fn currencies(&self, table: RateType) -> impl Iterator<Item = Currency> + use<'_> {
         let codes = match table {
-            RateType::Monthly => self.monthly.codes(),
-            RateType::Spot => self.spot.codes(),
-            RateType::Average => self.average.codes(),
+            RateType::Monthly => self.tables.monthly.codes(),
+            RateType::Spot => self.tables.spot.codes(),
+            RateType::Average => self.tables.average.codes(),
             RateType::Weekly => self.weekly_codes(),
         };
         codes.into_iter().map(Currency::from_code)
     }
 
+    /// How `code`'s monthly rate evolved: each published month's first day
+    /// with its units per £1, ascending. Months that do not list `code` are skipped.
+    ///
+    /// An error only when no month lists `code` at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let rates = Rates::new();
+    /// let (first_day, _units_per_gbp) = rates.rate_history("USD")?.next().unwrap();
+    /// assert_eq!(first_day.to_string(), "2014-02-01");
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn rate_history(
+        &self,
+        code: impl AsRef<str>,
+    ) -> Result<impl DoubleEndedIterator<Item = (NaiveDate, Decimal)>, LookupError> {
+        self.history(code.as_ref(), i32::MIN, i32::MAX)
+    }
+
+    /// [`Rates::rate_history`] for the months `from` to `to`, inclusive;
+    /// dates pick their month.
+    pub fn rate_history_between(
+        &self,
+        code: impl AsRef<str>,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+    ) -> Result<impl DoubleEndedIterator<Item = (NaiveDate, Decimal)>, LookupError> {
+        self.history(code.as_ref(), from.into().key(), to.into().key())
+    }
+
+    fn history(
+        &self,
+        code: &str,
+        from: i32,
+        to: i32,
+    ) -> Result<impl DoubleEndedIterator<Item = (NaiveDate, Decimal)> + use<'_>, LookupError> {
+        let aliased = self.codes.aliases.resolve(code);
+        let code = aliased.as_ref().map_or(code, AsRef::as_ref);
+        let unknown = || LookupError::UnknownCurrency {
+            code: code.trim().into(),
+            table: RateType::Monthly,
+            suggestions: suggest(code, self.tables.monthly.codes()),
+        };
+        let code = valid_code(code, || self.tables.monthly.codes())?;
+        let gbp = code == Currency::GBP.code();
+        if !gbp && !Known::Series(&self.tables.monthly).knows_via(code, &self.codes) {
+            return Err(unknown());
+        }
+        let keys = self.tables.monthly.keys().into_iter();
+        Ok(keys
+            .filter(move |key| (from..=to).contains(key))
+            .filter_map(move |key| {
+                let year_month = YearMonth::from_key(key);
+                let rate = match gbp {
+                    true => Decimal::ONE,
+                    false => self.monthly(year_month).ok()?.find(code)?.units_per_gbp(),
+                };
+                Some((Period::YearMonth(year_month).start()?, rate))
+            }))
+    }
+
     fn weekly_codes(&self) -> Vec<[u8; 3]> {
-        let mut codes: Vec<[u8; 3]> = self.weeks.arena().iter().map(|e| e.code).collect();
+        let mut codes: Vec<[u8; 3]> = self.tables.weeks.arena().iter().map(|e| e.code).collect();
         codes.sort_unstable();
         codes.dedup();
         codes
@@ -305,49 +1540,67 @@ impl Rates {
                 period: Period::YearEnd(period),
                 entries,
                 known: Known::Series(series),
+                codes: &self.codes,
             }),
             None => Err(self.period_missing(rate_type, Period::YearEnd(period))),
         }
     }
 
-    /// The loaded range of a series, for `PeriodNotAvailable` messages.
-    fn available(&self, table: RateType) -> Option<(Period, Period)> {
-        match table {
-            RateType::Monthly => self.monthly.first_last().map(|(f, l)| {
-                (
-                    Period::YearMonth(YearMonth::from_key(f)),
-                    Period::YearMonth(YearMonth::from_key(l)),
-                )
-            }),
-            RateType::Spot | RateType::Average => {
-                let series = if table == RateType::Spot {
-                    &self.spot
-                } else {
-                    &self.average
+    fn period_missing(&self, table: RateType, period: Period) -> LookupError {
+        LookupError::PeriodNotAvailable {
+            table,
+            period,
+            available: self.coverage(table),
+            nearest: self.nearest_period(table, period),
+        }
+    }
+
+    /// The published period closest in time to `period`, earlier on ties.
+    fn nearest_period(&self, table: RateType, period: Period) -> Option<Period> {
+        match (table, period) {
+            (RateType::Monthly, Period::YearMonth(m)) => {
+                closest(self.tables.monthly.keys(), m.key(), |key| key)
+                    .map(|key| Period::YearMonth(YearMonth::from_key(key)))
+            }
+            (RateType::Spot | RateType::Average, Period::YearEnd(ye)) => {
+                let series = match table {
+                    RateType::Spot => &self.tables.spot,
+                    _ => &self.tables.average,
                 };
-                series.first_last().map(|(f, l)| {
-                    (
-                        Period::YearEnd(YearEnd::from_key(f)),
-                        Period::YearEnd(YearEnd::from_key(l)),
-                    )
-                })
+                let month_of = |key| YearEnd::from_key(key).end_year_month().key();
+                closest(series.keys(), ye.end_year_month().key(), month_of)
+                    .map(|key| Period::YearEnd(YearEnd::from_key(key)))
             }
-            RateType::Weekly => {
-                let idx = self.weeks.index();
-                Some((week_period(idx.first()?)?, week_period(idx.last()?)?))
+            (RateType::Weekly, Period::Week { start, .. }) => {
+                let day = date_to_day(start);
+                let away = |w: &&WeekIdx| (w.start_day - day).max(day - w.end_day).max(0);
+                self.tables
+                    .weeks
+                    .index()
+                    .iter()
+                    .min_by_key(away)
+                    .and_then(week_period)
             }
+            _ => None,
         }
     }
+}
 
-    fn period_missing(&self, table: RateType, period: Period) -> LookupError {
-        LookupError::PeriodNotAvailable {
-            table,
-            period,
-            available: self.available(table),
-        }
+/// The month a period's figures belong to: a year end's last month, a week's first.
+fn period_month(period: Period) -> YearMonth {
+    match period {
+        Period::YearMonth(m) => m,
+        Period::YearEnd(ye) => ye.end_year_month(),
+        Period::Week { start, .. } => start.into(),
     }
 }
 
+/// The key in ascending `keys` whose `at` is closest to `target`, the first on ties.
+fn closest(keys: Vec<i32>, target: i32, at: impl Fn(i32) -> i32) -> Option<i32> {
+    keys.into_iter()
+        .min_by_key(|&key| (i64::from(at(key)) - i64::from(target)).unsigned_abs())
+}
+
 #[derive(Copy, Clone)]
 enum Known<'a> {
     Series(&'a Series),
@@ -361,6 +1614,48 @@ impl Known<'_> {
             Known::Weeks(w) => w.knows(code),
         }
     }
+
+    /// Whether `code`, or the other code of its redenomination, is ever published.
+    fn knows_via(&self, code: [u8; 3], codes: &CodeMap) -> bool {
+        self.knows(code)
+            || codes
+                .redenomination(code)
+                .is_some_and(|r| self.knows(r.counterpart(code)))
+    }
+}
+
+/// Every monthly rate in a [`Rates`], see [`Rates::iter`].
+pub struct MonthlyRates<'a> {
+    series: &'a Series,
+    months: alloc::vec::IntoIter<i32>,
+    current: Option<(NaiveDate, core::slice::Iter<'a, Entry>)>,
+}
+
+impl Iterator for MonthlyRates<'_> {
+    type Item = (NaiveDate, Currency, Decimal);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((first, entries)) = &mut self.current {
+                if let Some(entry) = entries.next() {
+                    return Some((*first, Currency::from_code(entry.code), entry.decimal()));
+                }
+            }
+            let key = self.months.next()?;
+            let first = Period::YearMonth(YearMonth::from_key(key)).start()?;
+            let entries = self.series.table(key).unwrap_or_default();
+            self.current = Some((first, entries.iter()));
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Rates {
+    type Item = (NaiveDate, Currency, Decimal);
+    type IntoIter = MonthlyRates<'a>;
+
+    fn into_iter(self) -> MonthlyRates<'a> {
+        self.iter()
+    }
 }
 
 /// A borrowed view of one period's table — resolve once, convert many times.
@@ -370,6 +1665,7 @@ pub struct Table<'a> {
     period: Period,
     entries: &'a [Entry],
     known: Known<'a>,
+    codes: &'a CodeMap,
 }
 
 impl core::fmt::Debug for Table<'_> {
@@ -414,36 +1710,45 @@ impl<'a> Table<'a> {
     ///     .sum();
     /// # Ok::<(), hmrc_rates::LookupError>(())
     /// ```
-    pub fn rate(&self, code: &str) -> Result<Rate, LookupError> {
+    pub fn rate(&self, code: impl AsRef<str>) -> Result<Rate, LookupError> {
+        let aliased = self.codes.aliases.resolve(code.as_ref());
+        let code = aliased.as_ref().map_or(code.as_ref(), AsRef::as_ref);
         if let Some(rate) = gbp_identity(code, self.period) {
             return Ok(rate);
         }
-        let Some(normalized) = Currency::normalize(code) else {
-            return Err(LookupError::UnknownCurrency {
-                code: code.trim().into(),
-                table: self.rate_type,
-            });
+        let unknown = || LookupError::UnknownCurrency {
+            code: code.trim().into(),
+            table: self.rate_type,
+            suggestions: suggest(code, self.entries.iter().map(|e| e.code)),
         };
-        match store::lookup(self.entries, normalized) {
-            Some(entry) => Ok(Rate::new(
-                entry.decimal(),
-                Currency::from_code(normalized),
-                self.period,
-            )),
-            None if self.known.knows(normalized) => Err(LookupError::NotInPeriod {
+        let normalized = valid_code(code, || self.entries.iter().map(|e| e.code))?;
+        match self.find(normalized) {
+            Some(rate) => Ok(rate),
+            None if self.known.knows_via(normalized, self.codes) => Err(LookupError::NotInPeriod {
                 currency: Currency::from_code(normalized),
                 table: self.rate_type,
                 period: self.period,
             }),
-            None => Err(LookupError::UnknownCurrency {
-                code: code.trim().into(),
-                table: self.rate_type,
-            }),
+            None => Err(unknown()),
         }
     }
 
+    /// The rate for a normalized code, restated across a redenomination if one is set;
+    /// a restated figure too large for a `Decimal` counts as unpublished.
+    fn find(&self, code: [u8; 3]) -> Option<Rate> {
+        let units = match self.codes.redenomination(code) {
+            None => store::lookup(self.entries, code)?.decimal(),
+            Some(redenomination) => {
+                let published = store::lookup(self.entries, code)
+                    .or_else(|| store::lookup(self.entries, redenomination.counterpart(code)))?;
+                redenomination.restate(published.decimal(), period_month(self.period), code)?
+            }
+        };
+        Some(Rate::new(units, Currency::from_code(code), self.period))
+    }
+
     /// Like [`Table::rate`] but `None` on any miss, for when absence isn't exceptional.
-    pub fn get(&self, code: &str) -> Option<Rate> {
+    pub fn get(&self, code: impl AsRef<str>) -> Option<Rate> {
         self.rate(code).ok()
     }
 
@@ -456,6 +1761,23 @@ impl<'a> Table<'a> {
         })
     }
 
+    /// The currencies quoted in this table, ascending by code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let rates = Rates::new();
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 8, 15).unwrap();
+    /// let codes: Vec<_> = rates.monthly(date)?.currencies().collect();
+    /// assert!(codes.iter().any(|c| c.as_str() == "USD"));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn currencies(&self) -> impl ExactSizeIterator<Item = Currency> + use<'a> {
+        self.entries.iter().map(|e| Currency::from_code(e.code))
+    }
+
     /// The number of currencies in this table.
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -467,6 +1789,146 @@ impl<'a> Table<'a> {
     }
 }
 
+// Rates and tables as nested maps of exact decimal strings:
+// {"monthly": {"2025-08": {"USD": "1.3541"}}, "spot": {..}, "average": {..},
+//  "weekly": [{"start": "2014-01-08", "end": "2014-01-14", "rates": {..}}]}
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{Rates, Table, date_to_day, day_to_date};
+    use crate::store::{Entry, Series, Weeks};
+    use crate::types::{Currency, Period, RateType, YearEnd, YearMonth};
+    use alloc::collections::BTreeMap;
+    use alloc::format;
+    use alloc::vec::Vec;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use serde::de::Error as _;
+    use serde::ser::{Error as _, SerializeStruct};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    type RateMap = BTreeMap<Currency, Decimal>;
+
+    #[derive(Serialize, Deserialize)]
+    struct Week {
+        start: NaiveDate,
+        end: NaiveDate,
+        rates: RateMap,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Snapshot {
+        monthly: BTreeMap<YearMonth, RateMap>,
+        spot: BTreeMap<YearEnd, RateMap>,
+        average: BTreeMap<YearEnd, RateMap>,
+        weekly: Vec<Week>,
+    }
+
+    fn rate_map(entries: &[Entry]) -> RateMap {
+        entries
+            .iter()
+            .map(|e| (Currency::from_code(e.code), e.decimal()))
+            .collect()
+    }
+
+    fn series_map<K: Ord>(series: &Series, key: impl Fn(i32) -> K) -> BTreeMap<K, RateMap> {
+        series
+            .keys()
+            .into_iter()
+            .filter_map(|k| Some((key(k), rate_map(series.table(k)?))))
+            .collect()
+    }
+
+    fn entries<E: serde::de::Error>(
+        rates: RateMap,
+        period: &dyn core::fmt::Display,
+    ) -> Result<Vec<Entry>, E> {
+        rates
+            .into_iter()
+            .map(|(currency, rate)| {
+                Entry::from_decimal(currency.code(), rate).ok_or_else(|| {
+                    E::custom(format!("invalid rate {rate} for {currency} in {period}"))
+                })
+            })
+            .collect()
+    }
+
+    impl Serialize for Rates {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let weekly = (0..self.tables.weeks.index().len())
+                .map(|i| {
+                    let week = self.tables.weeks.index()[i];
+                    match (day_to_date(week.start_day), day_to_date(week.end_day)) {
+                        (Some(start), Some(end)) => Ok(Week {
+                            start,
+                            end,
+                            rates: rate_map(self.tables.weeks.slice(i)),
+                        }),
+                        _ => Err(S::Error::custom("weekly period out of date range")),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Snapshot {
+                monthly: series_map(&self.tables.monthly, YearMonth::from_key),
+                spot: series_map(&self.tables.spot, YearEnd::from_key),
+                average: series_map(&self.tables.average, YearEnd::from_key),
+                weekly,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    /// Rebuilds owned tables; no bundled data is involved.
+    impl<'de> Deserialize<'de> for Rates {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rates, D::Error> {
+            let snapshot = Snapshot::deserialize(deserializer)?;
+            let mut rates = Rates::empty();
+            for (year_month, table) in snapshot.monthly {
+                let entries = entries(table, &year_month)?;
+                rates.set_period(RateType::Monthly, year_month.key(), entries);
+            }
+            for (rate_type, periods) in [
+                (RateType::Spot, snapshot.spot),
+                (RateType::Average, snapshot.average),
+            ] {
+                for (year_end, table) in periods {
+                    rates.set_period(rate_type, year_end.key(), entries(table, &year_end)?);
+                }
+            }
+            let mut weeks = Vec::with_capacity(snapshot.weekly.len());
+            let mut previous_end = None;
+            for week in snapshot.weekly {
+                let (start, end) = (date_to_day(week.start), date_to_day(week.end));
+                if start > end || previous_end.is_some_and(|p| p >= start) {
+                    return Err(D::Error::custom(format!(
+                        "weekly period {} to {} is reversed, unordered or overlapping",
+                        week.start, week.end
+                    )));
+                }
+                previous_end = Some(end);
+                let period = Period::Week {
+                    start: week.start,
+                    end: week.end,
+                };
+                weeks.push((start, end, entries(week.rates, &period)?));
+            }
+            rates.set_weeks(Weeks::owned(weeks));
+            Ok(rates)
+        }
+    }
+
+    /// `{"rate_type": "monthly", "period": {..}, "rates": {"USD": "1.3541"}}`.
+    impl Serialize for Table<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut table = serializer.serialize_struct("Table", 3)?;
+            table.serialize_field("rate_type", &self.rate_type)?;
+            table.serialize_field("period", &self.period)?;
+            table.serialize_field("rates", &rate_map(self.entries))?;
+            table.end()
+        }
+    }
+}
+
 #[cfg(test)]
 mod empty_tests {
     use super::*;
@@ -539,16 +2001,24 @@ mod bundled_tests {
     fn codegen_matches_fresh_parse() {
         let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/monthly/2025-08.xml");
         let bytes = std::fs::read(path).unwrap();
-        let ((year, month), raw) = crate::parse::parse_monthly_xml(&bytes).unwrap();
-        let parsed = crate::parse::dedup_majority(raw).unwrap();
+        let doc = crate::parse::parse_monthly_xml(&bytes).unwrap();
+        let (year, month) = doc.period;
+        let parsed = crate::parse::dedup_majority(doc.rates).unwrap();
 
         let rates = Rates::new();
-        let table = rates.monthly(YearMonth::new(year, month).unwrap()).unwrap();
+        let year_month = YearMonth::new(year, month).unwrap();
+        let table = rates.monthly(year_month).unwrap();
         assert_eq!(table.len(), parsed.len());
         for rate in &parsed {
             let entry = crate::store::lookup(table.entries, rate.code).unwrap();
             assert_eq!((entry.mantissa, entry.scale), (rate.mantissa, rate.scale));
         }
+        assert_eq!(rates.countries(year_month).len(), doc.countries.len());
+        for country in &doc.countries {
+            let bundled = rates.country(&country.country_code, year_month).unwrap();
+            assert_eq!(bundled.name(), country.country_name);
+            assert_eq!(bundled.currency_name(), country.currency_name);
+        }
 
         // Same for a year-end series: pins build.rs's YearEnd key encoding
         let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/average/2024-12.csv");
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+
+use rust_decimal::Decimal;
+
+use crate::types::{Currency, YearMonth};
+
+/// A currency replaced by a new one worth `units` of the old, see [`Rates::set_redenominations`](crate::Rates::set_redenominations).
+///
+/// `from` is the first month whose published figure is in new units,
+/// whichever code HMRC printed beside it: HMRC's labels often lag or lead the values
+/// (its `BYR` row switched to new units in 2016-08, the `BYN` label came in 2017-01).
+/// With a redenomination set, both codes answer in their own units in every month,
+/// so histories and conversions run straight across the changeover.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Rates, Redenomination, YearMonth};
+/// use rust_decimal::Decimal;
+///
+/// let mut rates = Rates::new();
+/// rates.set_redenominations(Redenomination::hmrc());
+/// let before = YearMonth::new(2016, 1).unwrap(); // BYN did not exist yet
+/// let byn = rates.monthly_rate("BYN", before)?.units_per_gbp();
+/// let byr = rates.monthly_rate("BYR", before)?.units_per_gbp();
+/// assert_eq!(byn * Decimal::from(10_000), byr);
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Redenomination {
+    old: Currency,
+    new: Currency,
+    from: YearMonth,
+    units: Decimal,
+}
+
+impl Redenomination {
+    /// One `new` is worth `units` of `old` from `from` on; `None` unless the codes differ
+    /// and `units` is a power of ten from 10 to 10¹², as every redenomination HMRC has
+    /// published across is.
+    pub fn new(
+        old: Currency,
+        new: Currency,
+        from: impl Into<YearMonth>,
+        units: Decimal,
+    ) -> Option<Redenomination> {
+        let power_of_ten = (1..=12).any(|exponent| units == Decimal::from(10_u64.pow(exponent)));
+        (power_of_ten && old != new).then(|| Redenomination {
+            old,
+            new,
+            from: from.into(),
+            units,
+        })
+    }
+
+    /// The changeovers inside HMRC's monthly history, dated by its figures:
+    /// `BYR` → `BYN` (10,000:1, 2016-08), `MRO` → `MRU` (10:1, 2020-01)
+    /// and `SLL` → `SLE` (1,000:1, 2023-02).
+    ///
+    /// Venezuela's and Zimbabwe's code changes are left out:
+    /// HMRC's figures across them are not a fixed ratio apart.
+    pub fn hmrc() -> Vec<Redenomination> {
+        [
+            (*b"BYR", *b"BYN", (2016, 8), 10_000),
+            (*b"MRO", *b"MRU", (2020, 1), 10),
+            (*b"SLL", *b"SLE", (2023, 2), 1_000),
+        ]
+        .into_iter()
+        .filter_map(|(old, new, (year, month), units)| {
+            Redenomination::new(
+                Currency::from_code(old),
+                Currency::from_code(new),
+                YearMonth::new(year, month)?,
+                Decimal::from(units),
+            )
+        })
+        .collect()
+    }
+
+    /// The replaced currency.
+    pub fn old_currency(&self) -> Currency {
+        self.old
+    }
+
+    /// The replacing currency.
+    pub fn new_currency(&self) -> Currency {
+        self.new
+    }
+
+    /// The first month published in new units.
+    pub fn from(&self) -> YearMonth {
+        self.from
+    }
+
+    /// How many of the old currency one new unit is worth.
+    pub fn units(&self) -> Decimal {
+        self.units
+    }
+
+    pub(crate) fn involves(&self, code: [u8; 3]) -> bool {
+        self.old.code() == code || self.new.code() == code
+    }
+
+    /// The other code of the pair.
+    pub(crate) fn counterpart(&self, code: [u8; 3]) -> [u8; 3] {
+        match self.old.code() == code {
+            true => self.new.code(),
+            false => self.old.code(),
+        }
+    }
+
+    /// A figure published in `month` under either code, restated in `wanted`'s units;
+    /// `None` if that does not fit a `Decimal`.
+    pub(crate) fn restate(
+        &self,
+        published: Decimal,
+        month: YearMonth,
+        wanted: [u8; 3],
+    ) -> Option<Decimal> {
+        let in_new_units = month >= self.from;
+        match (wanted == self.new.code(), in_new_units) {
+            (true, false) => published.checked_div(self.units),
+            (false, true) => published.checked_mul(self.units),
+            _ => Some(published),
+        }
+    }
+}
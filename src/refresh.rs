@@ -0,0 +1,105 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::http::{FetchError, Updater};
+use crate::rates::Rates;
+
+/// Keeps a shared [`Rates`] current from a background thread.
+///
+/// Starts from [`Updater::cached`], then calls [`Updater::refreshed`] right away
+/// and once per interval, swapping in the new rates on success.
+/// A failed refresh keeps the previous rates and is reported by
+/// [`AutoRefresher::take_error`], so staleness stays visible.
+/// Dropping the refresher stops the thread, after any refresh in flight.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use hmrc_rates::{AutoRefresher, Updater, YearMonth};
+///
+/// let refresher = AutoRefresher::start(Updater::new(), Duration::from_secs(6 * 60 * 60));
+/// let handle = refresher.handle(); // clone into every worker
+/// let rates = handle.current();
+/// let rate = rates.monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+pub struct AutoRefresher {
+    handle: RatesHandle,
+    error: Arc<Mutex<Option<FetchError>>>,
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// A cheap, cloneable reader of the rates an [`AutoRefresher`] maintains.
+#[derive(Clone)]
+pub struct RatesHandle {
+    shared: Arc<RwLock<Arc<Rates>>>,
+}
+
+impl RatesHandle {
+    /// The newest rates; a snapshot that later refreshes do not change.
+    pub fn current(&self) -> Arc<Rates> {
+        Arc::clone(&self.shared.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn replace(&self, rates: Rates) {
+        *self.shared.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(rates);
+    }
+}
+
+impl AutoRefresher {
+    /// Spawns the refresh thread.
+    pub fn start(updater: Updater, interval: Duration) -> AutoRefresher {
+        let handle = RatesHandle {
+            shared: Arc::new(RwLock::new(Arc::new(updater.cached()))),
+        };
+        let error = Arc::new(Mutex::new(None));
+        let (stop, stopped) = mpsc::channel();
+        let thread = {
+            let (handle, error) = (handle.clone(), Arc::clone(&error));
+            thread::spawn(move || {
+                loop {
+                    match updater.refreshed() {
+                        Ok(rates) => handle.replace(rates),
+                        Err(e) => *error.lock().unwrap_or_else(PoisonError::into_inner) = Some(e),
+                    }
+                    match stopped.recv_timeout(interval) {
+                        Err(RecvTimeoutError::Timeout) => {}
+                        _ => return,
+                    }
+                }
+            })
+        };
+        AutoRefresher {
+            handle,
+            error,
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+
+    /// A reader handle; clone it freely.
+    pub fn handle(&self) -> RatesHandle {
+        self.handle.clone()
+    }
+
+    /// The most recent refresh failure not yet taken, if any.
+    pub fn take_error(&self) -> Option<FetchError> {
+        self.error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+}
+
+impl Drop for AutoRefresher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// `rusty_money` interop: ISO money values in and out, HMRC rates as `ExchangeRate`s.
+
+use rust_decimal::Decimal;
+use rusty_money::iso;
+use rusty_money::{Exchange, ExchangeRate, FormattableCurrency, Money, MoneyError};
+
+use crate::error::LookupError;
+use crate::gbp::Gbp;
+use crate::money::ForeignMoney;
+use crate::rate::Rate;
+use crate::rates::Rates;
+use crate::types::{Currency, YearMonth};
+
+impl From<Gbp> for Money<'static, iso::Currency> {
+    fn from(gbp: Gbp) -> Money<'static, iso::Currency> {
+        Money::from_decimal(gbp.amount(), iso::GBP)
+    }
+}
+
+/// Fails with [`MoneyError::CurrencyMismatch`] unless the money is in GBP.
+impl TryFrom<Money<'_, iso::Currency>> for Gbp {
+    type Error = MoneyError;
+
+    fn try_from(money: Money<'_, iso::Currency>) -> Result<Gbp, MoneyError> {
+        if money.currency() != iso::GBP {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: iso::GBP.code(),
+                actual: money.currency().code(),
+            });
+        }
+        Ok(Gbp::new(*money.amount()))
+    }
+}
+
+impl From<Money<'_, iso::Currency>> for ForeignMoney {
+    fn from(money: Money<'_, iso::Currency>) -> ForeignMoney {
+        ForeignMoney::new(
+            *money.amount(),
+            Currency::from_iso_code(money.currency().code()),
+        )
+    }
+}
+
+/// Fails with [`MoneyError::InvalidCurrency`] for codes outside ISO 4217,
+/// such as the historical ones HMRC still lists.
+impl TryFrom<ForeignMoney> for Money<'static, iso::Currency> {
+    type Error = MoneyError;
+
+    fn try_from(money: ForeignMoney) -> Result<Money<'static, iso::Currency>, MoneyError> {
+        let currency = iso::find(money.currency().as_str()).ok_or(MoneyError::InvalidCurrency)?;
+        Ok(Money::from_decimal(money.amount(), currency))
+    }
+}
+
+impl Rate {
+    /// This rate as a `rusty_money` [`ExchangeRate`] from GBP to the rate's currency.
+    ///
+    /// The factor is [`Rate::units_per_gbp`], exactly.
+    /// Fails with [`MoneyError::InvalidCurrency`] for a currency outside ISO 4217, or for GBP itself.
+    pub fn exchange_rate(&self) -> Result<ExchangeRate<'static, iso::Currency>, MoneyError> {
+        let currency = iso::find(self.currency().as_str()).ok_or(MoneyError::InvalidCurrency)?;
+        ExchangeRate::new(iso::GBP, currency, self.units_per_gbp())
+    }
+}
+
+impl Rates {
+    /// A `rusty_money` [`Exchange`] holding `year_month`'s monthly rates, both ways.
+    ///
+    /// GBP to each currency uses HMRC's figure exactly.
+    /// The way back is its reciprocal, to `Decimal`'s 28 digits,
+    /// so prefer [`Rate::to_gbp`], which divides, where exactness matters.
+    /// Currencies outside ISO 4217 are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    /// use rusty_money::{Money, iso};
+    ///
+    /// let exchange = Rates::new().exchange(YearMonth::new(2025, 8).unwrap())?;
+    /// let rate = exchange.get_rate(iso::GBP, iso::USD).unwrap();
+    /// let dollars = rate.convert(&Money::from_major(100, iso::GBP)).unwrap();
+    /// assert_eq!(dollars.currency(), iso::USD);
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn exchange(
+        &self,
+        year_month: impl Into<YearMonth>,
+    ) -> Result<Exchange<'static, iso::Currency>, LookupError> {
+        let table = self.monthly(year_month)?;
+        let mut exchange = Exchange::new();
+        for (_, rate) in table.iter() {
+            let Ok(forward) = rate.exchange_rate() else {
+                continue;
+            };
+            exchange.set_rate(&forward);
+            if let Some(back) = Decimal::ONE.checked_div(rate.units_per_gbp()) {
+                if let Ok(back) = ExchangeRate::new(forward.to, iso::GBP, back) {
+                    exchange.set_rate(&back);
+                }
+            }
+        }
+        Ok(exchange)
+    }
+}
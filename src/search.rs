@@ -0,0 +1,94 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::rates::{Rates, one_edit_apart};
+use crate::types::{Currency, RateType};
+
+impl Rates {
+    /// Currencies matching `query` by code, alias, currency name, country name or country code,
+    /// best match first, for pickers and "did you mean" prompts.
+    ///
+    /// Case is ignored. An exact code or alias ranks first, then an exact name,
+    /// then names with a word starting with `query`, names containing it,
+    /// and finally codes and words one typo away. Ties sort by code.
+    /// Names come from every month's country listing, so renamed
+    /// and discontinued currencies are found too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let rates = Rates::new();
+    /// assert_eq!(rates.search("yen")[0].as_str(), "JPY");
+    /// assert_eq!(rates.search("dubai")[0].as_str(), "AED");
+    /// assert!(rates.search("").is_empty());
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<Currency> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut best: BTreeMap<Currency, u8> = BTreeMap::new();
+        let mut consider = |currency: Currency, rank: Option<u8>| {
+            if let Some(rank) = rank {
+                let held = best.entry(currency).or_insert(rank);
+                *held = (*held).min(rank);
+            }
+        };
+        for currency in self.currencies(RateType::Monthly) {
+            consider(currency, rank_code(&query, currency.as_str()));
+        }
+        if let Some(currency) = self.aliases().resolve(&query) {
+            consider(currency, Some(0));
+        }
+        // Listings repeat month after month; rank each distinct one once
+        let listings: BTreeSet<_> = self
+            .months()
+            .flat_map(|month| self.countries(month))
+            .map(|c| (c.currency(), c.name(), c.currency_name(), c.code()))
+            .collect();
+        for (currency, name, currency_name, code) in listings {
+            let rank = [name, currency_name, code]
+                .into_iter()
+                .filter_map(|name| rank_name(&query, name))
+                .min();
+            consider(currency, rank);
+        }
+        let mut ranked: Vec<(u8, Currency)> = best.into_iter().map(|(c, r)| (r, c)).collect();
+        ranked.sort();
+        ranked.into_iter().map(|(_, currency)| currency).collect()
+    }
+}
+
+fn rank_code(query: &str, code: &str) -> Option<u8> {
+    let code = code.to_ascii_lowercase();
+    if query == code {
+        Some(0)
+    } else if query.len() == 3 && one_edit_apart(query.as_bytes(), code.as_bytes()) {
+        Some(4)
+    } else {
+        None
+    }
+}
+
+fn rank_name(query: &str, name: &str) -> Option<u8> {
+    let name: String = name.to_lowercase();
+    let mut words = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty());
+    if name == query {
+        Some(1)
+    } else if words.clone().any(|word| word.starts_with(query)) || name.starts_with(query) {
+        Some(2)
+    } else if name.contains(query) {
+        Some(3)
+    } else if query.len() >= 3
+        && words.any(|word| one_edit_apart(query.as_bytes(), word.as_bytes()))
+    {
+        Some(4)
+    } else {
+        None
+    }
+}
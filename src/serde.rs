@@ -0,0 +1,103 @@
+//! `deserialize_with` helpers that convert to GBP while a document is read.
+//!
+//! The converter and month come from [`with_context`], set for the current thread
+//! around the `from_str`/`from_reader` call, so the fields need no second pass.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use hmrc_rates::{Gbp, Rates, YearMonth};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Expense {
+//!     description: String,
+//!     #[serde(deserialize_with = "hmrc_rates::serde::gbp_from_foreign")]
+//!     amount: Gbp,
+//! }
+//!
+//! let month = YearMonth::new(2025, 8).unwrap();
+//! let json = r#"[{"description": "hotel", "amount": "420 EUR"},
+//!                {"description": "taxi", "amount": {"amount": "35", "currency": "USD"}}]"#;
+//! let expenses: Vec<Expense> =
+//!     hmrc_rates::serde::with_context(Arc::new(Rates::new()), month, || serde_json::from_str(json))?;
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use std::cell::RefCell;
+use std::string::String;
+use std::sync::Arc;
+
+use ::serde::de::Error as _;
+use ::serde::{Deserialize, Deserializer};
+
+use crate::gbp::Gbp;
+use crate::money::ForeignMoney;
+use crate::source::RateSource;
+use crate::types::YearMonth;
+
+struct Context {
+    source: Arc<dyn RateSource>,
+    year_month: YearMonth,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+/// Restores the enclosing context, also when `f` panics.
+struct Restore(Option<Context>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| *context.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `f` with `source` and `year_month` as this thread's conversion context.
+///
+/// Nested calls shadow the outer context until they return.
+/// Other threads are unaffected, so concurrent deserializations can use different months.
+pub fn with_context<R>(
+    source: Arc<dyn RateSource>,
+    year_month: impl Into<YearMonth>,
+    f: impl FnOnce() -> R,
+) -> R {
+    let context = Context {
+        source,
+        year_month: year_month.into(),
+    };
+    let _restore = Restore(CONTEXT.with(|current| current.replace(Some(context))));
+    f()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Foreign {
+    Text(String),
+    Money(ForeignMoney),
+}
+
+/// Deserializes a [`ForeignMoney`], as `"420 EUR"`, `"€420"` or `{"amount", "currency"}`,
+/// and converts it at the context's monthly rate, exactly.
+///
+/// Fails outside [`with_context`], and on any [`LookupError`](crate::LookupError).
+pub fn gbp_from_foreign<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Gbp, D::Error> {
+    let money = match Foreign::deserialize(deserializer)? {
+        Foreign::Text(s) => s
+            .parse::<ForeignMoney>()
+            .map_err(|e| D::Error::custom(format_args!("{e}: '{s}'")))?,
+        Foreign::Money(money) => money,
+    };
+    let (source, year_month) = CONTEXT
+        .with(|context| {
+            let context = context.borrow();
+            context
+                .as_ref()
+                .map(|context| (Arc::clone(&context.source), context.year_month))
+        })
+        .ok_or_else(|| {
+            D::Error::custom(
+                "no conversion context, deserialize within hmrc_rates::serde::with_context",
+            )
+        })?;
+    money.to_gbp(&*source, year_month).map_err(D::Error::custom)
+}
@@ -0,0 +1,170 @@
+//! A small JSON HTTP service over [`Rates`], for callers outside Rust.
+//!
+//! | Endpoint | Answer |
+//! | --- | --- |
+//! | `GET /convert?amount=100&currency=USD&month=2025-08` | a [`Conversion`] |
+//! | `GET /rates/{month}` | that month's monthly [`Table`](crate::Table) |
+//! | `GET /currencies[?month=2025-08]` | every monthly currency, or one month's |
+//! | `GET /metrics` | [`Metrics`] in the Prometheus text format |
+//!
+//! Bodies use the `serde` forms, so amounts and rates are exact decimal strings.
+//! Failures answer `{"error": "...", "code": "..."}`, the code from [`ConvertError::code`]:
+//! 404 for a failed lookup, 400 for a currency that is not a three-letter code,
+//! and 422 for an amount too large to convert exactly; malformed queries answer 400.
+//!
+//! Every successful `/convert` counts in the router's [`Metrics`]; `/metrics` adds
+//! the age of the data served, measured with [`SystemClock`].
+
+use axum::extract::{FromRef, Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, SystemClock};
+use crate::conversion::Conversion;
+use crate::error::{ConvertError, LookupError};
+use crate::metrics::Metrics;
+use crate::rates::Rates;
+use crate::types::{Currency, RateType, YearMonth};
+
+/// The service's routes over `rates`, to serve as is or nest in a larger app.
+pub fn router(rates: Rates) -> Router {
+    router_with_metrics(rates, Metrics::new())
+}
+
+/// [`router`], counting into `metrics`, e.g. one shared with the
+/// `Updater` that fetched `rates` so `/metrics` reports its downloads too.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Metrics, Rates};
+///
+/// let metrics = Metrics::new();
+/// let app = hmrc_rates::serve::router_with_metrics(Rates::new(), metrics.clone());
+/// ```
+pub fn router_with_metrics(rates: Rates, metrics: Metrics) -> Router {
+    Router::new()
+        .route("/convert", get(convert))
+        .route("/rates/{month}", get(monthly))
+        .route("/currencies", get(currencies))
+        .route("/metrics", get(metrics_text))
+        .with_state(AppState { rates, metrics })
+}
+
+/// Serves [`router`] on `listener` until the process ends.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn f() -> std::io::Result<()> {
+/// use hmrc_rates::Rates;
+///
+/// let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await?;
+/// hmrc_rates::serve::serve(listener, Rates::new()).await
+/// # }
+/// ```
+pub async fn serve(listener: tokio::net::TcpListener, rates: Rates) -> std::io::Result<()> {
+    axum::serve(listener, router(rates)).await
+}
+
+#[derive(Clone)]
+struct AppState {
+    rates: Rates,
+    metrics: Metrics,
+}
+
+impl FromRef<AppState> for Rates {
+    fn from_ref(state: &AppState) -> Rates {
+        state.rates.clone()
+    }
+}
+
+impl FromRef<AppState> for Metrics {
+    fn from_ref(state: &AppState) -> Metrics {
+        state.metrics.clone()
+    }
+}
+
+#[derive(Deserialize)]
+struct ConvertQuery {
+    amount: Decimal,
+    currency: String,
+    month: YearMonth,
+}
+
+#[derive(Deserialize)]
+struct CurrenciesQuery {
+    month: Option<YearMonth>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+    code: &'static str,
+}
+
+struct ApiError(ConvertError);
+
+impl<E: Into<ConvertError>> From<E> for ApiError {
+    fn from(e: E) -> ApiError {
+        ApiError(e.into())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            error: self.0.to_string(),
+            code: self.0.code(),
+        };
+        let status = match self.0 {
+            ConvertError::Lookup(LookupError::InvalidCurrencyCode { .. }) => {
+                StatusCode::BAD_REQUEST
+            }
+            ConvertError::Lookup(_) => StatusCode::NOT_FOUND,
+            ConvertError::Arithmetic(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+async fn convert(
+    State(rates): State<Rates>,
+    State(metrics): State<Metrics>,
+    Query(query): Query<ConvertQuery>,
+) -> Result<Json<Conversion>, ApiError> {
+    let rate = rates.monthly_rate(&query.currency, query.month)?;
+    let conversion = rates.converted(query.amount, rate)?;
+    metrics.record_conversion(query.month, &conversion);
+    Ok(Json(conversion))
+}
+
+async fn monthly(
+    State(rates): State<Rates>,
+    Path(month): Path<YearMonth>,
+) -> Result<Response, ApiError> {
+    let table = rates.monthly(month)?;
+    Ok(Json(table).into_response())
+}
+
+async fn currencies(
+    State(rates): State<Rates>,
+    Query(query): Query<CurrenciesQuery>,
+) -> Result<Json<Vec<Currency>>, ApiError> {
+    Ok(Json(match query.month {
+        Some(month) => rates.monthly(month)?.currencies().collect(),
+        None => rates.currencies(RateType::Monthly).collect(),
+    }))
+}
+
+async fn metrics_text(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(&state.rates, SystemClock.today()),
+    )
+}
@@ -0,0 +1,281 @@
+use alloc::vec::Vec;
+
+use crate::error::SnapshotError;
+use crate::provenance::Source;
+use crate::rates::Rates;
+use crate::store::{Entry, Series, Weeks};
+use crate::types::{RateType, YearEnd, YearMonth};
+
+const MAGIC: &[u8; 8] = b"HMRCRATE";
+const DELTA_MAGIC: &[u8; 8] = b"HMRCDLTA";
+const VERSION: u16 = 1;
+const SERIES: [RateType; 3] = [RateType::Monthly, RateType::Spot, RateType::Average];
+
+/// Binary snapshots of a whole [`Rates`], for cold starts that cannot afford to parse XML.
+///
+/// The layout is the store's own, little-endian, behind an 8-byte magic and a format version:
+/// per series a period count, then each period's key, entry count and 12-byte entries
+/// (code, scale, mantissa). Rates stay exact; no extra dependency is involved.
+/// Holds the same data as `Rates::to_json`, decoded without any text parsing.
+impl Rates {
+    /// Every held period, bundled and loaded alike, as one byte buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::Rates;
+    ///
+    /// let bytes = Rates::new().to_snapshot();
+    /// let rates = Rates::from_snapshot(&bytes)?;
+    /// assert_eq!(rates.months().count(), Rates::new().months().count());
+    /// # Ok::<(), hmrc_rates::SnapshotError>(())
+    /// ```
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        for table in SERIES {
+            write_series(&mut out, self.series(table));
+        }
+        let weeks = self.week_store().index();
+        write_len(&mut out, weeks.len());
+        for (i, week) in weeks.iter().enumerate() {
+            out.extend_from_slice(&week.start_day.to_le_bytes());
+            out.extend_from_slice(&week.end_day.to_le_bytes());
+            write_entries(&mut out, self.week_store().slice(i));
+        }
+        out
+    }
+
+    /// Only the rates in a [`Rates::to_snapshot`] buffer.
+    ///
+    /// Checks the header and every entry: codes, positive rates, ascending periods
+    /// and disjoint weeks. Trailing bytes are rejected.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Rates, SnapshotError> {
+        let mut reader = Reader(bytes);
+        if reader.take(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        let version = u16::from_le_bytes(reader.array()?);
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion { version });
+        }
+        let mut rates = Rates::empty();
+        for table in SERIES {
+            let mut previous = None;
+            for _ in 0..reader.len()? {
+                let key = i32::from_le_bytes(reader.array()?);
+                if previous.is_some_and(|p| p >= key) {
+                    return Err(malformed("periods out of order"));
+                }
+                previous = Some(key);
+                rates.set_period(table, key, reader.entries()?);
+            }
+        }
+        let count = reader.len()?;
+        let mut weeks = Vec::new();
+        let mut previous_end = None;
+        for _ in 0..count {
+            let start = i32::from_le_bytes(reader.array()?);
+            let end = i32::from_le_bytes(reader.array()?);
+            if start > end || previous_end.is_some_and(|p| p >= start) {
+                return Err(malformed("weeks reversed, out of order or overlapping"));
+            }
+            previous_end = Some(end);
+            weeks.push((start, end, reader.entries()?));
+        }
+        if !reader.0.is_empty() {
+            return Err(malformed("trailing bytes"));
+        }
+        rates.set_weeks(Weeks::owned(weeks));
+        Ok(rates)
+    }
+}
+
+/// Deltas: the periods from one month on, to bring another copy up to date.
+///
+/// A delta has its own 8-byte magic and version, then the month it starts from,
+/// then the three series as in a snapshot, holding only monthly tables from that month
+/// and spot and average tables for year ends in or after it. The weekly amendments are
+/// fixed history and never included.
+impl Rates {
+    /// The monthly tables from `since` on, and the spot and average tables for years
+    /// ending from then, for [`Rates::apply_delta`] on a copy that has everything before.
+    ///
+    /// A service that holds data up to some month asks for a delta since that month:
+    /// it gets the new months, and the revised tables of that month if HMRC republished it,
+    /// in a few kilobytes rather than the whole history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let latest = Rates::new();
+    /// let newest = latest.months().next_back().unwrap();
+    /// let delta = latest.export_delta_since(newest.prev());
+    /// assert!(delta.len() < 4096);
+    ///
+    /// let mut replica = Rates::new();
+    /// let applied = replica.apply_delta(&delta)?;
+    /// assert_eq!(applied, [newest.prev(), newest]);
+    /// # Ok::<(), hmrc_rates::SnapshotError>(())
+    /// ```
+    pub fn export_delta_since(&self, since: impl Into<YearMonth>) -> Vec<u8> {
+        let since = since.into();
+        let mut out = Vec::new();
+        out.extend_from_slice(DELTA_MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&since.key().to_le_bytes());
+        for table in SERIES {
+            let series = self.series(table);
+            let keys: Vec<i32> = series
+                .map(Series::keys)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|&key| period_month(table, key) >= since)
+                .collect();
+            write_len(&mut out, keys.len());
+            for key in keys {
+                out.extend_from_slice(&key.to_le_bytes());
+                write_entries(
+                    &mut out,
+                    series.and_then(|s| s.table(key)).unwrap_or_default(),
+                );
+            }
+        }
+        out
+    }
+
+    /// Adds or replaces the periods in a [`Rates::export_delta_since`] buffer,
+    /// returning the months it held, ascending.
+    ///
+    /// Checked as [`Rates::from_snapshot`] checks, and periods before the delta's
+    /// starting month are rejected. Tables it does not mention are kept;
+    /// on error `self` is unchanged. Months applied report [`Source::Restored`](crate::Source::Restored).
+    pub fn apply_delta(&mut self, bytes: &[u8]) -> Result<Vec<YearMonth>, SnapshotError> {
+        let mut reader = Reader(bytes);
+        if reader.take(DELTA_MAGIC.len()).ok() != Some(&DELTA_MAGIC[..]) {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        let version = u16::from_le_bytes(reader.array()?);
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion { version });
+        }
+        let since = YearMonth::from_key(i32::from_le_bytes(reader.array()?));
+        let mut periods = Vec::new();
+        for table in SERIES {
+            let mut previous = None;
+            for _ in 0..reader.len()? {
+                let key = i32::from_le_bytes(reader.array()?);
+                if previous.is_some_and(|p| p >= key) {
+                    return Err(malformed("periods out of order"));
+                }
+                if period_month(table, key) < since {
+                    return Err(malformed("period before the delta's start"));
+                }
+                previous = Some(key);
+                periods.push((table, key, reader.entries()?));
+            }
+        }
+        if !reader.0.is_empty() {
+            return Err(malformed("trailing bytes"));
+        }
+        let mut months = Vec::new();
+        for (table, key, entries) in periods {
+            self.set_period(table, key, entries);
+            if table == RateType::Monthly {
+                self.record_source(key, Source::Restored);
+                months.push(YearMonth::from_key(key));
+            }
+        }
+        Ok(months)
+    }
+}
+
+/// The month a stored period ends in: the month itself, or a year end's March or December.
+fn period_month(table: RateType, key: i32) -> YearMonth {
+    match table {
+        RateType::Monthly => YearMonth::from_key(key),
+        _ => YearEnd::from_key(key).end_year_month(),
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    // Series and tables are far below u32::MAX entries
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_series(out: &mut Vec<u8>, series: Option<&Series>) {
+    let keys = series.map(Series::keys).unwrap_or_default();
+    write_len(out, keys.len());
+    for key in keys {
+        out.extend_from_slice(&key.to_le_bytes());
+        write_entries(out, series.and_then(|s| s.table(key)).unwrap_or_default());
+    }
+}
+
+fn write_entries(out: &mut Vec<u8>, entries: &[Entry]) {
+    write_len(out, entries.len());
+    for entry in entries {
+        out.extend_from_slice(&entry.code);
+        out.push(entry.scale);
+        out.extend_from_slice(&entry.mantissa.to_le_bytes());
+    }
+}
+
+fn malformed(reason: &'static str) -> SnapshotError {
+    SnapshotError::Malformed { reason }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SnapshotError> {
+        if self.0.len() < n {
+            return Err(malformed("truncated"));
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn len(&mut self) -> Result<usize, SnapshotError> {
+        Ok(u32::from_le_bytes(self.array()?) as usize)
+    }
+
+    /// One table's entries, which must be valid rates sorted by code.
+    fn entries(&mut self) -> Result<Vec<Entry>, SnapshotError> {
+        let count = self.len()?;
+        if self.0.len() / 12 < count {
+            return Err(malformed("truncated"));
+        }
+        let mut entries: Vec<Entry> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let code: [u8; 3] = self.array()?;
+            let [scale] = self.array()?;
+            let mantissa = u64::from_le_bytes(self.array()?);
+            if !code.iter().all(u8::is_ascii_uppercase) {
+                return Err(malformed("invalid currency code"));
+            }
+            if mantissa == 0 || scale > 28 {
+                return Err(malformed("invalid rate"));
+            }
+            if entries.last().is_some_and(|last| last.code >= code) {
+                return Err(malformed("currencies out of order"));
+            }
+            entries.push(Entry {
+                mantissa,
+                code,
+                scale,
+            });
+        }
+        Ok(entries)
+    }
+}
@@ -0,0 +1,293 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use rust_decimal::Decimal;
+
+use crate::conversion::Conversion;
+use crate::error::{ConvertError, LookupError};
+use crate::gbp::Gbp;
+use crate::rate::Rate;
+use crate::rates::Rates;
+use crate::types::{Currency, Period, RateType, YearMonth};
+
+/// Anything that can answer a monthly rate: [`Rates`], a database, another feed, a test double.
+///
+/// Implement the two required methods; the provided ones keep the crate's
+/// lookup rules (code normalization, the GBP identity, strict errors)
+/// and its exact conversion the same for every provider.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{Currency, RateSource, YearMonth};
+/// use rust_decimal::Decimal;
+///
+/// struct Flat;
+///
+/// impl RateSource for Flat {
+///     fn units_per_gbp(&self, currency: Currency, _: YearMonth) -> Option<Decimal> {
+///         (currency.as_str() == "USD").then(|| Decimal::new(125, 2))
+///     }
+///
+///     fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+///         None
+///     }
+/// }
+///
+/// let gbp = Flat.to_gbp(Decimal::from(100), "usd", YearMonth::new(2025, 8).unwrap())?;
+/// assert_eq!(gbp.amount(), Decimal::from(80));
+/// # Ok::<(), hmrc_rates::ConvertError>(())
+/// ```
+pub trait RateSource {
+    /// Currency units per £1 in `year_month`, if this source has one.
+    /// Rates that are not positive count as missing.
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal>;
+
+    /// The first and last month this source answers for, if it knows;
+    /// used only to word errors.
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)>;
+
+    /// The rate for `code` in `year_month`, strictly.
+    ///
+    /// A missing rate is [`LookupError::PeriodNotAvailable`] outside the coverage.
+    /// Inside it, as with [`Rates`], it is [`LookupError::NotInPeriod`] if some month
+    /// of the coverage has the currency and [`LookupError::UnknownCurrency`] if none does;
+    /// input that is not a code is [`LookupError::InvalidCurrencyCode`].
+    fn rate(&self, code: &str, year_month: YearMonth) -> Result<Rate, LookupError> {
+        let period = Period::YearMonth(year_month);
+        let currency: Currency = code.parse().map_err(|_| LookupError::InvalidCurrencyCode {
+            code: code.trim().into(),
+            suggestions: Box::default(),
+        })?;
+        if currency == Currency::GBP {
+            return Ok(Rate::new(Decimal::ONE, currency, period));
+        }
+        match self.units_per_gbp(currency, year_month) {
+            Some(rate) if rate > Decimal::ZERO => Ok(Rate::new(rate, currency, period)),
+            _ => {
+                let available = self.monthly_coverage();
+                match available {
+                    Some((first, last)) if first <= year_month && year_month <= last => {
+                        let published = (first.key()..=last.key())
+                            .map(YearMonth::from_key)
+                            .any(|month| self.units_per_gbp(currency, month).is_some());
+                        Err(match published {
+                            true => LookupError::NotInPeriod {
+                                currency,
+                                table: RateType::Monthly,
+                                period,
+                            },
+                            false => LookupError::UnknownCurrency {
+                                code: currency.as_str().into(),
+                                table: RateType::Monthly,
+                                suggestions: Box::default(),
+                            },
+                        })
+                    }
+                    _ => Err(LookupError::PeriodNotAvailable {
+                        table: RateType::Monthly,
+                        period,
+                        available: available
+                            .map(|(f, l)| (Period::YearMonth(f), Period::YearMonth(l))),
+                        nearest: available.map(|(first, last)| {
+                            Period::YearMonth(if year_month < first { first } else { last })
+                        }),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Converts `amount` to GBP at [`RateSource::rate`], exactly; fails rather than
+    /// overflowing, as [`Rate::checked_to_gbp`] does.
+    fn to_gbp(
+        &self,
+        amount: Decimal,
+        code: &str,
+        year_month: YearMonth,
+    ) -> Result<Gbp, ConvertError> {
+        let rate = self.rate(code, year_month)?;
+        Ok(self.convert_at_rate(amount, rate)?.gbp())
+    }
+
+    /// Converts `amount` to GBP at `rate`, one this source answered, exactly;
+    /// fails rather than overflowing, as [`Rate::checked_to_gbp`] does.
+    ///
+    /// The batch converters, `CsvConverter` and `gbp_series`, convert every row
+    /// through it, so a source can vet or record their conversions here.
+    fn convert_at_rate(&self, amount: Decimal, rate: Rate) -> Result<Conversion, ConvertError> {
+        Ok(Conversion::with_gbp(
+            amount,
+            rate,
+            rate.checked_to_gbp(amount)?,
+        ))
+    }
+}
+
+impl RateSource for Rates {
+    /// [`Rates::monthly_rate`], so [`Aliases`](crate::Aliases) apply.
+    fn rate(&self, code: &str, year_month: YearMonth) -> Result<Rate, LookupError> {
+        self.monthly_rate(code, year_month)
+    }
+
+    /// [`Rates::checked_convert`], so the negative-amount policy and an observer apply.
+    fn to_gbp(
+        &self,
+        amount: Decimal,
+        code: &str,
+        year_month: YearMonth,
+    ) -> Result<Gbp, ConvertError> {
+        self.checked_convert(amount, code, year_month)
+    }
+
+    /// Converts as [`Rates::convert_detailed`] does, so the negative-amount policy and an observer apply.
+    fn convert_at_rate(&self, amount: Decimal, rate: Rate) -> Result<Conversion, ConvertError> {
+        self.converted(amount, rate)
+    }
+
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+        Some(
+            self.monthly(year_month)
+                .ok()?
+                .get(currency)?
+                .units_per_gbp(),
+        )
+    }
+
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+        match self.coverage(RateType::Monthly)? {
+            (Period::YearMonth(first), Period::YearMonth(last)) => Some((first, last)),
+            _ => None,
+        }
+    }
+}
+
+impl<S: RateSource + ?Sized> RateSource for &S {
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+        (**self).units_per_gbp(currency, year_month)
+    }
+
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+        (**self).monthly_coverage()
+    }
+
+    fn rate(&self, code: &str, year_month: YearMonth) -> Result<Rate, LookupError> {
+        (**self).rate(code, year_month)
+    }
+
+    fn to_gbp(
+        &self,
+        amount: Decimal,
+        code: &str,
+        year_month: YearMonth,
+    ) -> Result<Gbp, ConvertError> {
+        (**self).to_gbp(amount, code, year_month)
+    }
+
+    fn convert_at_rate(&self, amount: Decimal, rate: Rate) -> Result<Conversion, ConvertError> {
+        (**self).convert_at_rate(amount, rate)
+    }
+}
+
+impl<S: RateSource + ?Sized> RateSource for Box<S> {
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+        (**self).units_per_gbp(currency, year_month)
+    }
+
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+        (**self).monthly_coverage()
+    }
+
+    fn rate(&self, code: &str, year_month: YearMonth) -> Result<Rate, LookupError> {
+        (**self).rate(code, year_month)
+    }
+
+    fn to_gbp(
+        &self,
+        amount: Decimal,
+        code: &str,
+        year_month: YearMonth,
+    ) -> Result<Gbp, ConvertError> {
+        (**self).to_gbp(amount, code, year_month)
+    }
+
+    fn convert_at_rate(&self, amount: Decimal, rate: Rate) -> Result<Conversion, ConvertError> {
+        (**self).convert_at_rate(amount, rate)
+    }
+}
+
+/// A source's rates for a run of conversions, each distinct month and currency
+/// looked up once however many rows share it.
+pub(crate) struct RateCache<'s, S: ?Sized> {
+    source: &'s S,
+    resolved: BTreeMap<(YearMonth, [u8; 3]), Result<Rate, LookupError>>,
+}
+
+impl<'s, S: RateSource + ?Sized> RateCache<'s, S> {
+    pub(crate) fn new(source: &'s S) -> RateCache<'s, S> {
+        RateCache {
+            source,
+            resolved: BTreeMap::new(),
+        }
+    }
+
+    /// [`RateSource::rate`], cached by month and code whatever its case.
+    pub(crate) fn rate(&mut self, code: &str, year_month: YearMonth) -> Result<Rate, LookupError> {
+        match Currency::normalize(code) {
+            Some(normalized) => self
+                .resolved
+                .entry((year_month, normalized))
+                .or_insert_with(|| self.source.rate(code, year_month))
+                .clone(),
+            None => self.source.rate(code, year_month), // aliases, or an error
+        }
+    }
+}
+
+/// Sources tried in order: the first with a rate answers.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{RateSource, Rates, SourceChain, YearMonth};
+///
+/// let corrections = Rates::empty();
+/// let chain = SourceChain::new().with(corrections).with(Rates::new());
+/// let rate = chain.rate("USD", YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+#[derive(Default)]
+pub struct SourceChain {
+    sources: Vec<Box<dyn RateSource + Send + Sync>>,
+}
+
+impl SourceChain {
+    /// A chain with no sources; every lookup fails.
+    pub fn new() -> SourceChain {
+        SourceChain::default()
+    }
+
+    /// Appends `source`, consulted after those already in the chain.
+    pub fn with(mut self, source: impl RateSource + Send + Sync + 'static) -> SourceChain {
+        self.sources.push(Box::new(source));
+        self
+    }
+}
+
+impl RateSource for SourceChain {
+    fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+        self.sources
+            .iter()
+            .filter_map(|s| s.units_per_gbp(currency, year_month))
+            .find(|rate| *rate > Decimal::ZERO)
+    }
+
+    /// The span from the earliest first month to the latest last month.
+    fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+        self.sources
+            .iter()
+            .filter_map(|s| s.monthly_coverage())
+            .reduce(|(f1, l1), (f2, l2)| (f1.min(f2), l1.max(l2)))
+    }
+}
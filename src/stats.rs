@@ -0,0 +1,256 @@
+use alloc::vec::Vec;
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::error::LookupError;
+use crate::rates::{LookupPolicy, Rates};
+use crate::types::{Currency, Period, YearMonth};
+
+/// Summary of one currency's monthly rates over a run of months, see [`Rates::rate_stats`].
+///
+/// All figures are units per £1, like the rates themselves.
+/// Minimum and maximum are published rates; the mean and standard deviation
+/// are computed to `Decimal`'s 28 significant digits, round them for display.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RateStats {
+    currency: Currency,
+    from: YearMonth,
+    to: YearMonth,
+    months: usize,
+    min: Decimal,
+    max: Decimal,
+    mean: Decimal,
+    std_dev: Decimal,
+}
+
+impl RateStats {
+    /// The currency summarised.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The first month covered.
+    pub fn from(&self) -> YearMonth {
+        self.from
+    }
+
+    /// The last month covered (inclusive).
+    pub fn to(&self) -> YearMonth {
+        self.to
+    }
+
+    /// How many monthly rates went in.
+    pub fn months(&self) -> usize {
+        self.months
+    }
+
+    /// The lowest rate.
+    pub fn min(&self) -> Decimal {
+        self.min
+    }
+
+    /// The highest rate.
+    pub fn max(&self) -> Decimal {
+        self.max
+    }
+
+    /// The arithmetic mean of the rates.
+    pub fn mean(&self) -> Decimal {
+        self.mean
+    }
+
+    /// The population standard deviation of the rates.
+    pub fn std_dev(&self) -> Decimal {
+        self.std_dev
+    }
+}
+
+impl Rates {
+    /// Min, max, mean and standard deviation of `code`'s monthly rates
+    /// from `from` to `to`, inclusive and in either order.
+    ///
+    /// Strict like [`Rates::monthly_rate`]: every month in the range must be published
+    /// and list `code`, otherwise its lookup error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let stats = rates.rate_stats(
+    ///     "USD",
+    ///     YearMonth::new(2024, 4).unwrap(),
+    ///     YearMonth::new(2025, 3).unwrap(),
+    /// )?;
+    /// assert_eq!(stats.months(), 12);
+    /// assert!(stats.min() <= stats.mean() && stats.mean() <= stats.max());
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn rate_stats(
+        &self,
+        code: &str,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+    ) -> Result<RateStats, LookupError> {
+        let (from, to) = (from.into(), to.into());
+        let (from, to) = (from.min(to), from.max(to));
+        let mut rates = Vec::new();
+        let mut month = from;
+        loop {
+            rates.push(self.monthly_rate(code, month)?);
+            if month == to {
+                break;
+            }
+            month = month.next();
+        }
+        let values = || rates.iter().map(|rate| rate.units_per_gbp());
+        let count = Decimal::from(rates.len());
+        let mean = values().sum::<Decimal>() / count;
+        let variance = values().map(|v| (v - mean) * (v - mean)).sum::<Decimal>() / count;
+        Ok(RateStats {
+            currency: rates[0].currency(),
+            from,
+            to,
+            months: rates.len(),
+            min: values().min().unwrap_or_default(),
+            max: values().max().unwrap_or_default(),
+            mean,
+            // A sum of squares is never negative, so the root always exists
+            std_dev: variance.sqrt().unwrap_or_default(),
+        })
+    }
+
+    /// How far sterling moved against `code` from `from` to `to`, in percent:
+    /// positive when £1 buys more of it, i.e. GBP strengthened.
+    ///
+    /// Both months are looked up strictly; the figure is exact to `Decimal`'s precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let change = rates.percent_change(
+    ///     "USD",
+    ///     YearMonth::new(2025, 1).unwrap(),
+    ///     YearMonth::new(2025, 8).unwrap(),
+    /// )?;
+    /// println!("GBP moved {}% against USD", change.round_dp(1));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn percent_change(
+        &self,
+        code: &str,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+    ) -> Result<Decimal, LookupError> {
+        let from = self.strict(code, from.into())?;
+        let to = self.strict(code, to.into())?;
+        Ok(change(from, to))
+    }
+
+    /// Rolling volatility of `code`'s monthly rates from `from` to `to`, inclusive:
+    /// the population standard deviation, in percentage points,
+    /// of each `window` consecutive month-on-month [percent changes](Rates::percent_change).
+    ///
+    /// One figure per window, labelled with its last month and ascending;
+    /// none when the range holds fewer than `window` changes or `window` is 0.
+    /// Every month in the range must be published and list `code`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let from = YearMonth::new(2024, 1).unwrap();
+    /// let to = YearMonth::new(2024, 12).unwrap();
+    /// let volatility = rates.rolling_volatility("USD", from, to, 3)?;
+    /// assert_eq!(volatility.len(), 9); // 11 changes, windows of 3
+    /// assert_eq!(volatility[0].0, YearMonth::new(2024, 4).unwrap());
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn rolling_volatility(
+        &self,
+        code: &str,
+        from: impl Into<YearMonth>,
+        to: impl Into<YearMonth>,
+        window: usize,
+    ) -> Result<Vec<(YearMonth, Decimal)>, LookupError> {
+        let (from, to) = (from.into(), to.into());
+        let mut changes = Vec::new();
+        let mut month = from;
+        let mut previous = self.strict(code, month)?;
+        while month < to {
+            month = month.next();
+            let rate = self.strict(code, month)?;
+            changes.push((month, change(previous, rate)));
+            previous = rate;
+        }
+        if window == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(changes
+            .windows(window)
+            .map(|run| {
+                let count = Decimal::from(run.len());
+                let mean = run.iter().map(|(_, c)| *c).sum::<Decimal>() / count;
+                let variance = run
+                    .iter()
+                    .map(|(_, c)| (*c - mean) * (*c - mean))
+                    .sum::<Decimal>()
+                    / count;
+                let last = run[run.len() - 1].0;
+                (last, variance.sqrt().unwrap_or_default())
+            })
+            .collect())
+    }
+
+    fn strict(&self, code: &str, month: YearMonth) -> Result<Decimal, LookupError> {
+        Ok(self
+            .monthly_rate_with(code, month, LookupPolicy::Exact)?
+            .units_per_gbp())
+    }
+
+    /// `code`'s units per £1 on `date`, read off a straight line from its month's rate,
+    /// taken to hold on the 1st, to the next month's rate on the following 1st.
+    ///
+    /// For analytics and charts, where monthly steps show up as artifacts.
+    /// HMRC publishes no such figure: customs and VAT use [`Rates::monthly_rate`].
+    /// The result is a bare `Decimal`, not a [`Rate`](crate::Rate), so it cannot be passed off
+    /// as a published one. Both months must be published and list `code`,
+    /// except on the 1st, which is the month's own rate; no lookup policy applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let aug = rates.monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?.units_per_gbp();
+    /// let sep = rates.monthly_rate("USD", YearMonth::new(2025, 9).unwrap())?.units_per_gbp();
+    /// let mid = rates.interpolated_rate("USD", NaiveDate::from_ymd_opt(2025, 8, 16).unwrap())?;
+    /// assert!(aug.min(sep) <= mid && mid <= aug.max(sep));
+    /// # Ok::<(), hmrc_rates::LookupError>(())
+    /// ```
+    pub fn interpolated_rate(&self, code: &str, date: NaiveDate) -> Result<Decimal, LookupError> {
+        let month = YearMonth::from(date);
+        let start = self.strict(code, month)?;
+        if date.day() == 1 {
+            return Ok(start);
+        }
+        let end = self.strict(code, month.next())?;
+        // `date` lies in `month`, so its last day always exists
+        let days = Period::YearMonth(month).end().map_or(1, |last| last.day());
+        Ok(start + (end - start) * Decimal::from(date.day0()) / Decimal::from(days))
+    }
+}
+
+/// Percent change from `from` to `to`; rates are always positive.
+fn change(from: Decimal, to: Decimal) -> Decimal {
+    (to - from) / from * Decimal::ONE_HUNDRED
+}
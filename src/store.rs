@@ -1,3 +1,5 @@
+use alloc::borrow::Cow;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use rust_decimal::Decimal;
@@ -14,6 +16,18 @@ impl Entry {
     pub fn decimal(&self) -> Decimal {
         Decimal::from_i128_with_scale(self.mantissa as i128, u32::from(self.scale))
     }
+
+    /// `None` unless `rate` is positive with a `u64` mantissa.
+    pub fn from_decimal(code: [u8; 3], rate: Decimal) -> Option<Entry> {
+        if rate <= Decimal::ZERO {
+            return None;
+        }
+        Some(Entry {
+            mantissa: u64::try_from(rate.mantissa()).ok()?,
+            code,
+            scale: u8::try_from(rate.scale()).ok()?,
+        })
+    }
 }
 
 /// Index row for key-addressed series; the period's entries are
@@ -39,7 +53,6 @@ pub(crate) struct StaticSeries {
     pub arena: &'static [Entry],
 }
 
-#[cfg(test)]
 pub(crate) const EMPTY_SERIES: StaticSeries = StaticSeries {
     index: &[],
     arena: &[],
@@ -51,7 +64,6 @@ pub(crate) struct StaticWeeks {
     pub arena: &'static [Entry],
 }
 
-#[cfg(test)]
 pub(crate) const EMPTY_WEEKS: StaticWeeks = StaticWeeks {
     index: &[],
     arena: &[],
@@ -66,7 +78,7 @@ pub(crate) struct Series {
 }
 
 impl Series {
-    pub fn new(statics: StaticSeries) -> Series {
+    pub const fn new(statics: StaticSeries) -> Series {
         Series {
             statics,
             overlay: Vec::new(),
@@ -74,7 +86,6 @@ impl Series {
     }
 
     /// Inserts or replaces a fetched period (entries must be sorted by code).
-    #[cfg(any(test, feature = "http"))]
     pub fn set(&mut self, key: i32, entries: Vec<Entry>) {
         match self.overlay.binary_search_by_key(&key, |(k, _)| *k) {
             Ok(i) => self.overlay[i].1 = entries,
@@ -82,18 +93,30 @@ impl Series {
         }
     }
 
+    /// `true` if `key` was set at runtime rather than bundled.
+    pub fn is_overlaid(&self, key: i32) -> bool {
+        self.overlay.binary_search_by_key(&key, |(k, _)| *k).is_ok()
+    }
+
     pub fn table(&self, key: i32) -> Option<&[Entry]> {
         if let Ok(i) = self.overlay.binary_search_by_key(&key, |(k, _)| *k) {
             return Some(&self.overlay[i].1);
         }
-        let i = self
-            .statics
-            .index
-            .binary_search_by_key(&key, |p| p.key)
-            .ok()?;
+        let i = self.position(key)?;
         Some(self.slice(i))
     }
 
+    /// Index of `key` in the statics; bundled series are gapless, so usually direct.
+    fn position(&self, key: i32) -> Option<usize> {
+        let index = self.statics.index;
+        let first = index.first()?.key;
+        let direct = usize::try_from(i64::from(key) - i64::from(first)).ok()?;
+        match index.get(direct) {
+            Some(p) if p.key == key => Some(direct),
+            _ => index.binary_search_by_key(&key, |p| p.key).ok(),
+        }
+    }
+
     fn slice(&self, i: usize) -> &'static [Entry] {
         let start = if i == 0 {
             0
@@ -158,38 +181,168 @@ impl Series {
     }
 }
 
-/// The weekly series is dead (2014–2016): statics only, addressed by day ranges.
-#[derive(Copy, Clone)]
+/// The weekly series is dead (2014–2016), addressed by day ranges.
+/// Bundled statics, or an owned copy when deserialized.
+#[derive(Clone)]
 pub(crate) struct Weeks {
-    statics: StaticWeeks,
+    index: Cow<'static, [WeekIdx]>,
+    arena: Cow<'static, [Entry]>,
 }
 
 impl Weeks {
-    pub fn new(statics: StaticWeeks) -> Weeks {
-        Weeks { statics }
+    pub const fn new(statics: StaticWeeks) -> Weeks {
+        Weeks {
+            index: Cow::Borrowed(statics.index),
+            arena: Cow::Borrowed(statics.arena),
+        }
+    }
+
+    /// Owned weeks from `(start_day, end_day, entries)` rows, ascending and disjoint.
+    pub fn owned(weeks: Vec<(i32, i32, Vec<Entry>)>) -> Weeks {
+        let mut index = Vec::with_capacity(weeks.len());
+        let mut arena = Vec::new();
+        for (start_day, end_day, entries) in weeks {
+            arena.extend(entries);
+            index.push(WeekIdx {
+                start_day,
+                end_day,
+                end: arena.len() as u32,
+            });
+        }
+        Weeks {
+            index: Cow::Owned(index),
+            arena: Cow::Owned(arena),
+        }
     }
 
     /// The week whose inclusive day range contains `day`.
-    pub fn containing(&self, day: i32) -> Option<(WeekIdx, &'static [Entry])> {
-        let idx = self.statics.index;
+    pub fn containing(&self, day: i32) -> Option<(WeekIdx, &[Entry])> {
+        let idx = &self.index;
         let i = idx.partition_point(|w| w.start_day <= day).checked_sub(1)?;
         let week = idx[i];
-        (day <= week.end_day).then(|| {
-            let start = if i == 0 { 0 } else { idx[i - 1].end as usize };
-            (week, &self.statics.arena[start..week.end as usize])
-        })
+        (day <= week.end_day).then(|| (week, self.slice(i)))
     }
 
-    pub fn index(&self) -> &'static [WeekIdx] {
-        self.statics.index
+    /// The entries of the `i`-th week.
+    pub fn slice(&self, i: usize) -> &[Entry] {
+        let start = if i == 0 {
+            0
+        } else {
+            self.index[i - 1].end as usize
+        };
+        &self.arena[start..self.index[i].end as usize]
+    }
+
+    pub fn index(&self) -> &[WeekIdx] {
+        &self.index
     }
 
-    pub fn arena(&self) -> &'static [Entry] {
-        self.statics.arena
+    pub fn arena(&self) -> &[Entry] {
+        &self.arena
     }
 
     pub fn knows(&self, code: [u8; 3]) -> bool {
-        self.statics.arena.iter().any(|e| e.code == code)
+        self.arena.iter().any(|e| e.code == code)
+    }
+}
+
+/// One country's listing in a monthly file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CountryRow {
+    pub code: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub currency: [u8; 3],
+    pub currency_name: Cow<'static, str>,
+}
+
+/// A bundled listing, unchanged over the monthly keys `first..=last`.
+#[derive(Clone, Debug)]
+pub(crate) struct CountryRun {
+    pub first: i32,
+    pub last: i32,
+    pub row: CountryRow,
+}
+
+/// Country listings per monthly key: bundled runs plus loaded months.
+/// Like [`Series`], a loaded month replaces the bundled listing whole.
+#[derive(Clone)]
+pub(crate) struct Countries {
+    statics: &'static [CountryRun],
+    overlay: Vec<(i32, Vec<CountryRow>)>, // sorted by key, rows by country code
+}
+
+impl Countries {
+    pub const fn new(statics: &'static [CountryRun]) -> Countries {
+        Countries {
+            statics,
+            overlay: Vec::new(),
+        }
+    }
+
+    /// Sets a month's listing; ignores empty ones (files that name no countries).
+    #[cfg(feature = "load")]
+    pub fn set(&mut self, key: i32, mut rows: Vec<CountryRow>) {
+        if rows.is_empty() {
+            return;
+        }
+        rows.sort_by(|a, b| a.code.cmp(&b.code));
+        rows.dedup_by(|a, b| a.code == b.code);
+        match self.overlay.binary_search_by_key(&key, |(k, _)| *k) {
+            Ok(i) => self.overlay[i].1 = rows,
+            Err(i) => self.overlay.insert(i, (key, rows)),
+        }
+    }
+
+    /// The month's listing, by country code.
+    pub fn month(&self, key: i32) -> Vec<&CountryRow> {
+        if let Ok(i) = self.overlay.binary_search_by_key(&key, |(k, _)| *k) {
+            return self.overlay[i].1.iter().collect();
+        }
+        self.statics
+            .iter()
+            .filter(|run| run.first <= key && key <= run.last)
+            .map(|run| &run.row)
+            .collect()
+    }
+}
+
+/// Data shared by every clone of its holder: a `&'static` until first written,
+/// then reference-counted. Cloning is O(1); a write copies only while the data is shared.
+pub(crate) struct Shared<T: 'static> {
+    statics: &'static T,
+    owned: Option<Arc<T>>,
+}
+
+impl<T> Shared<T> {
+    pub const fn new(statics: &'static T) -> Shared<T> {
+        Shared {
+            statics,
+            owned: None,
+        }
+    }
+}
+
+impl<T: Clone> Shared<T> {
+    pub fn make_mut(&mut self) -> &mut T {
+        let statics = self.statics;
+        Arc::make_mut(self.owned.get_or_insert_with(|| Arc::new(statics.clone())))
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Shared<T> {
+        Shared {
+            statics: self.statics,
+            owned: self.owned.clone(),
+        }
+    }
+}
+
+impl<T> core::ops::Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.owned.as_deref().unwrap_or(self.statics)
     }
 }
 
@@ -215,6 +368,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bundled_rows_stay_compact() {
+        // The bundle is typed statics, not embedded XML: 16 bytes per rate
+        assert_eq!(core::mem::size_of::<Entry>(), 16);
+        assert_eq!(core::mem::size_of::<PeriodIdx>(), 8);
+    }
+
     #[test]
     fn entry_decimal_conversion() {
         let e = Entry {
@@ -281,6 +441,44 @@ mod tests {
         assert_eq!(series.codes(), vec![*b"USD"]);
     }
 
+    #[test]
+    fn static_positions_survive_gaps() {
+        static ARENA: [Entry; 3] = [
+            Entry {
+                mantissa: 1,
+                code: *b"USD",
+                scale: 0,
+            },
+            Entry {
+                mantissa: 2,
+                code: *b"USD",
+                scale: 0,
+            },
+            Entry {
+                mantissa: 3,
+                code: *b"USD",
+                scale: 0,
+            },
+        ];
+        static INDEX: [PeriodIdx; 3] = [
+            PeriodIdx { key: 10, end: 1 },
+            PeriodIdx { key: 11, end: 2 },
+            PeriodIdx { key: 13, end: 3 },
+        ];
+        let series = Series::new(StaticSeries {
+            index: &INDEX,
+            arena: &ARENA,
+        });
+        let mantissa = |key| series.table(key).map(|t| t[0].mantissa);
+        assert_eq!(mantissa(10), Some(1));
+        assert_eq!(mantissa(11), Some(2));
+        assert_eq!(mantissa(12), None);
+        assert_eq!(mantissa(13), Some(3)); // past the gap: found by search
+        assert_eq!(mantissa(9), None);
+        assert_eq!(mantissa(i32::MIN), None);
+        assert_eq!(mantissa(i32::MAX), None);
+    }
+
     #[test]
     fn weeks_containment_boundaries() {
         // Two adjacent weeks over a static arena
@@ -0,0 +1,118 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use rust_decimal::Decimal;
+
+use crate::error::ConvertError;
+use crate::gbp::Gbp;
+use crate::rate::Rate;
+use crate::rates::Rates;
+use crate::source::RateCache;
+use crate::types::{Currency, YearMonth};
+
+/// GBP totals of dated foreign amounts, see [`Rates::summarize`].
+///
+/// Every list is ascending. Amounts are exact sums of exact conversions;
+/// round the figures you report, not the items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    total: Gbp,
+    items: usize,
+    by_currency: Vec<(Currency, Gbp)>,
+    by_month: Vec<(YearMonth, Gbp)>,
+    rates: Vec<(YearMonth, Rate)>,
+}
+
+impl Summary {
+    /// Everything, in sterling.
+    pub fn total(&self) -> Gbp {
+        self.total
+    }
+
+    /// How many amounts went in.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// The sterling total of each currency's amounts.
+    pub fn by_currency(&self) -> &[(Currency, Gbp)] {
+        &self.by_currency
+    }
+
+    /// The sterling total of each month's amounts, by the month of their date.
+    pub fn by_month(&self) -> &[(YearMonth, Gbp)] {
+        &self.by_month
+    }
+
+    /// The rate applied to each month's amounts in each currency, by month then currency.
+    ///
+    /// Under a fallback [`LookupPolicy`](crate::LookupPolicy) a rate's period
+    /// can be earlier than the month it was used for.
+    pub fn rates(&self) -> &[(YearMonth, Rate)] {
+        &self.rates
+    }
+}
+
+impl Rates {
+    /// Converts `(amount, currency code, date or month)` items at their month's rate
+    /// and totals them overall, per currency and per month, with the rates used:
+    /// the shape of a VAT return or a Self Assessment foreign income summary.
+    ///
+    /// Lookups follow this view's [`Rates::lookup_policy`] and aliases, one per month and
+    /// currency, and the observer sees every conversion. The first failed conversion is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hmrc_rates::Rates;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    /// let summary = Rates::new().summarize([
+    ///     (Decimal::from(120), "USD", day(7, 3)),
+    ///     (Decimal::from(80), "USD", day(7, 28)),
+    ///     (Decimal::from(5000), "JPY", day(8, 14)),
+    /// ])?;
+    /// println!("total {}", summary.total());
+    /// for (month, gbp) in summary.by_month() {
+    ///     println!("{month}: {gbp}");
+    /// }
+    /// # Ok::<(), hmrc_rates::ConvertError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a total does not fit a `Decimal`; a single conversion that does not fails instead.
+    pub fn summarize<'c, M: Into<YearMonth>>(
+        &self,
+        items: impl IntoIterator<Item = (Decimal, &'c str, M)>,
+    ) -> Result<Summary, ConvertError> {
+        let mut rates = RateCache::new(self);
+        let mut used: BTreeMap<(YearMonth, Currency), Rate> = BTreeMap::new();
+        let mut by_currency: BTreeMap<Currency, Gbp> = BTreeMap::new();
+        let mut by_month: BTreeMap<YearMonth, Gbp> = BTreeMap::new();
+        let mut total = Gbp::ZERO;
+        let mut count = 0;
+        for (amount, code, year_month) in items {
+            let year_month = year_month.into();
+            let rate = rates.rate(code, year_month)?;
+            let gbp = self.converted(amount, rate)?.gbp();
+            used.insert((year_month, rate.currency()), rate);
+            *by_currency.entry(rate.currency()).or_insert(Gbp::ZERO) += gbp;
+            *by_month.entry(year_month).or_insert(Gbp::ZERO) += gbp;
+            total += gbp;
+            count += 1;
+        }
+        Ok(Summary {
+            total,
+            items: count,
+            by_currency: by_currency.into_iter().collect(),
+            by_month: by_month.into_iter().collect(),
+            rates: used
+                .into_iter()
+                .map(|((year_month, _), rate)| (year_month, rate))
+                .collect(),
+        })
+    }
+}
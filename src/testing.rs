@@ -0,0 +1,121 @@
+//! Small, deterministic [`Rates`] for tests and doc examples of crates built on this one,
+//! without depending on the bundled history.
+//!
+//! [`RateTableFixture`] builds exactly the rates a test names; [`tiny`] is a fixed
+//! two-month data set. Both start from [`Rates::empty`], so they behave the same
+//! with or without `bundled` and never change when the bundle is updated.
+//!
+//! A fixture that cannot be built panics: it is a bug in the test using it.
+//!
+//! ```
+//! use hmrc_rates::testing::RateTableFixture;
+//! use hmrc_rates::YearMonth;
+//! use rust_decimal_macros::dec;
+//!
+//! let rates = RateTableFixture::month(2025, 8)
+//!     .rate("USD", dec!(1.354))
+//!     .rate("EUR", dec!(1.15))
+//!     .build();
+//! let usd = rates.monthly_rate("USD", YearMonth::new(2025, 8).unwrap())?;
+//! assert_eq!(usd.to_gbp(dec!(135.4)), dec!(100));
+//! # Ok::<(), hmrc_rates::LookupError>(())
+//! ```
+
+use rust_decimal::Decimal;
+
+use crate::rates::Rates;
+use crate::types::YearMonth;
+
+/// Builds a [`Rates`] holding only the monthly rates given, see the [module docs](self).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct RateTableFixture {
+    rates: Rates,
+    month: YearMonth,
+}
+
+impl RateTableFixture {
+    /// A fixture whose rates go into `month` of `year` until [`RateTableFixture::in_month`].
+    ///
+    /// # Panics
+    ///
+    /// If `month` is not 1 to 12.
+    pub fn month(year: i32, month: u32) -> RateTableFixture {
+        RateTableFixture {
+            rates: Rates::empty(),
+            month: year_month(year, month),
+        }
+    }
+
+    /// Sends the rates that follow to `month` of `year`.
+    ///
+    /// # Panics
+    ///
+    /// If `month` is not 1 to 12.
+    pub fn in_month(mut self, year: i32, month: u32) -> RateTableFixture {
+        self.month = year_month(year, month);
+        self
+    }
+
+    /// Sets `code`'s rate, in units per £1, for the current month.
+    ///
+    /// # Panics
+    ///
+    /// If `code` is not a currency code, or [`Rates::insert_rate`] rejects the rate.
+    #[allow(clippy::panic)] // a bad fixture is a bug in the test using it
+    pub fn rate(mut self, code: &str, rate: Decimal) -> RateTableFixture {
+        let Ok(currency) = code.parse() else {
+            panic!("fixture currency {code:?} is not a currency code");
+        };
+        if let Err(e) = self.rates.insert_rate(self.month, currency, rate) {
+            panic!("fixture rate for {}: {e}", self.month);
+        }
+        self
+    }
+
+    /// The [`Rates`], with default settings.
+    pub fn build(self) -> Rates {
+        self.rates
+    }
+}
+
+#[allow(clippy::panic)] // a bad fixture is a bug in the test using it
+fn year_month(year: i32, month: u32) -> YearMonth {
+    match YearMonth::new(year, month) {
+        Some(year_month) => year_month,
+        None => panic!("fixture month {year}-{month} is not a month"),
+    }
+}
+
+/// A fixed data set of HMRC's published July and August 2025 monthly rates
+/// for USD, EUR, JPY and CHF, and nothing else.
+///
+/// | | USD | EUR | JPY | CHF |
+/// | --- | --- | --- | --- | --- |
+/// | 2025-07 | 1.3451 | 1.1689 | 194.8485 | 1.0996 |
+/// | 2025-08 | 1.3541 | 1.1547 | 197.9633 | 1.0742 |
+///
+/// ```
+/// use hmrc_rates::YearMonth;
+/// use hmrc_rates::testing::tiny;
+///
+/// let rates = tiny();
+/// assert_eq!(rates.months().count(), 2);
+/// let yen = rates.monthly_rate("JPY", YearMonth::new(2025, 8).unwrap())?;
+/// # Ok::<(), hmrc_rates::LookupError>(())
+/// ```
+pub fn tiny() -> Rates {
+    const CODES: [&str; 4] = ["USD", "EUR", "JPY", "CHF"];
+    const MONTHS: [(u32, [i64; 4]); 2] = [
+        (7, [13451, 11689, 1948485, 10996]),
+        (8, [13541, 11547, 1979633, 10742]),
+    ];
+    let mut fixture = RateTableFixture::month(2025, 7);
+    for (month, rates) in MONTHS {
+        fixture = fixture.in_month(2025, month);
+        for (code, rate) in CODES.into_iter().zip(rates) {
+            fixture = fixture.rate(code, Decimal::new(rate, 4));
+        }
+    }
+    fixture.build()
+}
@@ -0,0 +1,279 @@
+use std::io;
+
+use crate::error::{ArithmeticError, ConvertError, LookupError};
+use crate::gbp::Rounding;
+use crate::number::NumberFormat;
+use crate::source::{RateCache, RateSource};
+use crate::types::YearMonth;
+use chrono::NaiveDate;
+
+/// Converts a CSV of transactions to GBP, appending the GBP amount and the rate used.
+///
+/// Every input column and row is written back unchanged, in order,
+/// followed by a `gbp` column and a `rate` column (units per £1, as HMRC publishes it).
+/// Each row uses the monthly rate for its date's month.
+/// The column names, date format and number format are configurable;
+/// GBP amounts are exact unless [`CsvConverter::round`] is set.
+///
+/// # Examples
+///
+/// ```
+/// use hmrc_rates::{CsvConverter, Rates, Rounding};
+///
+/// let input = "Date,Amount,Currency,Memo\n14/08/2025,1200.00,USD,hotel\n";
+/// let mut output = Vec::new();
+/// let rows = CsvConverter::new()
+///     .date_column("Date")
+///     .amount_column("Amount")
+///     .currency_column("Currency")
+///     .date_format("%d/%m/%Y")
+///     .round(2, Rounding::HalfUp)
+///     .convert(&Rates::new(), input.as_bytes(), &mut output)?;
+/// assert_eq!(rows, 1);
+/// assert!(String::from_utf8(output)?.starts_with("Date,Amount,Currency,Memo,gbp,rate\n"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsvConverter {
+    amount: String,
+    currency: String,
+    date: String,
+    gbp: String,
+    rate: String,
+    date_format: String,
+    number_format: NumberFormat,
+    round: Option<(u32, Rounding)>,
+    delimiter: u8,
+    headerless: bool,
+    streaming: bool,
+}
+
+/// Why [`CsvConverter::convert`] stopped.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum CsvError {
+    /// Reading or writing the CSV failed.
+    #[error("CSV conversion failed: {0}")]
+    Csv(#[from] csv::Error),
+    /// The header has no column of this name.
+    #[error("the CSV header has no '{column}' column")]
+    MissingColumn { column: String },
+    /// A cell could not be read as an amount or a date.
+    #[error("line {line}: invalid {column} '{value}'")]
+    InvalidValue {
+        line: u64,
+        column: String,
+        value: String,
+    },
+    /// The row's currency has no rate for its month.
+    #[error("line {line}: {source}")]
+    Lookup {
+        line: u64,
+        #[source]
+        source: LookupError,
+    },
+    /// The row's amount is too large to convert exactly, see [`Rate::checked_to_gbp`](crate::Rate::checked_to_gbp).
+    #[error("line {line}: {source}")]
+    Arithmetic {
+        line: u64,
+        #[source]
+        source: ArithmeticError,
+    },
+    /// The source refused to convert the row's amount, such as a negative one
+    /// under [`NegativeAmounts::Reject`](crate::NegativeAmounts::Reject).
+    #[error("line {line}: {source}")]
+    Rejected {
+        line: u64,
+        #[source]
+        source: ConvertError,
+    },
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> CsvError {
+        CsvError::Csv(e.into())
+    }
+}
+
+impl Default for CsvConverter {
+    fn default() -> CsvConverter {
+        CsvConverter::new()
+    }
+}
+
+impl CsvConverter {
+    /// Reads `amount`, `currency` and `date` (`YYYY-MM-DD`) columns, writes `gbp` and `rate`.
+    pub fn new() -> CsvConverter {
+        CsvConverter {
+            amount: "amount".into(),
+            currency: "currency".into(),
+            date: "date".into(),
+            gbp: "gbp".into(),
+            rate: "rate".into(),
+            date_format: "%Y-%m-%d".into(),
+            number_format: NumberFormat::PLAIN,
+            round: None,
+            delimiter: b',',
+            headerless: false,
+            streaming: false,
+        }
+    }
+
+    /// The header of the amount column.
+    pub fn amount_column(mut self, name: impl Into<String>) -> CsvConverter {
+        self.amount = name.into();
+        self
+    }
+
+    /// The header of the currency code column. Codes may be lowercase.
+    pub fn currency_column(mut self, name: impl Into<String>) -> CsvConverter {
+        self.currency = name.into();
+        self
+    }
+
+    /// The header of the transaction date column.
+    pub fn date_column(mut self, name: impl Into<String>) -> CsvConverter {
+        self.date = name.into();
+        self
+    }
+
+    /// The header of the appended GBP column.
+    pub fn gbp_column(mut self, name: impl Into<String>) -> CsvConverter {
+        self.gbp = name.into();
+        self
+    }
+
+    /// The header of the appended rate column.
+    pub fn rate_column(mut self, name: impl Into<String>) -> CsvConverter {
+        self.rate = name.into();
+        self
+    }
+
+    /// A `chrono` format for the dates, e.g. `"%d/%m/%Y"`.
+    pub fn date_format(mut self, format: impl Into<String>) -> CsvConverter {
+        self.date_format = format.into();
+        self
+    }
+
+    /// How amounts are written, e.g. [`NumberFormat::UK`] for `1,234.56`.
+    pub fn number_format(mut self, format: NumberFormat) -> CsvConverter {
+        self.number_format = format;
+        self
+    }
+
+    /// Rounds the GBP column to `decimal_places` with `rounding`.
+    pub fn round(mut self, decimal_places: u32, rounding: Rounding) -> CsvConverter {
+        self.round = Some((decimal_places, rounding));
+        self
+    }
+
+    /// The field separator for input and output, e.g. `b'\t'` for TSV.
+    pub fn delimiter(mut self, delimiter: u8) -> CsvConverter {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Reads input without a header row: the amount, currency and date are the first
+    /// three fields of each row, whatever the column names are set to, and no header is written.
+    pub fn headerless(mut self) -> CsvConverter {
+        self.headerless = true;
+        self
+    }
+
+    /// Flushes `writer` after every row, so whatever reads a pipe gets each row
+    /// as soon as it is converted rather than a buffer at a time.
+    pub fn streaming(mut self) -> CsvConverter {
+        self.streaming = true;
+        self
+    }
+
+    /// Converts every row of `reader` and writes the result to `writer`.
+    ///
+    /// Returns the number of rows written.
+    /// Stops at the first row that fails, so the output never silently lacks a rate.
+    pub fn convert(
+        &self,
+        source: &(impl RateSource + ?Sized),
+        reader: impl io::Read,
+        writer: impl io::Write,
+    ) -> Result<u64, CsvError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(!self.headerless)
+            .from_reader(reader);
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(writer);
+        let (amount, currency, date) = if self.headerless {
+            (0, 1, 2)
+        } else {
+            let headers = reader.headers()?.clone();
+            let column = |name: &str| {
+                headers
+                    .iter()
+                    .position(|h| h.trim() == name)
+                    .ok_or_else(|| CsvError::MissingColumn {
+                        column: name.into(),
+                    })
+            };
+            let columns = (
+                column(&self.amount)?,
+                column(&self.currency)?,
+                column(&self.date)?,
+            );
+            let mut out = headers.clone();
+            out.push_field(&self.gbp);
+            out.push_field(&self.rate);
+            writer.write_record(&out)?;
+            columns
+        };
+
+        let mut rates = RateCache::new(source);
+        let mut rows = 0;
+        for record in reader.records() {
+            let mut record = record?;
+            let line = record.position().map_or(0, |p| p.line());
+            let invalid = |column: &str, value: &str| CsvError::InvalidValue {
+                line,
+                column: column.into(),
+                value: value.into(),
+            };
+            let value = record.get(amount).unwrap_or("").trim();
+            let amount_value = self
+                .number_format
+                .parse(value)
+                .ok_or_else(|| invalid(&self.amount, value))?;
+            let value = record.get(date).unwrap_or("").trim();
+            let year_month = NaiveDate::parse_from_str(value, &self.date_format)
+                .map(YearMonth::from)
+                .map_err(|_| invalid(&self.date, value))?;
+            let code = record.get(currency).unwrap_or("");
+            let rate = rates
+                .rate(code, year_month)
+                .map_err(|source| CsvError::Lookup { line, source })?;
+
+            let gbp = source
+                .convert_at_rate(amount_value, rate)
+                .map_err(|source| match source {
+                    ConvertError::Lookup(source) => CsvError::Lookup { line, source },
+                    ConvertError::Arithmetic(source) => CsvError::Arithmetic { line, source },
+                    source => CsvError::Rejected { line, source },
+                })?
+                .gbp()
+                .amount();
+            let gbp = match self.round {
+                Some((decimal_places, rounding)) => rounding.apply(gbp, decimal_places),
+                None => gbp,
+            };
+            record.push_field(&gbp.to_string());
+            record.push_field(&rate.units_per_gbp().to_string());
+            writer.write_record(&record)?;
+            if self.streaming {
+                writer.flush()?;
+            }
+            rows += 1;
+        }
+        writer.flush()?;
+        Ok(rows)
+    }
+}
@@ -174,7 +174,15 @@ impl fmt::Display for YearEnd {
 /// A three-letter currency code as published by HMRC.
 ///
 /// Codes are HMRC's own, not always ISO 4217, e.g., Ecuador appears as `ECS`.
-/// Lookups accept plain `&str` (case-insensitive); the library returns `Currency`.
+/// Lookups accept a `Currency` or plain `&str` (case-insensitive);
+/// the library returns `Currency`.
+///
+/// ```
+/// use hmrc_rates::Currency;
+/// let usd: Currency = " usd ".parse().unwrap();
+/// assert_eq!(usd.to_string(), "USD");
+/// assert!("US DOLLARS".parse::<Currency>().is_err());
+/// ```
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Currency([u8; 3]);
 
@@ -188,6 +196,29 @@ impl Currency {
         core::str::from_utf8(&self.0).unwrap_or("???")
     }
 
+    /// Digits after the point in the currency's conventional minor unit, per ISO 4217.
+    ///
+    /// 0 for `JPY` and `KRW`, 3 for `BHD` and `KWD`, 2 for anything not listed,
+    /// including codes HMRC uses that ISO does not.
+    ///
+    /// ```
+    /// use hmrc_rates::Currency;
+    ///
+    /// let code = |s: &str| s.parse::<Currency>().unwrap();
+    /// assert_eq!(code("JPY").minor_units(), 0);
+    /// assert_eq!(code("BHD").minor_units(), 3);
+    /// assert_eq!(code("EUR").minor_units(), 2);
+    /// ```
+    pub fn minor_units(&self) -> u32 {
+        match &self.0 {
+            b"BIF" | b"CLP" | b"DJF" | b"GNF" | b"ISK" | b"JPY" | b"KMF" | b"KRW" | b"PYG"
+            | b"RWF" | b"UGX" | b"UYI" | b"VND" | b"VUV" | b"XAF" | b"XOF" | b"XPF" => 0,
+            b"BHD" | b"IQD" | b"JOD" | b"KWD" | b"LYD" | b"OMR" | b"TND" => 3,
+            b"CLF" | b"UYW" => 4,
+            _ => 2,
+        }
+    }
+
     pub(crate) fn from_code(code: [u8; 3]) -> Currency {
         Currency(code)
     }
@@ -209,6 +240,13 @@ impl Currency {
             bytes[2].to_ascii_uppercase(),
         ])
     }
+
+    /// A code from another crate's ISO 4217 currency type.
+    #[cfg(any(feature = "rusty-money", feature = "iso-currency"))]
+    pub(crate) fn from_iso_code(code: &str) -> Currency {
+        // Every ISO 4217 code is three letters; XXX is ISO's own "no currency"
+        Currency::normalize(code).map_or(Currency::from_code(*b"XXX"), Currency::from_code)
+    }
 }
 
 impl fmt::Display for Currency {
@@ -217,6 +255,37 @@ impl fmt::Display for Currency {
     }
 }
 
+impl AsRef<str> for Currency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The error returned when parsing a [`Currency`] from a string fails.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ParseCurrencyError;
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid currency code, expected three ASCII letters")
+    }
+}
+
+impl core::error::Error for ParseCurrencyError {}
+
+/// Parses three ASCII letters, any case, surrounding whitespace ignored.
+///
+/// Checks the form only: whether HMRC publishes the code is the lookup's call.
+impl core::str::FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Currency, ParseCurrencyError> {
+        Currency::normalize(s)
+            .map(Currency::from_code)
+            .ok_or(ParseCurrencyError)
+    }
+}
+
 /// The four rate series HMRC has published.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -258,6 +327,71 @@ pub enum Period {
     Week { start: NaiveDate, end: NaiveDate },
 }
 
+impl Period {
+    /// The first day the period covers.
+    ///
+    /// A year end spans the twelve months up to it, e.g. 1 April 2025 for the
+    /// year ending 31 March 2026. Spot rates are quoted on [`Period::end`] alone.
+    /// `None` only for years outside `chrono`'s range.
+    pub fn start(&self) -> Option<NaiveDate> {
+        match *self {
+            Period::YearMonth(m) => NaiveDate::from_ymd_opt(m.year(), m.month(), 1),
+            Period::YearEnd(ye) => {
+                let first = YearMonth::from_key(ye.end_year_month().key().checked_sub(11)?);
+                NaiveDate::from_ymd_opt(first.year(), first.month(), 1)
+            }
+            Period::Week { start, .. } => Some(start),
+        }
+    }
+
+    /// The last day the period covers (inclusive).
+    pub fn end(&self) -> Option<NaiveDate> {
+        let last_of = |m: YearMonth| {
+            let next = m.next();
+            NaiveDate::from_ymd_opt(next.year(), next.month(), 1)?.pred_opt()
+        };
+        match *self {
+            Period::YearMonth(m) => last_of(m),
+            Period::YearEnd(ye) => last_of(ye.end_year_month()),
+            Period::Week { end, .. } => Some(end),
+        }
+    }
+
+    /// `true` if `date` falls from [`Period::start`] to [`Period::end`] inclusive.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hmrc_rates::{Period, YearMonth};
+    ///
+    /// let feb = Period::YearMonth(YearMonth::new(2024, 2).unwrap());
+    /// assert!(feb.contains(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+    /// assert!(!feb.contains(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()));
+    /// ```
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start().is_some_and(|start| start <= date) && self.end().is_some_and(|end| date <= end)
+    }
+
+    /// The calendar year the period ends in: a month's own year, the year of a year end,
+    /// or the year of a week's last day.
+    pub fn year(&self) -> i32 {
+        match *self {
+            Period::YearMonth(m) => m.year(),
+            Period::YearEnd(ye) => ye.year(),
+            Period::Week { end, .. } => end.year(),
+        }
+    }
+
+    /// The calendar month (1 to 12) the period ends in: a month itself,
+    /// 3 or 12 for a year end, or the month of a week's last day.
+    pub fn month(&self) -> u32 {
+        match *self {
+            Period::YearMonth(m) => m.month(),
+            Period::YearEnd(ye) => ye.end_year_month().month(),
+            Period::Week { end, .. } => end.month(),
+        }
+    }
+}
+
 impl fmt::Display for Period {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -369,6 +503,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn period_date_bounds() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let feb = Period::YearMonth(YearMonth::new(2024, 2).unwrap());
+        assert_eq!(feb.start(), Some(date(2024, 2, 1)));
+        assert_eq!(feb.end(), Some(date(2024, 2, 29)));
+        let dec = Period::YearMonth(YearMonth::new(2025, 12).unwrap());
+        assert_eq!(dec.end(), Some(date(2025, 12, 31)));
+
+        let march = Period::YearEnd(YearEnd::march(2026));
+        assert_eq!(march.start(), Some(date(2025, 4, 1)));
+        assert_eq!(march.end(), Some(date(2026, 3, 31)));
+        let december = Period::YearEnd(YearEnd::december(2025));
+        assert_eq!(december.start(), Some(date(2025, 1, 1)));
+        assert_eq!(december.end(), Some(date(2025, 12, 31)));
+
+        let far = Period::YearMonth(YearMonth::new(i32::MAX / 12, 1).unwrap());
+        assert_eq!(far.start(), None);
+        assert_eq!(far.end(), None);
+    }
+
     #[test]
     fn currency_normalization() {
         assert_eq!(Currency::normalize(" usd "), Some(*b"USD"));
@@ -378,5 +533,7 @@ mod tests {
         assert_eq!(Currency::normalize("USDX"), None);
         assert_eq!(Currency::normalize("U5D"), None);
         assert_eq!(Currency::GBP.as_str(), "GBP");
+        assert_eq!("gbp".parse(), Ok(Currency::GBP));
+        assert_eq!("G8P".parse::<Currency>(), Err(ParseCurrencyError));
     }
 }
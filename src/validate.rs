@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+use rust_decimal::Decimal;
+
+use crate::rates::Rates;
+use crate::types::{Currency, YearMonth};
+
+/// Month-on-month change beyond which a rate counts as a jump: ×1.5, or ÷1.5.
+const JUMP: Decimal = Decimal::from_parts(15, 0, 0, false, 1);
+
+/// One suspicious feature of the monthly series, see [`Rates::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ValidationIssue {
+    /// No table for a month between the first and last loaded.
+    #[error("no monthly rates for {month}")]
+    MissingMonth { month: YearMonth },
+
+    /// A rate moved by more than half between consecutive loaded months.
+    #[error("{currency} moved from {from_rate} in {from} to {to_rate} in {to}")]
+    RateJump {
+        currency: Currency,
+        from: YearMonth,
+        from_rate: Decimal,
+        to: YearMonth,
+        to_rate: Decimal,
+    },
+
+    /// A currency in one loaded month is absent from the next.
+    #[error("{currency} is in {last} but not {next}")]
+    CurrencyDropped {
+        currency: Currency,
+        last: YearMonth,
+        next: YearMonth,
+    },
+}
+
+/// The findings of [`Rates::validate`], in month order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Every issue found.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// `true` if nothing looked suspicious.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Rates {
+    /// Sanity-checks the monthly series, for data from outside the bundle.
+    ///
+    /// Flags months missing inside the loaded range, rates that move by more
+    /// than half from one loaded month to the next, and currencies that vanish.
+    /// These are warnings, not errors: HMRC's own history has real jumps
+    /// (redenominations, devaluations) and currencies that end (HRK in 2023).
+    /// Zero, negative and duplicate rates cannot occur, every loader rejects them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Rates, ValidationIssue};
+    ///
+    /// let report = Rates::new().validate();
+    /// assert!(!report
+    ///     .issues()
+    ///     .iter()
+    ///     .any(|issue| matches!(issue, ValidationIssue::MissingMonth { .. })));
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let mut issues = Vec::new();
+        let months: Vec<YearMonth> = self.months().collect();
+        for pair in months.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            issues.extend(gaps(from, to).map(|month| ValidationIssue::MissingMonth { month }));
+            let (Ok(before), Ok(after)) = (self.monthly(from), self.monthly(to)) else {
+                continue;
+            };
+            for (currency, rate) in before.iter() {
+                let Some(next) = after.get(currency) else {
+                    issues.push(ValidationIssue::CurrencyDropped {
+                        currency,
+                        last: from,
+                        next: to,
+                    });
+                    continue;
+                };
+                let (from_rate, to_rate) = (rate.units_per_gbp(), next.units_per_gbp());
+                if to_rate > from_rate * JUMP || from_rate > to_rate * JUMP {
+                    issues.push(ValidationIssue::RateJump {
+                        currency,
+                        from,
+                        from_rate,
+                        to,
+                        to_rate,
+                    });
+                }
+            }
+        }
+        ValidationReport { issues }
+    }
+
+    /// The months missing between the first and last loaded, ascending.
+    ///
+    /// Strict lookups already fail for these; this finds them up front,
+    /// before a fallback such as [`LookupPolicy::Earlier`](crate::LookupPolicy::Earlier)
+    /// quietly covers them. The bundle has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hmrc_rates::{Currency, Rates, YearMonth};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let usd: Currency = "USD".parse().unwrap();
+    /// let rates = Rates::empty()
+    ///     .with_rate(YearMonth::new(2023, 2).unwrap(), usd, Decimal::ONE)?
+    ///     .with_rate(YearMonth::new(2023, 4).unwrap(), usd, Decimal::ONE)?;
+    /// assert_eq!(rates.missing_months(), [YearMonth::new(2023, 3).unwrap()]);
+    /// # Ok::<(), hmrc_rates::InvalidRate>(())
+    /// ```
+    pub fn missing_months(&self) -> Vec<YearMonth> {
+        let months: Vec<YearMonth> = self.months().collect();
+        months
+            .windows(2)
+            .flat_map(|pair| gaps(pair[0], pair[1]))
+            .collect()
+    }
+}
+
+/// The months strictly between `from` and `to`.
+fn gaps(from: YearMonth, to: YearMonth) -> impl Iterator<Item = YearMonth> {
+    core::iter::successors(Some(from.next()), |month| Some(month.next()))
+        .take_while(move |month| *month < to)
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Seek, Write};
+use std::path::Path;
+
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::error::LookupError;
+use crate::pretty::HEADER;
+use crate::rates::Rates;
+use crate::types::YearMonth;
+
+/// Why [`Rates::export_xlsx`] failed.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum XlsxError {
+    /// Creating or writing the file failed.
+    #[error("writing the workbook failed: {0}")]
+    Io(#[from] io::Error),
+    /// Packaging the workbook failed.
+    #[error("writing the workbook failed: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    /// A requested month has no monthly table.
+    #[error(transparent)]
+    Lookup(#[from] LookupError),
+    /// No months were given: Excel will not open a workbook without sheets.
+    #[error("no months to export")]
+    NoMonths,
+}
+
+/// A sheet's month and its rows.
+type Sheet = (YearMonth, Vec<[String; 3]>);
+
+const MAIN: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const PACKAGE_RELATIONSHIPS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+
+impl Rates {
+    /// Writes an Excel workbook to `path` with one sheet per month, named `YYYY-MM`,
+    /// of the rows [`Rates::format_month`] prints: currency, country, rate.
+    ///
+    /// Sheets are in month order, whatever order `months` come in. Rates are numeric
+    /// cells holding HMRC's exact figures. Any month without a table, or no months at all,
+    /// fails before the file is created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hmrc_rates::{Rates, YearMonth};
+    ///
+    /// let rates = Rates::new();
+    /// let year = (1..=12).map(|month| YearMonth::new(2024, month).unwrap());
+    /// rates.export_xlsx("rates-2024.xlsx", year)?;
+    /// # Ok::<(), hmrc_rates::XlsxError>(())
+    /// ```
+    pub fn export_xlsx(
+        &self,
+        path: impl AsRef<Path>,
+        months: impl IntoIterator<Item = impl Into<YearMonth>>,
+    ) -> Result<(), XlsxError> {
+        let sheets = self.sheets(months)?;
+        let mut file = File::create(path)?;
+        write_workbook(&mut file, &sheets)?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    /// [`Rates::export_xlsx`] into any seekable writer, such as an in-memory
+    /// `Cursor<Vec<u8>>` for an HTTP response.
+    pub fn write_xlsx(
+        &self,
+        writer: impl Write + Seek,
+        months: impl IntoIterator<Item = impl Into<YearMonth>>,
+    ) -> Result<(), XlsxError> {
+        write_workbook(writer, &self.sheets(months)?)
+    }
+
+    fn sheets(
+        &self,
+        months: impl IntoIterator<Item = impl Into<YearMonth>>,
+    ) -> Result<Vec<Sheet>, XlsxError> {
+        let months: BTreeSet<YearMonth> = months.into_iter().map(Into::into).collect();
+        if months.is_empty() {
+            return Err(XlsxError::NoMonths);
+        }
+        months
+            .into_iter()
+            .map(|month| Ok((month, self.month_rows(month)?)))
+            .collect()
+    }
+}
+
+fn write_workbook(writer: impl Write + Seek, sheets: &[Sheet]) -> Result<(), XlsxError> {
+    let mut zip = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default();
+    let part = |zip: &mut ZipWriter<_>, name: &str, xml: &str| -> Result<(), XlsxError> {
+        zip.start_file(name, options)?;
+        zip.write_all(xml.as_bytes())?;
+        Ok(())
+    };
+
+    let mut types = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+        r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+        r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+        r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+    ));
+    for n in 1..=sheets.len() {
+        let _ = write!(
+            types,
+            r#"<Override PartName="/xl/worksheets/sheet{n}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+        );
+    }
+    types.push_str("</Types>");
+    part(&mut zip, "[Content_Types].xml", &types)?;
+
+    part(
+        &mut zip,
+        "_rels/.rels",
+        &format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{PACKAGE_RELATIONSHIPS}"><Relationship Id="rId1" Type="{RELATIONSHIPS}/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+        ),
+    )?;
+
+    let mut workbook = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="{MAIN}" xmlns:r="{RELATIONSHIPS}"><sheets>"#
+    );
+    let mut relationships = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{PACKAGE_RELATIONSHIPS}">"#
+    );
+    for (n, (month, _)) in (1..).zip(sheets) {
+        let _ = write!(
+            workbook,
+            r#"<sheet name="{month}" sheetId="{n}" r:id="rId{n}"/>"#
+        );
+        let _ = write!(
+            relationships,
+            r#"<Relationship Id="rId{n}" Type="{RELATIONSHIPS}/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+        );
+    }
+    workbook.push_str("</sheets></workbook>");
+    relationships.push_str("</Relationships>");
+    part(&mut zip, "xl/workbook.xml", &workbook)?;
+    part(&mut zip, "xl/_rels/workbook.xml.rels", &relationships)?;
+
+    for (n, (_, rows)) in (1..).zip(sheets) {
+        part(
+            &mut zip,
+            &format!("xl/worksheets/sheet{n}.xml"),
+            &worksheet(rows),
+        )?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// A sheet of text cells for the header, currency and country, and a number for the rate.
+fn worksheet(rows: &[[String; 3]]) -> String {
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="{MAIN}"><sheetData>"#
+    );
+    let text = |xml: &mut String, cell: &str, value: &str| {
+        let _ = write!(
+            xml,
+            r#"<c r="{cell}" t="inlineStr"><is><t>{}</t></is></c>"#,
+            escape(value)
+        );
+    };
+    xml.push_str(r#"<row r="1">"#);
+    for (column, title) in ["A1", "B1", "C1"].into_iter().zip(HEADER) {
+        text(&mut xml, column, title);
+    }
+    xml.push_str("</row>");
+    for (r, [currency, country, rate]) in (2..).zip(rows) {
+        let _ = write!(xml, r#"<row r="{r}">"#);
+        text(&mut xml, &format!("A{r}"), currency);
+        if !country.is_empty() {
+            text(&mut xml, &format!("B{r}"), country);
+        }
+        let _ = write!(xml, r#"<c r="C{r}"><v>{rate}</v></c></row>"#);
+    }
+    xml.push_str("</sheetData></worksheet>");
+    xml
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -3,7 +3,11 @@
 #![allow(clippy::unwrap_used, clippy::panic)]
 
 use chrono::NaiveDate;
-use hmrc_rates::{LookupError, Period, RateType, Rates, YearEnd, YearMonth};
+use hmrc_rates::{
+    Aliases, ArithmeticError, ConvertError, Currency, ForeignMoney, Gbp, InvalidRate, LookupError,
+    LookupPolicy, MoneyFormat, NegativeAmounts, NumberFormat, Period, RateSource, RateType, Rates,
+    Redenomination, Rounding, SourceChain, YearEnd, YearMonth,
+};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -22,6 +26,22 @@ fn bundled_monthly_coverage_is_contiguous_from_2014_02() {
     }
 }
 
+#[test]
+fn coverage_matches_the_period_listings() {
+    let rates = Rates::new();
+    let months: Vec<YearMonth> = rates.months().collect();
+    let (first, last) = rates.coverage(RateType::Monthly).unwrap();
+    assert_eq!(first, Period::YearMonth(months[0]));
+    assert_eq!(last, Period::YearMonth(*months.last().unwrap()));
+    assert_eq!(first.start(), Some(date(2014, 2, 1)));
+
+    let (first, _) = rates.coverage(RateType::Spot).unwrap();
+    assert_eq!(first, Period::YearEnd(YearEnd::december(2010)));
+    let (first, last) = rates.coverage(RateType::Weekly).unwrap();
+    assert_eq!(first.start(), Some(date(2014, 1, 8)));
+    assert_eq!(Some(last), rates.weeks().next_back());
+}
+
 #[test]
 fn golden_usd_and_eur_august_2025() {
     let rates = Rates::new();
@@ -48,6 +68,42 @@ fn conversions_are_exact_not_rounded() {
     assert_eq!(rate.from_gbp(gbp).round_dp(10), dec!(100));
 }
 
+#[test]
+fn free_convert_uses_the_shared_bundle() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    assert!(core::ptr::eq(Rates::bundled(), Rates::bundled()));
+    assert_eq!(
+        hmrc_rates::convert(dec!(100), "usd", date(2025, 8, 15)).unwrap(),
+        Gbp::new(dec!(100) / dec!(1.3541)),
+    );
+    assert_eq!(
+        hmrc_rates::convert(dec!(100), "XXX", aug),
+        Err(LookupError::UnknownCurrency {
+            code: "XXX".into(),
+            table: RateType::Monthly,
+            suggestions: Box::default(),
+        })
+    );
+}
+
+#[test]
+fn unit_prices_scale_without_rounding_drift() {
+    // Rounding a unit price to pence before multiplying would be off by pounds
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let unit = rates.convert_detailed(dec!(0.0137), "USD", month).unwrap();
+    let total = rates.convert_detailed(dec!(1370), "USD", month).unwrap();
+    let scaled = unit.gbp() * dec!(100000);
+    assert_eq!(
+        scaled.amount().round_dp(18),
+        total.gbp().amount().round_dp(18)
+    );
+    assert_ne!(
+        unit.gbp().round(2, Rounding::HalfUp) * dec!(100000),
+        total.gbp().round(2, Rounding::HalfUp)
+    );
+}
+
 #[test]
 fn strict_monthly_lookup_errors_outside_coverage() {
     let rates = Rates::new();
@@ -119,18 +175,83 @@ fn fallback_walks_back_and_reveals_period() {
 }
 
 #[test]
-fn unknown_currency_vs_not_in_period() {
+fn lookup_policies_bound_their_fallback() {
     let rates = Rates::new();
-    // Garbage input and never-published codes fold into UnknownCurrency
-    for code in ["XXX", "", "US DOLLARS", "usd extra"] {
-        let err = rates
-            .monthly_rate(code, YearMonth::new(2025, 8).unwrap())
-            .unwrap_err();
-        assert!(
-            matches!(err, LookupError::UnknownCurrency { .. }),
-            "{code:?}: {err}"
+    let first = YearMonth::new(2014, 2).unwrap();
+    let before = first.prev().prev(); // 2013-12, two months before coverage
+    let usd = |month, policy| rates.monthly_rate_with("USD", month, policy);
+
+    assert!(usd(before, LookupPolicy::Exact).is_err());
+    assert!(usd(before, LookupPolicy::Earlier(24)).is_err()); // nothing earlier exists
+    assert!(usd(before, LookupPolicy::Nearest(1)).is_err()); // one month is not enough
+    let rate = usd(before, LookupPolicy::Nearest(2)).unwrap();
+    assert_eq!(rate.period(), Period::YearMonth(first));
+
+    // Published months resolve to themselves under every policy
+    let month = YearMonth::new(2025, 8).unwrap();
+    for policy in [
+        LookupPolicy::Exact,
+        LookupPolicy::Earlier(3),
+        LookupPolicy::Nearest(3),
+        LookupPolicy::LatestWithin(3),
+    ] {
+        assert_eq!(
+            usd(month, policy).unwrap().period(),
+            Period::YearMonth(month)
         );
     }
+    assert_eq!(LookupPolicy::default(), LookupPolicy::Exact);
+
+    // Past the end, Nearest behaves like Earlier
+    let last = rates.months().next_back().unwrap();
+    let rate = usd(last.next(), LookupPolicy::Nearest(1)).unwrap();
+    assert_eq!(rate.period(), Period::YearMonth(last));
+}
+
+#[test]
+fn latest_within_covers_only_months_past_the_newest() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let rates = Rates::empty()
+        .with_rate(aug.prev().prev(), "USD".parse().unwrap(), dec!(1.30))
+        .unwrap()
+        .with_rate(aug, "USD".parse().unwrap(), dec!(1.36))
+        .unwrap();
+    let usd = |month, policy| rates.monthly_rate_with("USD", month, policy);
+
+    let ahead = aug.next().next().next();
+    assert!(usd(ahead, LookupPolicy::Exact).is_err());
+    assert!(usd(ahead, LookupPolicy::LatestWithin(2)).is_err());
+    let rate = usd(ahead, LookupPolicy::LatestWithin(3)).unwrap();
+    assert_eq!(rate.period(), Period::YearMonth(aug));
+    let rate = usd(
+        YearMonth::new(2099, 1).unwrap(),
+        LookupPolicy::LatestWithin(u32::MAX),
+    )
+    .unwrap();
+    assert_eq!(rate.period(), Period::YearMonth(aug));
+    // A hole inside the data is never filled
+    assert!(usd(aug.prev(), LookupPolicy::LatestWithin(u32::MAX)).is_err());
+}
+
+#[test]
+fn unknown_currency_vs_not_in_period() {
+    let rates = Rates::new();
+    // A well-formed code never published is UnknownCurrency
+    let aug = YearMonth::new(2025, 8).unwrap();
+    assert!(matches!(
+        rates.monthly_rate("XXX", aug),
+        Err(LookupError::UnknownCurrency { .. })
+    ));
+    // Input that cannot be a code is rejected before any table, even for missing months
+    for code in ["", "US DOLLARS", "usd extra", "U5D"] {
+        for month in [aug, YearMonth::new(2100, 1).unwrap()] {
+            let err = rates.monthly_rate(code, month).unwrap_err();
+            assert!(
+                matches!(err, LookupError::InvalidCurrencyCode { .. }),
+                "{code:?}: {err}"
+            );
+        }
+    }
     // GHS appears in the full-list Dec 2015 spot table but not in Dec 2024
     let err = rates
         .spot(YearEnd::december(2024))
@@ -253,6 +374,12 @@ fn discovery_iterators() {
     let (currency, rate) = table.iter().next().unwrap();
     assert_eq!(rate.currency(), currency);
     assert_eq!(rate.period(), table.period());
+
+    // The per-month list is the table's own, not the series' union
+    let codes: Vec<_> = table.currencies().collect();
+    assert_eq!(codes.len(), table.len());
+    assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(codes.len() < monthly_currencies.len());
 }
 
 #[test]
@@ -264,9 +391,1480 @@ fn errors_render_helpful_messages() {
     let message = err.to_string();
     assert!(message.contains("2013-01"), "{message}");
     assert!(message.contains("available 2014-02"), "{message}");
+    assert!(message.contains("nearest is 2014-02"), "{message}");
 
     let err = rates
         .monthly_rate("XXX", YearMonth::new(2025, 8).unwrap())
         .unwrap_err();
     assert!(err.to_string().contains("'XXX'"));
+    assert!(!err.to_string().contains("did you mean"));
+}
+
+#[test]
+fn unknown_codes_suggest_published_ones() {
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let err = rates.monthly_rate(" euro", aug).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "'euro' is not a three-letter currency code — did you mean 'EUR'?"
+    );
+    assert_eq!(err.code(), "invalid_currency_code");
+    let LookupError::InvalidCurrencyCode { suggestions, .. } =
+        rates.monthly_rate("US", aug).unwrap_err()
+    else {
+        panic!("expected InvalidCurrencyCode")
+    };
+    assert!(
+        suggestions.contains(&"USD".parse().unwrap()),
+        "{suggestions:?}"
+    );
+    assert!(suggestions.len() <= 3);
+}
+
+#[test]
+fn cross_currency_conversion_triangulates_through_gbp() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    // USD 1.3541 and EUR 1.1547 per £1: $100 = €100 * 1.1547 / 1.3541
+    let euros = rates
+        .convert_between(dec!(100), "USD", "EUR", month)
+        .unwrap();
+    assert_eq!(euros, dec!(115.47) / dec!(1.3541));
+    // GBP on either side degenerates to the plain conversions
+    let usd = rates.monthly_rate("USD", month).unwrap();
+    assert_eq!(
+        rates
+            .convert_between(dec!(100), "USD", "GBP", month)
+            .unwrap(),
+        usd.to_gbp(dec!(100))
+    );
+    assert_eq!(
+        rates
+            .convert_between(dec!(100), "GBP", "USD", month)
+            .unwrap(),
+        usd.from_gbp(dec!(100))
+    );
+    // Either leg failing fails the whole conversion
+    assert!(matches!(
+        rates.convert_between(dec!(1), "USD", "XXX", month),
+        Err(ConvertError::Lookup(LookupError::UnknownCurrency { .. }))
+    ));
+}
+
+#[test]
+fn convert_str_parses_amount_then_code() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let usd = rates.monthly_rate("USD", month).unwrap();
+    for input in ["1234.56 USD", "  1234.56\tusd ", "1234.56   Usd"] {
+        assert_eq!(
+            rates.convert_str(input, month).unwrap().amount(),
+            usd.to_gbp(dec!(1234.56)),
+            "{input:?}"
+        );
+    }
+    assert_eq!(
+        rates.convert_str("-5 GBP", month).unwrap(),
+        -Gbp::new(dec!(5))
+    );
+
+    for input in [
+        "",
+        "1234.56",
+        "USD 1234.56",
+        "12,34 USD",
+        "1 USD extra",
+        "1 US",
+    ] {
+        assert!(
+            matches!(
+                rates.convert_str(input, month),
+                Err(ConvertError::InvalidInput { .. })
+            ),
+            "{input:?}"
+        );
+    }
+    // Well-formed input still fails on the lookup
+    assert!(matches!(
+        rates.convert_str("1 XXX", month),
+        Err(ConvertError::Lookup(LookupError::UnknownCurrency { .. }))
+    ));
+}
+
+#[test]
+fn convert_str_with_reads_grouped_and_european_amounts() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let eur = rates.monthly_rate("EUR", month).unwrap();
+    let cases = [
+        ("1.234,56 EUR", NumberFormat::EUROPEAN),
+        ("1234,56 eur", NumberFormat::EUROPEAN),
+        ("1,234.56 EUR", NumberFormat::UK),
+        ("1'234.56 EUR", NumberFormat::SWISS),
+    ];
+    for (input, format) in cases {
+        assert_eq!(
+            rates
+                .convert_str_with(input, month, format)
+                .unwrap()
+                .amount(),
+            eur.to_gbp(dec!(1234.56)),
+            "{input:?}"
+        );
+    }
+    for (input, format) in [
+        ("12.34,56 EUR", NumberFormat::EUROPEAN),
+        ("1,234.56 EUR", NumberFormat::EUROPEAN),
+        ("1,234.56 EUR", NumberFormat::PLAIN),
+        ("1.2.3 EUR", NumberFormat::UK),
+    ] {
+        assert!(
+            matches!(
+                rates.convert_str_with(input, month, format),
+                Err(ConvertError::InvalidInput { .. })
+            ),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn reverse_conversions_round_to_the_target_minor_units() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let table = rates.monthly(month).unwrap();
+    let (usd, jpy, bhd) = (
+        table.rate("USD").unwrap(),
+        table.rate("JPY").unwrap(),
+        table.rate("BHD").unwrap(),
+    );
+    let yen = jpy.from_gbp_rounded(dec!(100), Rounding::HalfEven);
+    assert_eq!(yen, jpy.from_gbp(dec!(100)).round_dp(0));
+    assert_eq!(yen.scale(), 0);
+    assert_eq!(
+        bhd.from_gbp_rounded(dec!(1), Rounding::TowardZero),
+        bhd.units_per_gbp().trunc_with_scale(3)
+    );
+    assert_eq!(usd.from_gbp_rounded(dec!(1), Rounding::HalfUp).scale(), 2);
+    assert_eq!(
+        rates
+            .convert_between_rounded(dec!(100), "usd", "JPY", month, Rounding::HalfUp)
+            .unwrap(),
+        usd.to_currency_rounded(dec!(100), &jpy, Rounding::HalfUp)
+    );
+
+    let money: ForeignMoney = "¥1234.5".parse().unwrap();
+    assert_eq!(money.round(Rounding::HalfEven).to_string(), "¥1234");
+    let money: ForeignMoney = "1.5 KWD".parse().unwrap();
+    assert_eq!(money.round(Rounding::HalfEven).to_string(), "1.500 KWD");
+}
+
+#[test]
+fn foreign_money_parses_displays_and_converts() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let eur: ForeignMoney = "1234.56 eur".parse().unwrap();
+    assert_eq!(eur.amount(), dec!(1234.56));
+    assert_eq!(eur.currency(), "EUR".parse::<Currency>().unwrap());
+    assert_eq!(eur.to_string(), "€1234.56");
+    assert_eq!(format!("{eur:#}"), "1234.56 EUR");
+    assert_eq!(
+        eur.to_gbp(&rates, month).unwrap().amount(),
+        rates
+            .monthly_rate("EUR", month)
+            .unwrap()
+            .to_gbp(dec!(1234.56))
+    );
+
+    for (input, shown) in [
+        ("-€5", "-€5"),
+        ("€-5", "-€5"),
+        ("CN¥10", "CN¥10"),
+        ("¥10", "¥10"),
+        ("A$1.50", "A$1.50"),
+        ("10 CHF", "10 CHF"),
+        ("-10 chf", "-10 CHF"),
+    ] {
+        let money: ForeignMoney = input.parse().unwrap();
+        assert_eq!(money.to_string(), shown, "{input:?}");
+        assert_eq!(money.to_string().parse::<ForeignMoney>().unwrap(), money);
+    }
+    for input in ["", "10", "€", "10 EURO", "10 EUR x", "Fr10"] {
+        assert!(input.parse::<ForeignMoney>().is_err(), "{input:?}");
+    }
+
+    let unknown: ForeignMoney = "1 XXX".parse().unwrap();
+    assert!(matches!(
+        unknown.to_gbp(&rates, month),
+        Err(ConvertError::Lookup(LookupError::UnknownCurrency { .. }))
+    ));
+    let huge = ForeignMoney::new(Decimal::MAX, "EUR".parse().unwrap());
+    assert!(matches!(
+        huge.to_gbp(&rates, month),
+        Err(ConvertError::Arithmetic(_))
+    ));
+}
+
+#[test]
+fn convert_many_matches_one_by_one_conversion() {
+    let rates = Rates::new();
+    let items = [
+        (dec!(100), "USD", date(2025, 8, 1)),
+        (dec!(40), "eur", date(2025, 8, 31)),
+        (dec!(250), "usd", date(2025, 8, 15)),
+        (dec!(7), "USD", date(2013, 1, 1)),
+        (dec!(9), "", date(2025, 8, 2)),
+        (dec!(5), "GBP", date(2035, 1, 1)),
+    ];
+    let batch = rates.convert_many(items);
+    assert_eq!(batch.len(), items.len());
+    for ((amount, code, day), result) in items.into_iter().zip(&batch) {
+        let single = rates
+            .monthly_rate(code, day)
+            .map(|r| Gbp::new(r.to_gbp(amount)))
+            .map_err(ConvertError::from);
+        assert_eq!(&single, result, "{code} on {day}");
+    }
+    assert!(batch[3].is_err() && batch[4].is_err());
+}
+
+#[test]
+fn detailed_conversion_keeps_its_inputs() {
+    let rates = Rates::new();
+    let next = rates.months().next_back().unwrap().next();
+    let usd = rates.monthly_rate_or_earlier("USD", next, 1).unwrap();
+    let conversion = hmrc_rates::Conversion::new(dec!(2500), usd);
+    assert_eq!(conversion.amount(), dec!(2500));
+    assert_eq!(conversion.currency().as_str(), "USD");
+    // The substituted period travels with the result
+    assert_eq!(conversion.period(), usd.period());
+    assert_ne!(conversion.period(), Period::YearMonth(next));
+    assert_eq!(conversion.gbp().amount(), usd.to_gbp(dec!(2500)));
+    assert!(rates.convert_detailed(dec!(1), "USD", next).is_err());
+}
+
+#[test]
+fn lookups_accept_typed_currencies() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    assert_eq!(
+        rates.monthly_rate(usd, month).unwrap(),
+        rates.monthly_rate("usd", month).unwrap()
+    );
+    let table = rates.monthly(month).unwrap();
+    // Every listed currency resolves as itself
+    for currency in table.currencies() {
+        assert_eq!(table.rate(currency).unwrap().currency(), currency);
+    }
+    let owned = String::from("EUR");
+    assert!(table.get(&owned).is_some());
+}
+
+#[test]
+fn insert_rate_applies_an_erratum_and_builds_tables() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    let mut rates = Rates::new();
+    let before = rates.monthly(aug).unwrap().len();
+    rates.insert_rate(aug, usd, dec!(1.3600)).unwrap();
+    assert_eq!(rates.monthly(aug).unwrap().len(), before);
+    assert_eq!(
+        rates.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.36)
+    );
+    assert_eq!(
+        rates.monthly_rate("EUR", aug).unwrap().units_per_gbp(),
+        dec!(1.1547)
+    );
+
+    let built = Rates::empty()
+        .with_rate(aug.next(), usd, dec!(1.5))
+        .unwrap()
+        .with_rate(aug.next(), "EUR".parse().unwrap(), dec!(1.2))
+        .unwrap();
+    assert!(built.months().eq([aug.next()]));
+    assert_eq!(built.monthly(aug.next()).unwrap().len(), 2);
+
+    for rate in [dec!(0), dec!(-1.5)] {
+        assert_eq!(
+            rates.insert_rate(aug, usd, rate),
+            Err(InvalidRate {
+                currency: usd,
+                rate
+            })
+        );
+    }
+    assert!(
+        rates
+            .insert_rate(aug, "GBP".parse().unwrap(), dec!(1))
+            .is_err()
+    );
+}
+
+#[test]
+fn source_chain_layers_a_custom_provider_over_the_bundle() {
+    struct Override;
+
+    impl RateSource for Override {
+        fn units_per_gbp(&self, currency: Currency, year_month: YearMonth) -> Option<Decimal> {
+            (currency.as_str() == "USD" && year_month == YearMonth::new(2025, 8).unwrap())
+                .then_some(dec!(1.25))
+        }
+
+        fn monthly_coverage(&self) -> Option<(YearMonth, YearMonth)> {
+            None
+        }
+    }
+
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let chain = SourceChain::new().with(Override).with(Rates::new());
+    assert_eq!(
+        chain.to_gbp(dec!(100), "usd", aug).unwrap(),
+        Gbp::new(dec!(80))
+    );
+    assert_eq!(
+        chain.rate("EUR", aug).unwrap(),
+        Rates::new().monthly_rate("EUR", aug).unwrap()
+    );
+    assert_eq!(chain.rate("GBP", aug).unwrap().units_per_gbp(), dec!(1));
+    assert_eq!(chain.monthly_coverage(), Rates::new().monthly_coverage());
+    assert!(matches!(
+        chain.rate("XXX", aug),
+        Err(LookupError::UnknownCurrency { .. })
+    ));
+    assert!(matches!(
+        chain.rate("HRK", aug), // the euro replaced it in 2023
+        Err(LookupError::NotInPeriod { .. })
+    ));
+    assert!(matches!(
+        chain.to_gbp(Decimal::MAX, "EUR", aug),
+        Err(ConvertError::Arithmetic(_))
+    ));
+    assert!(matches!(
+        chain.rate("USD", YearMonth::new(2000, 1).unwrap()),
+        Err(LookupError::PeriodNotAvailable {
+            available: Some(_),
+            ..
+        })
+    ));
+    assert!(matches!(
+        SourceChain::new().rate("US", aug),
+        Err(LookupError::InvalidCurrencyCode { .. })
+    ));
+}
+
+#[test]
+fn convert_spot_and_average_use_the_year_end_tables() {
+    let rates = Rates::new();
+    let tax_year = YearEnd::march(2025);
+    let eur = rates.average(tax_year).unwrap().rate("EUR").unwrap();
+    assert_eq!(
+        rates.convert_average(dec!(1000), "eur", tax_year).unwrap(),
+        Gbp::new(eur.to_gbp(dec!(1000)))
+    );
+    assert_eq!(
+        rates.convert_average(dec!(5), "GBP", tax_year).unwrap(),
+        Gbp::new(dec!(5))
+    );
+    assert!(matches!(
+        rates.convert_average(dec!(1), "EUR", YearEnd::march(1999)),
+        Err(ConvertError::Lookup(LookupError::PeriodNotAvailable { .. }))
+    ));
+
+    let year_end = YearEnd::december(2024);
+    let usd = rates.spot(year_end).unwrap().rate("USD").unwrap();
+    assert_ne!(usd, rates.average(year_end).unwrap().rate("USD").unwrap());
+    assert_eq!(
+        rates.convert_spot(dec!(1000), "USD", year_end).unwrap(),
+        Gbp::new(usd.to_gbp(dec!(1000)))
+    );
+}
+
+#[test]
+fn countries_carry_hmrc_names_and_currencies() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let japan = rates.country("jp", month).unwrap();
+    assert_eq!(
+        (japan.code(), japan.name(), japan.currency_name()),
+        ("JP", "Japan", "Yen")
+    );
+    assert_eq!(japan.currency(), "JPY".parse::<Currency>().unwrap());
+    assert_eq!(
+        rates.country_rate("JP", month).unwrap().units_per_gbp(),
+        dec!(197.9633)
+    );
+    assert!(rates.countries(month).len() > 100);
+
+    let eur: Currency = "EUR".parse().unwrap();
+    let early: Vec<&str> = rates
+        .countries_using(eur, YearMonth::new(2014, 2).unwrap())
+        .map(|c| c.name())
+        .collect();
+    assert!(early.len() > 10 && early.contains(&"Austria"), "{early:?}");
+    let now: Vec<&str> = rates
+        .countries_using(eur, month)
+        .map(|c| c.name())
+        .collect();
+    assert_eq!(now, ["Eurozone"]);
+
+    assert!(matches!(
+        rates.country_rate("XX", month),
+        Err(LookupError::UnknownCountry { .. })
+    ));
+}
+
+#[test]
+fn rate_history_follows_the_monthly_tables() {
+    let rates = Rates::new();
+    let history: Vec<(NaiveDate, Decimal)> = rates.rate_history("usd").unwrap().collect();
+    assert_eq!(history.len(), rates.months().count());
+    assert_eq!(history[0].0, date(2014, 2, 1));
+    let owned = rates.rate_history(String::from("USD")).unwrap();
+    assert_eq!(owned.count(), history.len());
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let usd = rates.monthly_rate("USD", aug).unwrap().units_per_gbp();
+    assert!(history.contains(&(date(2025, 8, 1), usd)));
+
+    let year: Vec<NaiveDate> = rates
+        .rate_history_between("USD", date(2025, 1, 31), YearMonth::new(2025, 12).unwrap())
+        .unwrap()
+        .map(|(day, _)| day)
+        .collect();
+    assert_eq!(year.len(), 12);
+    assert_eq!((year[0], year[11]), (date(2025, 1, 1), date(2025, 12, 1)));
+
+    assert!(
+        rates
+            .rate_history("GBP")
+            .unwrap()
+            .all(|(_, r)| r == Decimal::ONE)
+    );
+    assert!(matches!(
+        rates.rate_history("XXX"),
+        Err(LookupError::UnknownCurrency { .. })
+    ));
+}
+
+#[test]
+fn rate_stats_summarise_a_run_of_months() {
+    let rates = Rates::new();
+    let (jul, aug) = (
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    let a = rates.monthly_rate("USD", jul).unwrap().units_per_gbp();
+    let b = rates.monthly_rate("USD", aug).unwrap().units_per_gbp();
+    let stats = rates.rate_stats("USD", aug, jul).unwrap();
+    assert_eq!((stats.from(), stats.to(), stats.months()), (jul, aug, 2));
+    assert_eq!((stats.min(), stats.max()), (a.min(b), a.max(b)));
+    assert_eq!(stats.mean(), (a + b) / dec!(2));
+    assert_eq!(stats.std_dev(), (a - b).abs() / dec!(2));
+
+    let single = rates.rate_stats("usd", aug, aug).unwrap();
+    assert_eq!((single.mean(), single.std_dev()), (b, Decimal::ZERO));
+
+    let next = rates.months().next_back().unwrap().next();
+    assert!(matches!(
+        rates.rate_stats("USD", aug, next),
+        Err(LookupError::PeriodNotAvailable { .. })
+    ));
+}
+
+#[test]
+fn diff_months_lists_added_removed_and_changed() {
+    let (jul, aug) = (
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    let [usd, eur, jpy] = ["USD", "EUR", "JPY"].map(|c| c.parse::<Currency>().unwrap());
+    let rates = Rates::empty()
+        .with_rate(jul, usd, dec!(1.20))
+        .and_then(|r| r.with_rate(jul, eur, dec!(1.15)))
+        .and_then(|r| r.with_rate(aug, usd, dec!(1.23)))
+        .and_then(|r| r.with_rate(aug, jpy, dec!(198)))
+        .unwrap();
+    let diff = rates.diff_months(jul, aug).unwrap();
+    assert_eq!((diff.from(), diff.to()), (jul, aug));
+    assert_eq!(diff.added(), [(jpy, dec!(198))]);
+    assert_eq!(diff.removed(), [(eur, dec!(1.15))]);
+    let [change] = diff.changed() else {
+        panic!("{:?}", diff.changed())
+    };
+    assert_eq!(
+        (change.currency(), change.old_rate(), change.new_rate()),
+        (usd, dec!(1.20), dec!(1.23))
+    );
+    assert_eq!(change.percent_change(), dec!(2.5));
+
+    assert!(Rates::new().diff_months(aug, aug).unwrap().is_empty());
+    assert!(matches!(
+        rates.diff_months(jul, aug.next()),
+        Err(LookupError::PeriodNotAvailable { .. })
+    ));
+}
+
+#[test]
+fn bundled_months_have_no_gaps() {
+    assert!(Rates::new().missing_months().is_empty());
+}
+
+#[test]
+fn missing_periods_name_the_nearest_published_one() {
+    let rates = Rates::new();
+    let nearest = |err: LookupError| match err {
+        LookupError::PeriodNotAvailable { nearest, .. } => nearest,
+        other => panic!("unexpected: {other}"),
+    };
+    let last = rates.months().next_back().unwrap();
+    let err = rates.monthly_rate("USD", YearMonth::new(2035, 1).unwrap());
+    assert_eq!(nearest(err.unwrap_err()), Some(Period::YearMonth(last)));
+
+    let err = rates.spot(YearEnd::march(2010)).unwrap_err();
+    assert_eq!(nearest(err), Some(Period::YearEnd(YearEnd::december(2010))));
+
+    let err = rates.weekly(date(2017, 1, 1)).unwrap_err();
+    let Some(Period::Week { end, .. }) = nearest(err) else {
+        panic!("no nearest week")
+    };
+    assert!(end < date(2016, 6, 1));
+
+    let err = Rates::empty().monthly_rate("USD", last).unwrap_err();
+    assert_eq!(nearest(err), None);
+}
+
+#[test]
+fn aliases_resolve_before_lookup_and_stay_visible() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let cny = Rates::new().monthly_rate("CNY", aug).unwrap();
+    assert!(Rates::new().monthly_rate("RMB", aug).is_err()); // none by default
+
+    let rates = Rates::new().with_aliases(Aliases::common());
+    assert_eq!(rates.monthly_rate(" rmb ", aug).unwrap(), cny);
+    assert_eq!(
+        rates.monthly_rate("UKP", aug).unwrap().units_per_gbp(),
+        Decimal::ONE
+    );
+    assert_eq!(
+        rates.rate_history("Yuan").unwrap().count(),
+        rates.months().count()
+    );
+
+    let mut aliases = Aliases::new().with("dollar", "USD".parse().unwrap());
+    aliases.insert("DOLLAR", "CAD".parse().unwrap());
+    assert_eq!(aliases.iter().len(), 1);
+    assert_eq!(aliases.resolve("Dollar"), "CAD".parse().ok());
+    let rates = rates.with_aliases(aliases);
+    assert!(rates.monthly_rate("RMB", aug).is_err()); // replaced, not merged
+    let source: &dyn RateSource = &rates;
+    assert_eq!(
+        source.rate("dollar", aug).unwrap().currency().as_ref(),
+        "CAD"
+    );
+}
+
+#[test]
+fn redenominations_restate_both_codes_across_the_changeover() {
+    let ym = |y, m| YearMonth::new(y, m).unwrap();
+    let mut rates = Rates::new();
+    assert!(matches!(
+        rates.monthly_rate("BYN", ym(2016, 7)),
+        Err(LookupError::NotInPeriod { .. })
+    ));
+    rates.set_redenominations(Redenomination::hmrc());
+
+    let byn: Vec<Decimal> = rates
+        .rate_history_between("BYN", ym(2016, 7), ym(2017, 1))
+        .unwrap()
+        .map(|(_, rate)| rate)
+        .collect();
+    // HMRC's BYR row: 29028.58 old units, then 2.6455 .. 2.4891 new; then BYN 2.4841
+    assert_eq!(byn.first(), Some(&dec!(2.902858)));
+    assert_eq!(byn[1], dec!(2.6455));
+    assert_eq!(byn.last(), Some(&dec!(2.4841)));
+    assert_eq!(byn.len(), 7);
+    assert_eq!(
+        rates
+            .monthly_rate("BYR", ym(2017, 1))
+            .unwrap()
+            .units_per_gbp(),
+        dec!(24841)
+    );
+
+    // Labels that disagree with their figures: SLE over old units, SLL over new ones
+    let sle = rates.monthly_rate("SLE", ym(2022, 11)).unwrap();
+    assert_eq!(sle.units_per_gbp(), dec!(15.6311792));
+    let sll = rates.monthly_rate("sll", ym(2023, 2)).unwrap();
+    assert_eq!(
+        (sll.currency().as_ref(), sll.units_per_gbp()),
+        ("SLL", dec!(23661.7))
+    );
+
+    let table = rates.monthly(ym(2022, 11)).unwrap();
+    assert!(
+        table
+            .iter()
+            .any(|(c, r)| c.as_ref() == "SLE" && r.units_per_gbp() == dec!(15631.1792))
+    );
+    assert!(
+        Redenomination::new(
+            "USD".parse().unwrap(),
+            "USD".parse().unwrap(),
+            ym(2020, 1),
+            Decimal::ONE
+        )
+        .is_none()
+    );
+    let (old, new) = ("SLL".parse().unwrap(), "SLE".parse().unwrap());
+    for units in [Decimal::ONE, dec!(7.5), dec!(1000.5), dec!(1e13)] {
+        assert!(Redenomination::new(old, new, ym(2023, 2), units).is_none());
+    }
+    assert!(Redenomination::new(old, new, ym(2023, 2), dec!(1000.0)).is_some());
+}
+
+#[test]
+fn latest_rates_are_the_newest_month() {
+    let rates = Rates::new();
+    let last = rates.months().next_back().unwrap();
+    assert_eq!(rates.latest_month(), Some(last));
+    assert_eq!(
+        rates.latest_rates().unwrap().len(),
+        rates.monthly(last).unwrap().len()
+    );
+    assert!(Rates::empty().latest_rates().is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn convert_today_reads_the_injected_clock() {
+    use hmrc_rates::{Clock, FixedClock};
+
+    let rates = Rates::new();
+    let clock = FixedClock(date(2025, 8, 31));
+    assert_eq!(clock.today(), date(2025, 8, 31));
+    assert_eq!(
+        rates.convert_today_with(dec!(100), "USD", clock),
+        rates
+            .convert_detailed(dec!(100), "USD", clock.today())
+            .map(|c| c.gbp())
+    );
+    let future: &dyn Clock = &FixedClock(date(2099, 1, 1));
+    assert!(matches!(
+        rates.convert_today_with(dec!(100), "USD", future),
+        Err(ConvertError::Lookup(LookupError::PeriodNotAvailable { .. }))
+    ));
+    // The system clock gives some month: either published or plainly not
+    assert!(!matches!(
+        rates.convert_today(dec!(100), "USD"),
+        Err(ConvertError::Lookup(LookupError::UnknownCurrency { .. }))
+    ));
+}
+
+#[test]
+fn snapshots_round_trip_every_series() {
+    let rates = Rates::new();
+    let bytes = rates.to_snapshot();
+    let restored = Rates::from_snapshot(&bytes).unwrap();
+    assert_eq!(restored.to_snapshot(), bytes);
+
+    assert!(restored.months().eq(rates.months()));
+    for month in rates.months() {
+        let (a, b) = (
+            rates.monthly(month).unwrap(),
+            restored.monthly(month).unwrap(),
+        );
+        assert!(a.iter().eq(b.iter()), "{month}");
+    }
+    assert!(restored.spot_periods().eq(rates.spot_periods()));
+    assert!(restored.average_periods().eq(rates.average_periods()));
+    assert!(restored.weeks().eq(rates.weeks()));
+    let day = date(2015, 3, 10);
+    let (a, b) = (rates.weekly(day).unwrap(), restored.weekly(day).unwrap());
+    assert!(a.iter().eq(b.iter()));
+
+    let empty = Rates::empty().to_snapshot();
+    assert_eq!(Rates::from_snapshot(&empty).unwrap().months().count(), 0);
+}
+
+#[test]
+fn malformed_snapshots_are_rejected() {
+    use hmrc_rates::SnapshotError;
+
+    let bytes = Rates::new().to_snapshot();
+    assert_eq!(
+        Rates::from_snapshot(b"<?xml").unwrap_err(),
+        SnapshotError::NotASnapshot
+    );
+    let mut future = bytes.clone();
+    future[8] = 99;
+    assert_eq!(
+        Rates::from_snapshot(&future).unwrap_err(),
+        SnapshotError::UnsupportedVersion { version: 99 }
+    );
+    for broken in [&bytes[..bytes.len() - 1], &[&bytes[..], &[0]].concat()[..]] {
+        assert!(matches!(
+            Rates::from_snapshot(broken),
+            Err(SnapshotError::Malformed { .. })
+        ));
+    }
+}
+
+const _: () = {
+    const fn shareable<T: Clone + Send + Sync + 'static>() {}
+    shareable::<Rates>();
+};
+
+#[test]
+fn clones_share_tables_and_configure_independently() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    let rates = Rates::new().with_rate(aug, usd, dec!(1.3600)).unwrap();
+
+    let aliased = rates.clone().with_aliases(Aliases::common());
+    assert_eq!(
+        aliased.monthly_rate("RMB", aug).unwrap(),
+        rates.monthly_rate("CNY", aug).unwrap()
+    );
+    assert!(rates.monthly_rate("RMB", aug).is_err());
+    assert_eq!(
+        aliased.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.3600)
+    );
+
+    let mut edited = rates.clone();
+    edited.insert_rate(aug, usd, dec!(1.3700)).unwrap();
+    assert_eq!(
+        edited.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.3700)
+    );
+    assert_eq!(
+        rates.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.3600)
+    );
+
+    let shared = rates.clone();
+    std::thread::spawn(move || shared.monthly_rate("USD", aug).unwrap())
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn iter_walks_every_monthly_rate_in_order() {
+    let rates = Rates::new();
+    let held: usize = rates
+        .months()
+        .map(|m| rates.monthly(m).unwrap().len())
+        .sum();
+    assert_eq!(rates.iter().count(), held);
+    assert_eq!((&rates).into_iter().count(), held);
+
+    let all: Vec<_> = rates.iter().collect();
+    assert!(all.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    let usd: Vec<_> = all
+        .iter()
+        .filter(|(_, currency, _)| currency.as_str() == "USD")
+        .map(|&(first, _, rate)| (first, rate))
+        .collect();
+    assert_eq!(usd, rates.rate_history("USD").unwrap().collect::<Vec<_>>());
+
+    let loaded = Rates::empty()
+        .with_rate(
+            YearMonth::new(2025, 8).unwrap(),
+            "USD".parse().unwrap(),
+            dec!(1.36),
+        )
+        .unwrap();
+    let rows: Vec<_> = loaded.iter().collect();
+    assert_eq!(
+        rows,
+        [(date(2025, 8, 1), "USD".parse().unwrap(), dec!(1.36))]
+    );
+}
+
+#[test]
+fn format_month_aligns_ascii_and_markdown_tables() {
+    use hmrc_rates::TableFormat;
+
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let ascii = rates.format_month(aug, TableFormat::Ascii).unwrap();
+    let lines: Vec<&str> = ascii.lines().collect();
+    assert!(lines[0].starts_with("+-") && lines[2] == lines[0]);
+    assert!(lines[1].starts_with("| Currency | Country"));
+    assert_eq!(lines.last(), lines.first());
+    let width = lines[0].chars().count();
+    assert!(lines.iter().all(|line| line.chars().count() == width));
+    let usd = rates.monthly_rate("USD", aug).unwrap().units_per_gbp();
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("| USA ") && line.ends_with(&format!(" {usd} |")))
+    );
+
+    let markdown = rates.format_month(aug, TableFormat::Markdown).unwrap();
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert!(lines[1].starts_with("| ---") && lines[1].ends_with(": |"));
+    assert_eq!(lines.len(), ascii.lines().count() - 2);
+
+    let bare = Rates::empty()
+        .with_rate(aug, "USD".parse().unwrap(), dec!(1.36))
+        .unwrap();
+    assert!(
+        bare.format_month(aug, TableFormat::Ascii)
+            .unwrap()
+            .contains("| USD      |         | 1.36 |")
+    );
+    assert!(bare.format_month(aug.next(), TableFormat::Ascii).is_err());
+}
+
+#[test]
+fn observer_sees_every_gbp_conversion() {
+    use std::sync::{Arc, Mutex};
+
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let rates = Rates::new().with_observer({
+        let log = Arc::clone(&log);
+        move |conversion: &hmrc_rates::Conversion| log.lock().unwrap().push(*conversion)
+    });
+
+    let detailed = rates.convert_detailed(dec!(100), "USD", aug).unwrap();
+    let text = rates.convert_str("50 EUR", aug).unwrap();
+    let average = rates
+        .convert_average(dec!(10), "JPY", YearEnd::march(2025))
+        .unwrap();
+    let money = ForeignMoney::new(dec!(20), "CHF".parse().unwrap())
+        .to_gbp(&rates, aug)
+        .unwrap();
+    rates.convert_many([(dec!(1), "USD", aug), (dec!(2), "XXX", aug)]);
+    rates.convert_between(dec!(3), "USD", "EUR", aug).unwrap();
+
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), 6);
+    assert_eq!(log[0], detailed);
+    assert_eq!((log[1].gbp(), log[1].amount()), (text, dec!(50)));
+    assert_eq!(log[2].period(), Period::YearEnd(YearEnd::march(2025)));
+    assert_eq!(log[2].gbp(), average);
+    assert_eq!((log[3].gbp(), log[3].currency().as_str()), (money, "CHF"));
+    assert_eq!(log[4].amount(), dec!(1));
+    assert_eq!(
+        (log[5].amount(), log[5].currency().as_str()),
+        (dec!(3), "USD")
+    );
+
+    let mut quiet = rates.clone();
+    quiet.clear_observer();
+    quiet.convert_detailed(dec!(100), "USD", aug).unwrap();
+    assert_eq!(log.len(), 6);
+}
+
+#[test]
+fn builder_layers_data_then_applies_settings() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    let seen = Arc::new(AtomicUsize::new(0));
+    let rates = Rates::builder()
+        .lookup_policy(LookupPolicy::Earlier(2))
+        .aliases(Aliases::new().with("dollar", usd))
+        .observer({
+            let seen = Arc::clone(&seen);
+            move |_| {
+                seen.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+        .empty()
+        .data(Rates::empty().with_rate(aug, usd, dec!(1.36)).unwrap())
+        .build();
+
+    assert_eq!(rates.months().collect::<Vec<_>>(), [aug]);
+    assert_eq!(rates.lookup_policy(), LookupPolicy::Earlier(2));
+    let rate = rates.monthly_rate("Dollar", aug.next().next()).unwrap();
+    assert_eq!(rate.period(), Period::YearMonth(aug));
+    assert!(rates.monthly_rate("USD", aug.next().next().next()).is_err());
+    rates.convert_detailed(dec!(1), "USD", aug).unwrap();
+    assert_eq!(seen.load(Ordering::Relaxed), 1);
+
+    let default = Rates::builder().build();
+    assert_eq!(default.lookup_policy(), LookupPolicy::Exact);
+    assert_eq!(default.months().count(), Rates::new().months().count());
+}
+
+#[test]
+fn interpolated_rates_run_straight_between_months() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let rates = Rates::empty()
+        .with_rate(aug, "USD".parse().unwrap(), dec!(1.30))
+        .unwrap()
+        .with_rate(aug.next(), "USD".parse().unwrap(), dec!(1.61))
+        .unwrap();
+    let on = |d| rates.interpolated_rate("USD", date(2025, 8, d));
+
+    assert_eq!(on(1).unwrap(), dec!(1.30));
+    assert_eq!(on(11).unwrap(), dec!(1.40)); // 10 of August's 31 days
+    assert_eq!(on(31).unwrap(), dec!(1.60));
+    assert_eq!(
+        rates.interpolated_rate("USD", date(2025, 9, 1)).unwrap(),
+        dec!(1.61)
+    );
+    assert!(rates.interpolated_rate("USD", date(2025, 9, 2)).is_err()); // October unpublished
+    assert!(rates.interpolated_rate("USD", date(2025, 7, 31)).is_err());
+}
+
+#[test]
+fn percent_change_and_rolling_volatility_follow_the_monthly_series() {
+    let jan = YearMonth::new(2025, 1).unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    let rates = [dec!(1.20), dec!(1.26), dec!(1.197), dec!(1.197)]
+        .into_iter()
+        .enumerate()
+        .fold(Rates::empty(), |rates, (i, rate)| {
+            let month = (0..i).fold(jan, |m, _| m.next());
+            rates.with_rate(month, usd, rate).unwrap()
+        });
+    let apr = jan.next().next().next();
+
+    assert_eq!(
+        rates.percent_change("USD", jan, jan.next()).unwrap(),
+        dec!(5)
+    );
+    assert_eq!(
+        rates
+            .percent_change("USD", jan.next(), jan.next().next())
+            .unwrap(),
+        dec!(-5)
+    );
+    assert_eq!(
+        rates.percent_change("USD", jan, jan).unwrap(),
+        Decimal::ZERO
+    );
+    assert!(rates.percent_change("USD", jan, apr.next()).is_err());
+
+    // Changes: +5, -5, 0
+    let volatility = rates.rolling_volatility("USD", jan, apr, 2).unwrap();
+    assert_eq!(volatility, [(jan.next().next(), dec!(5)), (apr, dec!(2.5))]);
+    assert!(
+        rates
+            .rolling_volatility("USD", jan, apr, 4)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        rates
+            .rolling_volatility("USD", jan, apr, 0)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(rates.rolling_volatility("USD", jan, apr.next(), 2).is_err());
+}
+
+#[test]
+fn search_ranks_codes_names_and_countries() {
+    let rates = Rates::new();
+    let first = |query| rates.search(query).first().map(|c| c.as_str().to_owned());
+
+    assert_eq!(first("usd").as_deref(), Some("USD"));
+    assert_eq!(first(" Yen ").as_deref(), Some("JPY"));
+    assert_eq!(first("euro").as_deref(), Some("EUR"));
+    assert_eq!(first("jp").as_deref(), Some("JPY"));
+    assert_eq!(first("abu dhabi").as_deref(), Some("AED"));
+    // "dollar" is many currencies' name: every one is offered
+    let dollars = rates.search("dollar");
+    for code in ["AUD", "CAD", "USD"] {
+        assert!(dollars.iter().any(|c| c.as_str() == code));
+    }
+    assert!(rates.search("eyr").iter().any(|c| c.as_str() == "EUR"));
+    assert!(rates.search("zzzzzz").is_empty());
+
+    let aliased = Rates::new().with_aliases(Aliases::common());
+    assert_eq!(aliased.search("rmb")[0].as_str(), "CNY");
+}
+
+#[test]
+fn rates_between_yields_each_published_months_table() {
+    let rates = Rates::new();
+    let jan = YearMonth::new(2025, 1).unwrap();
+    let jun = YearMonth::new(2025, 6).unwrap();
+
+    let months: Vec<_> = rates.rates_between(jan, jun).collect();
+    assert_eq!(months.len(), 6);
+    for (month, table) in &months {
+        assert_eq!(table.period(), Period::YearMonth(*month));
+        assert_eq!(
+            table.rate("USD").unwrap(),
+            rates.monthly_rate("USD", *month).unwrap()
+        );
+    }
+    assert!(
+        months
+            .iter()
+            .map(|(month, _)| *month)
+            .eq(rates.months().filter(|month| (jan..=jun).contains(month)))
+    );
+    assert_eq!(rates.rates_between(jun, jan).count(), 0);
+    assert_eq!(rates.rates_between(jun, jun).next_back().unwrap().0, jun);
+    // Nothing past the newest month
+    let last = rates.latest_month().unwrap();
+    assert_eq!(rates.rates_between(last, last.next().next()).count(), 1);
+}
+
+#[test]
+fn summarize_totals_by_currency_and_month_with_rates_used() {
+    let rates = Rates::new();
+    let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    let (jul, aug) = (
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    let gbp = |amount, code, month| rates.convert_detailed(amount, code, month).unwrap().gbp();
+
+    let summary = rates
+        .summarize([
+            (dec!(120), "USD", day(7, 3)),
+            (dec!(80), "usd", day(7, 28)),
+            (dec!(5000), "JPY", day(8, 14)),
+            (dec!(10), "GBP", day(8, 1)),
+        ])
+        .unwrap();
+    let usd = gbp(dec!(200), "USD", jul);
+    let jpy = gbp(dec!(5000), "JPY", aug);
+    let sterling = Gbp::new(dec!(10));
+    assert_eq!(summary.items(), 4);
+    assert_eq!(summary.total(), usd + jpy + sterling);
+    let by_currency: Vec<_> = summary
+        .by_currency()
+        .iter()
+        .map(|(c, gbp)| (c.as_str(), *gbp))
+        .collect();
+    assert_eq!(by_currency, [("GBP", sterling), ("JPY", jpy), ("USD", usd)]);
+    assert_eq!(summary.by_month(), [(jul, usd), (aug, jpy + sterling)]);
+    let used: Vec<_> = summary
+        .rates()
+        .iter()
+        .map(|(month, rate)| (*month, rate.currency().as_str().to_owned()))
+        .collect();
+    assert_eq!(
+        used,
+        [
+            (jul, "USD".into()),
+            (aug, "GBP".into()),
+            (aug, "JPY".into())
+        ]
+    );
+
+    assert!(matches!(
+        rates.summarize([(dec!(1), "USD", day(7, 1)), (dec!(1), "XXX", day(7, 1))]),
+        Err(ConvertError::Lookup(LookupError::UnknownCurrency { .. }))
+    ));
+    let empty = rates.summarize::<YearMonth>([]).unwrap();
+    assert_eq!((empty.total(), empty.items()), (Gbp::ZERO, 0));
+}
+
+#[test]
+fn provenance_follows_each_month_through_edits_merges_and_snapshots() {
+    use hmrc_rates::{MergePolicy, Source};
+
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let bundled = rates.provenance(aug).unwrap();
+    assert_eq!(
+        *bundled.source(),
+        Source::Bundled {
+            file: "monthly/2025-08.xml".into()
+        }
+    );
+    assert_eq!(bundled.loaded_at(), None);
+    assert!(rates.provenance(YearMonth::new(2100, 1).unwrap()).is_none());
+
+    let edited = rates
+        .clone()
+        .with_rate(aug, "USD".parse().unwrap(), dec!(1.5))
+        .unwrap();
+    let manual = edited.provenance(aug).unwrap();
+    assert_eq!(*manual.source(), Source::Manual);
+    assert!(manual.loaded_at().is_some());
+    assert_eq!(rates.provenance(aug), Some(bundled.clone()));
+
+    let mut merged = Rates::empty();
+    merged.merge(&edited, MergePolicy::Overwrite).unwrap();
+    assert_eq!(merged.provenance(aug), Some(manual));
+    assert_eq!(
+        merged.provenance(aug.prev()).map(|p| p.source().clone()),
+        edited.provenance(aug.prev()).map(|p| p.source().clone())
+    );
+
+    let restored = Rates::from_snapshot(&edited.to_snapshot()).unwrap();
+    assert_eq!(
+        *restored.provenance(aug).unwrap().source(),
+        Source::Restored
+    );
+}
+
+#[test]
+fn convert_from_gbp_all_prices_every_currency_of_the_month() {
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let prices = rates.convert_from_gbp_all(Gbp::new(dec!(10)), aug).unwrap();
+    let table = rates.monthly(aug).unwrap();
+    assert_eq!(prices.len(), table.len());
+    assert!(prices.keys().copied().eq(table.currencies()));
+    assert_eq!(prices[&"USD".parse::<Currency>().unwrap()], dec!(13.541));
+
+    let next = rates.latest_month().unwrap().next();
+    assert!(matches!(
+        rates.convert_from_gbp_all(Gbp::new(dec!(10)), next),
+        Err(ConvertError::Lookup(LookupError::PeriodNotAvailable { .. }))
+    ));
+    assert!(matches!(
+        rates.convert_from_gbp_all(Gbp::new(Decimal::MAX), aug),
+        Err(ConvertError::Arithmetic(ArithmeticError::Overflow { .. }))
+    ));
+    let fallback = rates.clone().with_lookup_policy(LookupPolicy::Earlier(1));
+    assert_eq!(
+        fallback
+            .convert_from_gbp_all(Gbp::new(dec!(10)), next)
+            .unwrap()
+            .len(),
+        rates.monthly(next.prev()).unwrap().len()
+    );
+}
+
+#[test]
+fn periods_know_their_dates() {
+    let rates = Rates::new();
+    let leap = rates
+        .monthly_rate("USD", YearMonth::new(2024, 2).unwrap())
+        .unwrap()
+        .period();
+    assert!(leap.contains(date(2024, 2, 29)));
+    assert!(!leap.contains(date(2024, 3, 1)) && !leap.contains(date(2024, 1, 31)));
+    assert_eq!((leap.year(), leap.month()), (2024, 2));
+
+    let march = rates.spot(YearEnd::march(2024)).unwrap().period();
+    assert!(march.contains(date(2023, 4, 1)) && march.contains(date(2024, 3, 31)));
+    assert!(!march.contains(date(2024, 4, 1)));
+    assert_eq!((march.year(), march.month()), (2024, 3));
+
+    let week = rates.weekly(date(2014, 1, 10)).unwrap().period();
+    assert!(week.contains(date(2014, 1, 8)) && week.contains(date(2014, 1, 14)));
+    assert!(!week.contains(date(2014, 1, 15)));
+    assert_eq!((week.year(), week.month()), (2014, 1));
+}
+
+#[test]
+fn negative_amounts_convert_symmetrically_unless_rejected() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let rates = Rates::new();
+    assert_eq!(rates.negative_amounts(), NegativeAmounts::Allow);
+    let refund = rates
+        .convert_detailed(dec!(-100), "USD", aug)
+        .unwrap()
+        .gbp();
+    assert_eq!(
+        refund,
+        -rates.convert_detailed(dec!(100), "USD", aug).unwrap().gbp()
+    );
+    for rounding in [
+        Rounding::HalfEven,
+        Rounding::HalfUp,
+        Rounding::TowardZero,
+        Rounding::AwayFromZero,
+    ] {
+        let credit = rates
+            .convert_between_rounded(dec!(-123.45), "USD", "JPY", aug, rounding)
+            .unwrap();
+        let debit = rates
+            .convert_between_rounded(dec!(123.45), "USD", "JPY", aug, rounding)
+            .unwrap();
+        assert_eq!(credit, -debit);
+    }
+
+    let strict = Rates::builder()
+        .negative_amounts(NegativeAmounts::Reject)
+        .build();
+    let err = strict.convert_str("-1.50 USD", aug).unwrap_err();
+    assert_eq!(err.code(), "negative_amount");
+    assert!(matches!(
+        strict.convert_many([(dec!(-1), "USD", aug), (dec!(-0), "USD", aug)])[..],
+        [Err(ConvertError::NegativeAmount { .. }), Ok(_)]
+    ));
+    assert!(strict.convert_between(dec!(-1), "USD", "EUR", aug).is_err());
+    assert!(
+        strict
+            .summarize([(dec!(5), "USD", aug), (dec!(-5), "USD", aug)])
+            .is_err()
+    );
+    assert_eq!(
+        strict.convert_detailed(dec!(0), "USD", aug).unwrap().gbp(),
+        Gbp::ZERO
+    );
+}
+
+#[test]
+fn checked_conversions_fail_instead_of_saturating() {
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let kwd = rates.monthly_rate("KWD", aug).unwrap();
+    assert!(kwd.units_per_gbp() < Decimal::ONE);
+    assert!(matches!(
+        kwd.checked_to_gbp(Decimal::MAX),
+        Err(ArithmeticError::Overflow { .. })
+    ));
+    let usd = rates.monthly_rate("USD", aug).unwrap();
+    assert!(matches!(
+        usd.checked_from_gbp(Decimal::MAX),
+        Err(ArithmeticError::Overflow { .. })
+    ));
+    let huge = Decimal::from_i128_with_scale(10_i128.pow(24), 0) + dec!(0.37);
+    let err = usd.checked_to_gbp(huge).unwrap_err();
+    assert_eq!(err.code(), "precision_loss");
+    assert!(matches!(
+        usd.checked_to_currency(huge, &kwd),
+        Err(ArithmeticError::PrecisionLoss { amount, .. }) if amount == huge
+    ));
+    assert_eq!(
+        rates.checked_convert(huge, "USD", aug).unwrap_err().code(),
+        "precision_loss"
+    );
+    assert!(matches!(
+        rates.checked_convert(Decimal::MAX, "KWD", aug),
+        Err(ConvertError::Arithmetic(ArithmeticError::Overflow { .. }))
+    ));
+    assert!(matches!(
+        rates.convert_str(&format!("{} KWD", Decimal::MAX), aug),
+        Err(ConvertError::Arithmetic(ArithmeticError::Overflow { .. }))
+    ));
+    // Every conversion on Rates is checked, not only checked_convert
+    assert!(matches!(
+        rates.convert_detailed(huge, "USD", aug),
+        Err(ConvertError::Arithmetic(_))
+    ));
+    assert!(matches!(
+        rates.convert_many([(Decimal::MAX, "KWD", aug)])[..],
+        [Err(ConvertError::Arithmetic(_))]
+    ));
+    assert!(matches!(
+        rates.convert_between(Decimal::MAX, "USD", "KWD", aug),
+        Err(ConvertError::Arithmetic(_))
+    ));
+    assert_eq!(
+        rates.checked_convert(dec!(100), "USD", aug),
+        Ok(rates.convert_detailed(dec!(100), "USD", aug).unwrap().gbp())
+    );
+    // Exact results keep their small scale
+    assert_eq!(
+        Rates::empty()
+            .with_rate(aug, "USD".parse().unwrap(), dec!(1.25))
+            .unwrap()
+            .checked_convert(dec!(100), "USD", aug),
+        Ok(Gbp::new(dec!(80)))
+    );
+}
+
+#[test]
+fn round_trips_stay_within_the_documented_drift() {
+    let rates = Rates::new();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let amounts = [
+        dec!(0.01),
+        dec!(1),
+        dec!(99.99),
+        dec!(12345.678),
+        dec!(987654321.05),
+        dec!(1234567890123.45),
+    ];
+    let roundings = [
+        Rounding::HalfEven,
+        Rounding::HalfUp,
+        Rounding::TowardZero,
+        Rounding::AwayFromZero,
+    ];
+    for (_, rate) in rates.monthly(aug).unwrap().iter() {
+        for amount in amounts {
+            let gbp = rate.checked_to_gbp(amount).unwrap();
+            for rounding in roundings {
+                let pence = Gbp::new(gbp).round(2, rounding).amount();
+                let back = rate.checked_from_gbp(pence).unwrap();
+                assert!(
+                    (back - amount).abs() <= rate.round_trip_drift(2, rounding),
+                    "{amount} {} via {pence}",
+                    rate.currency()
+                );
+            }
+            let exact = rate.from_gbp(gbp);
+            assert!((exact - amount).abs() <= rate.round_trip_drift(28, Rounding::HalfEven));
+        }
+    }
+}
+
+#[test]
+fn deltas_carry_only_recent_periods() {
+    use hmrc_rates::{SnapshotError, Source};
+
+    let newest = Rates::new().months().next_back().unwrap();
+    let usd: Currency = "USD".parse().unwrap();
+    let upstream = Rates::new()
+        .with_rate(newest, usd, dec!(9.99))
+        .unwrap()
+        .with_rate(newest.next(), usd, dec!(1.5))
+        .unwrap();
+    let delta = upstream.export_delta_since(newest);
+    assert!(delta.len() * 50 < upstream.to_snapshot().len());
+
+    let mut replica = Rates::new();
+    let before = replica.monthly(newest.prev()).unwrap().len();
+    assert_eq!(
+        replica.apply_delta(&delta).unwrap(),
+        [newest, newest.next()]
+    );
+    assert_eq!(
+        replica.monthly_rate("USD", newest).unwrap().units_per_gbp(),
+        dec!(9.99)
+    );
+    assert_eq!(
+        replica
+            .monthly_rate("USD", newest.next())
+            .unwrap()
+            .units_per_gbp(),
+        dec!(1.5)
+    );
+    assert_eq!(replica.monthly(newest.prev()).unwrap().len(), before);
+    assert_eq!(
+        *replica.provenance(newest).unwrap().source(),
+        Source::Restored
+    );
+    assert_eq!(replica.export_delta_since(newest), delta);
+
+    let mut untouched = Rates::new();
+    assert_eq!(
+        untouched.apply_delta(&delta[..delta.len() - 1]),
+        Err(SnapshotError::Malformed {
+            reason: "truncated"
+        })
+    );
+    assert_eq!(
+        untouched.apply_delta(&Rates::new().to_snapshot()),
+        Err(SnapshotError::NotASnapshot)
+    );
+    assert!(untouched.monthly(newest.next()).is_err());
+}
+
+#[test]
+fn money_formats_lay_out_foreign_amounts_per_market() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let eur: Currency = "EUR".parse().unwrap();
+    let rate = Rates::new().monthly_rate("EUR", aug).unwrap();
+    let price = ForeignMoney::new(rate.from_gbp(dec!(1000)), eur);
+    let fr = price
+        .format_with(MoneyFormat::FRENCH.minor_units(Rounding::HalfEven))
+        .to_string();
+    let (amount, symbol) = fr.split_once('\u{a0}').unwrap();
+    assert_eq!(symbol, "€");
+    assert_eq!(
+        NumberFormat::FRENCH.parse(amount),
+        Some(price.round(Rounding::HalfEven).amount())
+    );
+
+    let yen = ForeignMoney::new(dec!(-1234567.5), "JPY".parse().unwrap());
+    let uk = MoneyFormat::UK.minor_units(Rounding::HalfUp);
+    assert_eq!(yen.format_with(uk).to_string(), "-¥1,234,568");
+    let dirham = ForeignMoney::new(dec!(1234.5), "AED".parse().unwrap());
+    assert_eq!(
+        dirham.format_with(MoneyFormat::UK).to_string(),
+        "AED\u{a0}1,234.5"
+    );
+    assert_eq!(
+        dirham.format_with(MoneyFormat::EUROPEAN).to_string(),
+        "1.234,5\u{a0}AED"
+    );
+    let plain = MoneyFormat::new(NumberFormat::PLAIN)
+        .with_code()
+        .symbol_after();
+    assert_eq!(
+        price.format_with(plain).to_string(),
+        format!("{price:#}").replace(' ', "\u{a0}")
+    );
+}
+
+#[test]
+fn money_formats_resolve_from_locales() {
+    let swiss = MoneyFormat::new(NumberFormat::SWISS).with_code();
+    for (locale, format) in [
+        ("en-GB", Some(MoneyFormat::UK)),
+        ("EN_us", Some(MoneyFormat::UK)),
+        ("fr_FR.UTF-8", Some(MoneyFormat::FRENCH)),
+        ("fr-CA", Some(MoneyFormat::FRENCH)),
+        ("de", Some(MoneyFormat::EUROPEAN)),
+        ("de_CH@euro", Some(swiss)),
+        ("it-Latn-CH", Some(swiss)),
+        ("es-ES", Some(MoneyFormat::EUROPEAN)),
+        ("es-MX", Some(MoneyFormat::UK)),
+        ("es-419", None),
+        ("en-ZA", None),
+        ("en-IN", None),
+        ("de-AT", None),
+        ("fr-CH", None),
+        ("pt-BR", None),
+        ("C", None),
+        ("", None),
+    ] {
+        assert_eq!(MoneyFormat::for_locale(locale), format, "{locale}");
+    }
+}
+
+#[test]
+fn rates_for_resolves_the_month_by_policy() {
+    let rates = Rates::new();
+    let date = NaiveDate::from_ymd_opt(2025, 8, 15).unwrap();
+    let table = rates.rates_for(date).unwrap();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(table.period(), Period::YearMonth(aug));
+    assert_eq!(table.len(), rates.monthly(aug).unwrap().len());
+
+    let later = rates.latest_month().unwrap().next().next();
+    assert!(matches!(
+        rates.rates_for(later),
+        Err(LookupError::PeriodNotAvailable { .. })
+    ));
+    let nearest = rates.with_lookup_policy(LookupPolicy::LatestWithin(2));
+    let table = nearest.rates_for(later).unwrap();
+    assert_eq!(
+        table.period(),
+        Period::YearMonth(nearest.latest_month().unwrap())
+    );
+    let (currency, rate) = table.iter().next().unwrap();
+    assert_eq!(
+        nearest.monthly_rate(currency.as_str(), later).unwrap(),
+        rate
+    );
+}
+
+#[test]
+fn convert_at_takes_the_date_in_the_timestamps_zone() {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Europe::London;
+
+    let rates = Rates::new();
+    let rate = |month| rates.monthly_rate("USD", YearMonth::new(2025, month).unwrap());
+    let amount = Decimal::from(250);
+    let at = |utc: chrono::DateTime<Utc>| {
+        rates
+            .convert_at(amount, "USD", utc.with_timezone(&London))
+            .unwrap()
+            .amount()
+    };
+    // British Summer Time: 23:30 UTC on 31 July is August in London
+    let summer = Utc.with_ymd_and_hms(2025, 7, 31, 23, 30, 0).unwrap();
+    assert_eq!(at(summer), rate(8).unwrap().to_gbp(amount));
+    assert_eq!(
+        rates.convert_at(amount, "USD", summer).unwrap().amount(),
+        rate(7).unwrap().to_gbp(amount)
+    );
+    // Winter: London is on UTC, so 23:30 UTC on 31 January stays January
+    let winter = Utc.with_ymd_and_hms(2025, 1, 31, 23, 30, 0).unwrap();
+    assert_eq!(at(winter), rate(1).unwrap().to_gbp(amount));
 }
@@ -0,0 +1,49 @@
+//! `Gbp` as a `NUMERIC` column for sqlx and diesel.
+#![cfg(any(feature = "sqlx", feature = "diesel"))]
+#![allow(clippy::unwrap_used)]
+
+use hmrc_rates::Gbp;
+use rust_decimal_macros::dec;
+
+#[cfg(feature = "sqlx")]
+#[test]
+fn sqlx_binds_gbp_as_numeric() {
+    use rust_decimal::Decimal;
+    use sqlx::postgres::{PgArguments, Postgres};
+    use sqlx::{Arguments, Decode, Type};
+
+    fn decodes<T: for<'r> Decode<'r, Postgres>>() {}
+    decodes::<Gbp>();
+
+    assert_eq!(
+        <Gbp as Type<Postgres>>::type_info(),
+        <Decimal as Type<Postgres>>::type_info()
+    );
+    let mut arguments = PgArguments::default();
+    arguments.add(Gbp::new(dec!(12.50))).unwrap();
+    assert_eq!(arguments.len(), 1);
+    let _query = sqlx::query("INSERT INTO fees (gbp) VALUES ($1)").bind(Gbp::new(dec!(12.50)));
+}
+
+#[cfg(feature = "diesel")]
+#[test]
+fn diesel_binds_gbp_as_numeric() {
+    use diesel::deserialize::FromSql;
+    use diesel::dsl::select;
+    use diesel::expression::IntoSql;
+    use diesel::pg::Pg;
+    use diesel::sql_types::{Nullable, Numeric};
+
+    fn loads<T: FromSql<Numeric, Pg>>() {}
+    loads::<Gbp>();
+
+    let query = select(Gbp::new(dec!(12.50)).into_sql::<Numeric>());
+    let sql = diesel::debug_query::<Pg, _>(&query).to_string();
+    assert_eq!(sql, "SELECT $1 -- binds: [Gbp(12.50)]");
+    let query = select(Some(Gbp::new(dec!(1))).into_sql::<Nullable<Numeric>>());
+    assert!(
+        diesel::debug_query::<Pg, _>(&query)
+            .to_string()
+            .contains("Some(Gbp(1))")
+    );
+}
@@ -1,9 +1,9 @@
 //! Updater behavior against a mock Trade Tariff endpoint.
 #![cfg(feature = "http")]
-#![allow(clippy::unwrap_used)]
+#![allow(clippy::unwrap_used, clippy::panic)]
 
 use chrono::Utc;
-use hmrc_rates::{Rates, Updater, YearMonth};
+use hmrc_rates::{AutoRefresher, FetchError, Metrics, Rates, Updater, YearMonth};
 use httpmock::prelude::*;
 use rust_decimal_macros::dec;
 
@@ -211,6 +211,359 @@ fn cached_works_fully_offline() {
     );
 }
 
+#[test]
+fn fetch_month_merges_one_month_into_existing_rates() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{month}.xml"));
+        then.status(200).body(monthly_xml(month, "4.4444"));
+    });
+    mock_all_missing(&server);
+
+    let updater = updater(&server, &cache);
+    let mut rates = Rates::new();
+    assert!(updater.fetch_month(&mut rates, month).unwrap());
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(4.4444)
+    );
+    assert_eq!(
+        *rates.provenance(month).unwrap().source(),
+        hmrc_rates::Source::Url {
+            url: format!("{}/monthly_xml_{month}.xml", server.base_url())
+        }
+    );
+    // A past month is immutable: the cached copy serves every later call
+    assert!(updater.fetch_month(&mut Rates::new(), month).unwrap());
+    mock.assert_hits(1);
+
+    assert!(!updater.fetch_month(&mut rates, next_month()).unwrap());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test(flavor = "current_thread")]
+async fn fetch_month_async_merges_off_the_runtime() {
+    let server = MockServer::start_async().await;
+    let cache = tempfile::tempdir().unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path(format!("/monthly_xml_{month}.xml"));
+            then.status(200).body(monthly_xml(month, "4.4444"));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.any_request();
+            then.status(404);
+        })
+        .await;
+
+    let updater = updater(&server, &cache);
+    let mut rates = Rates::new();
+    assert!(updater.fetch_month_async(&mut rates, month).await.unwrap());
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(4.4444)
+    );
+    assert!(updater.fetch_month_async(&mut rates, month).await.unwrap());
+    mock.assert_hits_async(1).await;
+    assert!(
+        !updater
+            .fetch_month_async(&mut rates, next_month())
+            .await
+            .unwrap()
+    );
+}
+
+#[test]
+fn fetch_range_merges_published_months_and_reports_progress() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let (jun, jul, aug) = (
+        YearMonth::new(2025, 6).unwrap(),
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    for (month, rate) in [(jun, "1.1111"), (aug, "3.3333")] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/monthly_xml_{month}.xml"));
+            then.status(200).body(monthly_xml(month, rate));
+        });
+    }
+    mock_all_missing(&server);
+
+    let mut rates = Rates::empty();
+    let mut seen = Vec::new();
+    let fetched = updater(&server, &cache)
+        .fetch_range_with(&mut rates, jun, aug, |month, done, total| {
+            seen.push((month, done, total));
+        })
+        .unwrap();
+    assert_eq!(fetched, [jun, aug]);
+    assert!(rates.months().eq([jun, aug]));
+    assert_eq!(seen, [(jun, 1, 3), (jul, 2, 3), (aug, 3, 3)]);
+
+    let untouched = MockServer::start();
+    let any = untouched.mock(|when, then| {
+        when.any_request();
+        then.status(404);
+    });
+    let far = YearMonth::new(9999, 12).unwrap();
+    let ancient = YearMonth::new(1900, 1).unwrap();
+    for (from, to) in [(aug, jun), (aug, far), (ancient, aug)] {
+        assert!(matches!(
+            updater(&untouched, &cache).fetch_range(&mut rates, from, to),
+            Err(FetchError::BadRange { .. })
+        ));
+    }
+    any.assert_hits(0);
+}
+
+#[test]
+fn server_errors_are_retried_with_backoff_but_404s_are_not() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let failing = server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{month}.xml"));
+        then.status(503);
+    });
+    let rejected = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/monthly_xml_{}.xml", month.next()));
+        then.status(400);
+    });
+    let missing = server.mock(|when, then| {
+        when.any_request();
+        then.status(404);
+    });
+
+    let updater = updater(&server, &cache).with_retries(2, std::time::Duration::from_millis(1));
+    let err = updater.fetch_month(&mut Rates::new(), month).unwrap_err();
+    assert!(matches!(err, FetchError::Http(_)), "{err}");
+    failing.assert_hits(3);
+
+    assert!(
+        !updater
+            .fetch_month(&mut Rates::new(), month.prev())
+            .unwrap()
+    );
+    missing.assert_hits(1);
+
+    let err = updater
+        .fetch_month(&mut Rates::new(), month.next())
+        .unwrap_err();
+    assert!(matches!(err, FetchError::Http(_)), "{err}");
+    rejected.assert_hits(1);
+}
+
+#[test]
+fn checksums_gate_downloads_and_cache_files() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let name = format!("monthly_xml_{month}.xml");
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(format!("/{name}"));
+        then.status(200).body(monthly_xml(month, "4.4444"));
+    });
+    mock_all_missing(&server);
+
+    let wrong = format!("{}  {name}\n", "ab".repeat(32));
+    let err = updater(&server, &cache)
+        .with_sha256sums(&wrong)
+        .unwrap()
+        .fetch_month(&mut Rates::new(), month)
+        .unwrap_err();
+    let FetchError::ChecksumMismatch { actual, .. } = err else {
+        panic!("expected ChecksumMismatch, got {err}")
+    };
+    assert_eq!(err.code(), "checksum_mismatch");
+    // Nothing unverified was cached
+    assert!(!cache.path().join(&name).exists());
+
+    let verified = updater(&server, &cache).with_checksum(name.as_str(), actual);
+    assert!(verified.fetch_month(&mut Rates::new(), month).unwrap());
+    mock.assert_hits(2);
+    // A tampered cache file is skipped offline and refetched online
+    std::fs::write(cache.path().join(&name), monthly_xml(month, "1.0000")).unwrap();
+    let offline = verified.cached();
+    assert_ne!(
+        offline.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.0000)
+    );
+    let mut rates = Rates::new();
+    assert!(verified.fetch_month(&mut rates, month).unwrap());
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(4.4444)
+    );
+    mock.assert_hits(3);
+
+    for bad in ["nothex  monthly_xml_2025-08.xml", &"ab".repeat(32)] {
+        assert!(matches!(
+            Updater::new().with_sha256sums(bad),
+            Err(FetchError::BadManifest { line: 1 })
+        ));
+    }
+}
+
+#[test]
+fn fetch_latest_prefers_the_pre_published_month() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let next = next_month();
+    server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{next}.xml"));
+        then.status(200).body(monthly_xml(next, "3.3333"));
+    });
+    mock_all_missing(&server);
+
+    let mut rates = Rates::new();
+    let latest = updater(&server, &cache).fetch_latest(&mut rates).unwrap();
+    assert_eq!(latest, Some(next));
+    assert_eq!(
+        rates.monthly_rate("USD", next).unwrap().units_per_gbp(),
+        dec!(3.3333)
+    );
+
+    let empty = MockServer::start();
+    mock_all_missing(&empty);
+    let none = updater(&empty, &tempfile::tempdir().unwrap()).fetch_latest(&mut rates);
+    assert_eq!(none.unwrap(), None);
+}
+
+#[test]
+fn metrics_count_downloads_but_not_cache_hits() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let next = next_month();
+    server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{next}.xml"));
+        then.status(200).body(monthly_xml(next, "3.3333"));
+    });
+    mock_all_missing(&server);
+
+    let metrics = Metrics::new();
+    let rates = &mut Rates::new();
+    let fetcher = updater(&server, &cache).with_metrics(metrics.clone());
+    fetcher.fetch_month(rates, next).unwrap();
+    fetcher.fetch_month(rates, next).unwrap(); // cached within the TTL
+    fetcher.fetch_month(rates, next.next()).unwrap(); // 404
+    let today = Utc::now().date_naive();
+    let text = metrics.render(rates, today);
+    assert!(
+        text.contains("hmrc_rates_fetch_successes_total 2\n"),
+        "{text}"
+    );
+    assert!(!text.contains("hmrc_rates_fetch_failures_total{"), "{text}");
+
+    let broken = MockServer::start();
+    broken.mock(|when, then| {
+        when.any_request();
+        then.status(200).body("<html>maintenance page</html>");
+    });
+    let empty_cache = tempfile::tempdir().unwrap();
+    let fetcher = updater(&broken, &empty_cache).with_metrics(metrics.clone());
+    assert!(fetcher.fetch_month(rates, next).is_err());
+    let text = metrics.render(rates, today);
+    assert!(
+        text.contains("hmrc_rates_fetch_failures_total{code=\"bad_data\"} 1\n"),
+        "{text}"
+    );
+    assert!(text.contains("hmrc_rates_data_age_days -"), "{text}");
+}
+
+#[test]
+fn past_months_fetch_offline_after_the_first_download() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{month}.xml"));
+        then.status(200).body(monthly_xml(month, "2.2222"));
+    });
+    assert!(
+        updater(&server, &cache)
+            .fetch_month(&mut Rates::new(), month)
+            .unwrap()
+    );
+
+    // Nothing listens here: only the cache can answer
+    let offline = Updater::new()
+        .with_base_url("http://127.0.0.1:9")
+        .with_cache_dir(cache.path());
+    let mut rates = Rates::new();
+    assert!(offline.fetch_month(&mut rates, month).unwrap());
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(2.2222)
+    );
+}
+
+/// Polls until `done` holds; background threads need a moment.
+fn eventually(mut done: impl FnMut() -> bool) -> bool {
+    (0..200).any(|_| {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        done()
+    })
+}
+
+#[test]
+fn auto_refresher_swaps_in_new_months() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    let next = next_month();
+    server.mock(|when, then| {
+        when.method(GET).path(format!("/monthly_xml_{next}.xml"));
+        then.status(200).body(monthly_xml(next, "1.2345"));
+    });
+    mock_all_missing(&server);
+
+    let refresher = AutoRefresher::start(
+        updater(&server, &cache),
+        std::time::Duration::from_secs(3600),
+    );
+    let handle = refresher.handle();
+    assert!(eventually(|| handle.current().monthly(next).is_ok()));
+    assert_eq!(
+        handle
+            .current()
+            .monthly_rate("USD", next)
+            .unwrap()
+            .units_per_gbp(),
+        dec!(1.2345)
+    );
+    assert!(refresher.take_error().is_none());
+    drop(refresher); // joins the thread without waiting out the interval
+}
+
+#[test]
+fn auto_refresher_keeps_old_rates_and_reports_failures() {
+    let server = MockServer::start();
+    let cache = tempfile::tempdir().unwrap();
+    server.mock(|when, then| {
+        when.any_request();
+        then.status(500);
+    });
+
+    let refresher = AutoRefresher::start(
+        updater(&server, &cache),
+        std::time::Duration::from_secs(3600),
+    );
+    let handle = refresher.handle();
+    let mut error = None;
+    assert!(eventually(|| {
+        error = error.take().or_else(|| refresher.take_error());
+        error.is_some()
+    }));
+    assert!(matches!(error, Some(FetchError::Http(_))));
+    assert!(handle.current().months().eq(Rates::new().months()));
+}
+
 /// Live smoke test against the real endpoint; run manually or from the cron
 /// workflow with `cargo test --features http -- --ignored`.
 #[test]
@@ -0,0 +1,40 @@
+//! Interop with `iso_currency` behind the `iso-currency` feature.
+#![cfg(all(feature = "iso-currency", feature = "bundled"))]
+#![allow(clippy::unwrap_used)]
+
+use hmrc_rates::{Currency, RateType, Rates, YearMonth};
+
+#[test]
+fn iso_currencies_look_up_and_list() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+
+    let usd = Currency::from(iso_currency::Currency::USD);
+    assert_eq!(usd.as_str(), "USD");
+    assert_eq!(
+        rates.monthly_rate(usd, month).unwrap(),
+        rates
+            .monthly_rate(iso_currency::Currency::USD.code(), month)
+            .unwrap()
+    );
+    assert_eq!(
+        iso_currency::Currency::try_from(usd).unwrap(),
+        iso_currency::Currency::USD
+    );
+    assert_eq!(usd.iso().unwrap().exponent(), Some(2));
+    let historical: Currency = "XXY".parse().unwrap();
+    assert!(iso_currency::Currency::try_from(historical).is_err());
+
+    let table = rates.monthly(month).unwrap();
+    let listed: Vec<_> = table.iso_currencies().collect();
+    assert!(listed.contains(&iso_currency::Currency::JPY));
+    assert!(listed.len() <= table.len());
+    assert!(
+        listed
+            .iter()
+            .all(|iso| table.rate(Currency::from(*iso)).is_ok())
+    );
+    let all: Vec<_> = rates.iso_currencies(RateType::Monthly).collect();
+    assert!(all.len() >= listed.len());
+    assert!(listed.iter().all(|iso| all.contains(iso)));
+}
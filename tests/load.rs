@@ -0,0 +1,782 @@
+//! Loading HMRC files from readers.
+#![cfg(feature = "load")]
+#![allow(clippy::unwrap_used, clippy::panic)]
+
+use std::io::Read;
+
+use hmrc_rates::{
+    DuplicateRates, LoadError, MergePolicy, ParseWarning, ParseWarningKind, Period, Rates,
+    ValidationIssue, YearMonth,
+};
+use rust_decimal_macros::dec;
+
+const USD_ONLY: &str = r#"<?xml version="1.0"?>
+<exchangeRateMonthList Period="01/Aug/2025 to 31/Aug/2025">
+  <exchangeRate><countryName>USA</countryName><currencyCode>USD</currencyCode><rateNew>1.5</rateNew></exchangeRate>
+</exchangeRateMonthList>"#;
+
+/// Hands out one byte per read, like a slow socket.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Some((first, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+        buf[0] = *first;
+        self.0 = rest;
+        Ok(1)
+    }
+}
+
+#[test]
+fn from_reader_holds_only_that_month() {
+    let file = std::fs::File::open("data/monthly/2025-08.xml").unwrap();
+    let rates = Rates::from_reader(file).unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert!(rates.months().eq([month]));
+    let usd = rates.monthly_rate("USD", month).unwrap();
+    assert_eq!(usd.units_per_gbp(), dec!(1.3541));
+}
+
+#[test]
+fn load_reader_streams_in_small_reads() {
+    let mut rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let month = rates.load_reader(Trickle(USD_ONLY.as_bytes())).unwrap();
+    assert_eq!(month, YearMonth::new(2025, 8).unwrap());
+    assert_eq!(rates.monthly(month).unwrap().len(), 1);
+}
+
+#[test]
+fn loaded_xml_keeps_country_names() {
+    let xml = USD_ONLY.replace(
+        "<countryName>USA</countryName>",
+        "<countryName>USA</countryName><countryCode>US</countryCode><currencyName>Dollar </currencyName>",
+    );
+    let rates = Rates::from_reader(xml.as_bytes()).unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let usa = rates.country("us", month).unwrap();
+    assert_eq!((usa.name(), usa.currency_name()), ("USA", "Dollar"));
+    assert_eq!(
+        rates.country_rate("US", month).unwrap().units_per_gbp(),
+        dec!(1.5)
+    );
+    assert_eq!(rates.countries(month.prev()).len(), 0);
+}
+
+#[cfg(feature = "bundled")]
+#[test]
+fn load_reader_replaces_a_bundled_month() {
+    let mut rates = Rates::new();
+    let month = rates.load_reader(USD_ONLY.as_bytes()).unwrap();
+    assert_eq!(rates.monthly(month).unwrap().len(), 1);
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.5)
+    );
+    assert!(rates.monthly(month.prev()).unwrap().len() > 100);
+}
+
+#[test]
+fn load_csv_reads_the_monthly_csv_layout() {
+    let csv = "Country/Territories,Currency,Currency Code,Currency Units per £1,Start date,End date\n\
+               USA,Dollar,usd,1.3541,01/08/2025,31/08/2025\n\
+               Eurozone,Euro,EUR,1.1547,01/08/2025,31/08/2025\n";
+    let month = YearMonth::new(2025, 8).unwrap();
+    let mut rates = Rates::from_csv(csv.as_bytes(), month).unwrap();
+    assert!(rates.months().eq([month]));
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.3541)
+    );
+
+    let bad = "Country,Currency Code,Currency Units per £1\nUSA,USD,n/a\n";
+    assert!(matches!(
+        rates.load_csv(bad.as_bytes(), month.next()),
+        Err(LoadError::BadData { .. })
+    ));
+    assert!(rates.months().eq([month]));
+}
+
+#[test]
+fn malformed_input_leaves_rates_unchanged() {
+    let mut rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let truncated = &USD_ONLY.as_bytes()[..USD_ONLY.len() / 2];
+    let err = rates.load_reader(truncated).unwrap_err();
+    assert!(matches!(err, LoadError::BadData { .. }), "{err}");
+    assert_eq!(err.code(), "bad_data");
+    // Broken XML reports how far the parse got
+    let mismatched = USD_ONLY.replace("</rateNew>", "</rate>");
+    let Err(LoadError::BadData {
+        offset: Some(offset),
+        ..
+    }) = rates.load_reader(mismatched.as_bytes())
+    else {
+        panic!("expected BadData with an offset")
+    };
+    let line = mismatched.find("<exchangeRate>").unwrap();
+    assert!(
+        (line..mismatched.len()).contains(&(offset as usize)),
+        "{offset}"
+    );
+    let bad_rate = USD_ONLY.replace("1.5", "-1.5");
+    assert!(rates.load_reader(bad_rate.as_bytes()).is_err());
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.5)
+    );
+}
+
+#[test]
+fn lenient_loads_skip_and_report_bad_records() {
+    let xml = r#"<exchangeRateMonthList Period="01/Aug/2025 to 31/Aug/2025">
+      <exchangeRate><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>EURO</currencyCode><rateNew>1.1547</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>JPY</currencyCode><rateNew>n/a</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>CHF</currencyCode></exchangeRate>
+    </exchangeRateMonthList>"#;
+    assert!(Rates::from_reader(xml.as_bytes()).is_err());
+
+    let (rates, warnings) = Rates::from_reader_lenient(xml.as_bytes()).unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(rates.monthly(month).unwrap().len(), 1);
+    assert!(rates.monthly_rate("USD", month).is_ok());
+    let records: Vec<usize> = warnings.iter().map(|w: &ParseWarning| w.record).collect();
+    assert_eq!(records, [2, 3, 4]);
+    assert!(warnings.iter().all(|w| w.kind == ParseWarningKind::Skipped));
+    assert!(
+        warnings[1]
+            .to_string()
+            .starts_with("skipped exchangeRate record 3: ")
+    );
+
+    // Leniency covers records, not the document
+    let nothing_usable = xml.replace("<currencyCode>USD</currencyCode>", "");
+    assert!(Rates::from_reader_lenient(nothing_usable.as_bytes()).is_err());
+    let truncated = &xml[..xml.len() - 30];
+    assert!(Rates::from_reader_lenient(truncated.as_bytes()).is_err());
+}
+
+#[test]
+fn duplicate_rates_follow_the_policy() {
+    let xml = r#"<exchangeRateMonthList Period="01/Aug/2025 to 31/Aug/2025">
+      <exchangeRate><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>EUR</currencyCode><rateNew>1.1547</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>USD</currencyCode><rateNew>1.3600</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>EUR</currencyCode><rateNew>1.1547</rateNew></exchangeRate>
+      <exchangeRate><currencyCode>USD</currencyCode><rateNew>1.3541</rateNew></exchangeRate>
+    </exchangeRateMonthList>"#;
+    let month = YearMonth::new(2025, 8).unwrap();
+    let usd = |rule: DuplicateRates| {
+        let mut rates = Rates::empty().with_duplicate_rates(rule);
+        rates.load_reader(xml.as_bytes())?;
+        Ok::<_, LoadError>(rates.monthly_rate("USD", month).unwrap().units_per_gbp())
+    };
+    assert_eq!(Rates::empty().duplicate_rates(), DuplicateRates::Majority);
+    assert_eq!(usd(DuplicateRates::Majority).unwrap(), dec!(1.3541));
+    assert_eq!(usd(DuplicateRates::KeepFirst).unwrap(), dec!(1.3541));
+    assert_eq!(usd(DuplicateRates::KeepLast).unwrap(), dec!(1.3541));
+    let e = usd(DuplicateRates::Reject).unwrap_err();
+    assert!(
+        e.to_string()
+            .contains("'USD' is listed at both 1.3541 and 1.3600"),
+        "{e}"
+    );
+
+    // Identical repeats are never a conflict; the lenient report names each overruled row
+    // USD at 1.3541, 1.3600, then 1.3700
+    let last = xml
+        .replacen("1.3541", "1.3700", 2)
+        .replacen("1.3700", "1.3541", 1);
+    let mut rates = Rates::empty().with_duplicate_rates(DuplicateRates::KeepLast);
+    let (_, warnings) = rates.load_reader_lenient(last.as_bytes()).unwrap();
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.3700)
+    );
+    assert!(
+        warnings
+            .iter()
+            .all(|w| w.kind == ParseWarningKind::Overruled)
+    );
+    let warnings: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        warnings,
+        [
+            "overruled exchangeRate record 1: USD at 1.3541 lost to 1.3700",
+            "overruled exchangeRate record 3: USD at 1.3600 lost to 1.3700",
+        ]
+    );
+}
+
+#[test]
+fn load_dir_reads_hmrc_named_files_only() {
+    let dir = tempfile::tempdir().unwrap();
+    let copy = |from: &str, to: &str| std::fs::copy(from, dir.path().join(to)).unwrap();
+    copy("data/monthly/2025-08.xml", "exrates-monthly-0825.xml");
+    copy("data/monthly/2025-07.xml", "exrates-monthly-0725.xml");
+    copy("data/monthly/2025-06.xml", "notes.xml");
+    std::fs::create_dir(dir.path().join("exrates-monthly-old.xml")).unwrap();
+
+    let rates = Rates::from_dir(dir.path()).unwrap();
+    let (july, august) = (
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    assert!(rates.months().eq([july, august]));
+    let path = dir.path().join("exrates-monthly-0825.xml");
+    let provenance = rates.provenance(august).unwrap();
+    assert_eq!(
+        *provenance.source(),
+        hmrc_rates::Source::File {
+            path: path.display().to_string()
+        }
+    );
+    assert!(provenance.loaded_at().is_some());
+
+    let mut rates = rates;
+    rates.load_reader(USD_ONLY.as_bytes()).unwrap();
+    let provenance = rates.provenance(august).unwrap();
+    assert_eq!(*provenance.source(), hmrc_rates::Source::Reader);
+}
+
+#[test]
+fn load_dir_names_the_bad_file_and_changes_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("exrates-monthly-0825.xml"), USD_ONLY).unwrap();
+    let bad = dir.path().join("exrates-monthly-0925.xml");
+    std::fs::write(&bad, "<exchangeRateMonthList/>").unwrap();
+
+    let mut rates = Rates::from_reader(USD_ONLY.replace("1.5", "2").as_bytes()).unwrap();
+    let err = rates.load_dir(dir.path()).unwrap_err();
+    let LoadError::File { path, .. } = &err else {
+        panic!("unexpected {err:?}");
+    };
+    assert_eq!(path, &bad);
+    assert!(err.to_string().contains("exrates-monthly-0925.xml"));
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(2)
+    );
+}
+
+#[test]
+fn load_dir_partial_keeps_the_good_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("exrates-monthly-0825.xml"), USD_ONLY).unwrap();
+    let bad = dir.path().join("exrates-monthly-0925.xml");
+    std::fs::write(&bad, "<exchangeRateMonthList/>").unwrap();
+
+    let mut rates = Rates::from_reader(USD_ONLY.replace("1.5", "2").as_bytes()).unwrap();
+    let report = rates.load_dir_partial(dir.path()).unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(report.loaded(), [month]);
+    assert!(!report.is_complete());
+    let [LoadError::File { path, .. }] = report.failures() else {
+        panic!("unexpected {:?}", report.failures());
+    };
+    assert_eq!(path, &bad);
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap().units_per_gbp(),
+        dec!(1.5)
+    );
+
+    assert!(rates.load_dir_partial(dir.path().join("missing")).is_err());
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn load_zip_reads_every_xml_entry() {
+    use std::io::{Cursor, Write};
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    for (name, month) in [
+        ("2025/exrates-monthly-0825.xml", "08"),
+        ("exrates-monthly-0725.xml", "07"),
+    ] {
+        writer.start_file(name, options).unwrap();
+        writer
+            .write_all(&std::fs::read(format!("data/monthly/2025-{month}.xml")).unwrap())
+            .unwrap();
+    }
+    writer.start_file("README.txt", options).unwrap();
+    writer.write_all(b"not rates").unwrap();
+    let archive = writer.finish().unwrap();
+
+    let rates = Rates::from_zip(Cursor::new(archive.into_inner())).unwrap();
+    let (july, august) = (
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    assert!(rates.months().eq([july, august]));
+    assert_eq!(
+        *rates.provenance(august).unwrap().source(),
+        hmrc_rates::Source::ZipEntry {
+            name: "2025/exrates-monthly-0825.xml".into()
+        }
+    );
+
+    let mut rates = rates;
+    let err = rates
+        .load_zip(Cursor::new(b"not a zip".to_vec()))
+        .unwrap_err();
+    assert!(matches!(err, LoadError::Zip(_)), "{err}");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test(flavor = "current_thread")]
+async fn load_reader_async_matches_the_blocking_loader() {
+    let file = tokio::fs::File::open("data/monthly/2025-08.xml")
+        .await
+        .unwrap();
+    let mut rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let month = rates.load_reader_async(file).await.unwrap();
+    let bytes = std::fs::read("data/monthly/2025-08.xml").unwrap();
+    let blocking = Rates::from_reader(bytes.as_slice()).unwrap();
+    assert!(
+        rates
+            .monthly(month)
+            .unwrap()
+            .iter()
+            .eq(blocking.monthly(month).unwrap().iter())
+    );
+
+    let truncated = &bytes[..bytes.len() / 2];
+    assert!(rates.load_reader_async(truncated).await.is_err());
+}
+
+#[cfg(feature = "bundled")]
+#[test]
+fn load_dir_of_a_year_matches_the_bundle() {
+    let dir = tempfile::tempdir().unwrap();
+    for month in 1..=12 {
+        let from = format!("data/monthly/2024-{month:02}.xml");
+        let to = dir.path().join(format!("exrates-monthly-{month:02}24.xml"));
+        std::fs::copy(from, to).unwrap();
+    }
+    let loaded = Rates::from_dir(dir.path()).unwrap();
+    let bundled = Rates::new();
+    let year: Vec<YearMonth> = (1..=12).map(|m| YearMonth::new(2024, m).unwrap()).collect();
+    assert!(loaded.months().eq(year.iter().copied()));
+    for month in year {
+        let (a, b) = (
+            loaded.monthly(month).unwrap(),
+            bundled.monthly(month).unwrap(),
+        );
+        assert!(a.iter().eq(b.iter()), "{month}");
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn load_api_json_reads_exchange_rate_resources() {
+    let json = r#"{
+        "data": {"id": "2025-8", "type": "exchange_rate_period"},
+        "included": [
+            {"id": "1", "type": "exchange_rate", "attributes": {"currency_code": "USD",
+                "rate": "1.3541", "validity_start_date": "2025-08-01", "validity_end_date": "2025-08-31"}},
+            {"id": "2", "type": "exchange_rate", "attributes": {"currency_code": "eur",
+                "rate": 1.1547, "validity_start_date": "2025-08-01"}},
+            {"id": "3", "type": "exchange_rate_country", "attributes": {"country": "USA"}}
+        ]
+    }"#;
+    let mut rates = Rates::from_api_json(json).unwrap();
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert!(rates.months().eq([month]));
+    assert_eq!(rates.monthly(month).unwrap().len(), 2);
+    assert_eq!(
+        rates.monthly_rate("EUR", month).unwrap().units_per_gbp(),
+        dec!(1.1547)
+    );
+
+    let mixed = json.replacen("2025-08-01", "2025-09-01", 1);
+    assert!(matches!(
+        rates.load_api_json(&mixed),
+        Err(LoadError::BadData { .. })
+    ));
+    assert!(rates.load_api_json(r#"{"included": []}"#).is_err());
+    assert!(rates.months().eq([month]));
+}
+
+#[test]
+fn validate_flags_gaps_jumps_and_dropped_currencies() {
+    let month = |period: &str, body: &str| {
+        format!(r#"<exchangeRateMonthList Period="{period}">{body}</exchangeRateMonthList>"#)
+    };
+    let record = |code: &str, rate: &str| {
+        format!(
+            "<exchangeRate><currencyCode>{code}</currencyCode><rateNew>{rate}</rateNew></exchangeRate>"
+        )
+    };
+    let mut rates = Rates::from_reader(
+        month(
+            "01/Jun/2025 to 30/Jun/2025",
+            &(record("USD", "1.3") + &record("HRK", "9.1")),
+        )
+        .as_bytes(),
+    )
+    .unwrap();
+    assert!(
+        Rates::from_reader(USD_ONLY.as_bytes())
+            .unwrap()
+            .validate()
+            .is_clean()
+    );
+    rates
+        .load_reader(month("01/Aug/2025 to 31/Aug/2025", &record("USD", "2.1")).as_bytes())
+        .unwrap();
+
+    let (jun, jul, aug) = (
+        YearMonth::new(2025, 6).unwrap(),
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    );
+    let report = rates.validate();
+    assert_eq!(
+        report.issues(),
+        [
+            ValidationIssue::MissingMonth { month: jul },
+            ValidationIssue::CurrencyDropped {
+                currency: "HRK".parse().unwrap(),
+                last: jun,
+                next: aug,
+            },
+            ValidationIssue::RateJump {
+                currency: "USD".parse().unwrap(),
+                from: jun,
+                from_rate: dec!(1.3),
+                to: aug,
+                to_rate: dec!(2.1),
+            },
+        ]
+    );
+    assert_eq!(
+        report.issues()[2].to_string(),
+        "USD moved from 1.3 in 2025-06 to 2.1 in 2025-08"
+    );
+    assert_eq!(rates.missing_months(), [jul]);
+}
+
+#[test]
+fn merge_layers_periods_by_policy() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let usd = |rates: &Rates| rates.monthly_rate("USD", aug).unwrap().units_per_gbp();
+    let newer = Rates::from_reader(USD_ONLY.replace("1.5", "2").as_bytes()).unwrap();
+    let mut september = Rates::from_reader(
+        USD_ONLY
+            .replace("01/Aug/2025 to 31/Aug/2025", "01/Sep/2025 to 30/Sep/2025")
+            .as_bytes(),
+    )
+    .unwrap();
+    september
+        .merge(
+            &Rates::from_reader(USD_ONLY.as_bytes()).unwrap(),
+            MergePolicy::ErrorOnConflict,
+        )
+        .unwrap();
+    assert!(september.months().eq([aug, aug.next()]));
+
+    let mut rates = september.clone();
+    rates.merge(&newer, MergePolicy::KeepExisting).unwrap();
+    assert_eq!(usd(&rates), dec!(1.5));
+    let conflict = rates
+        .merge(&newer, MergePolicy::ErrorOnConflict)
+        .unwrap_err();
+    assert_eq!(conflict.period, Period::YearMonth(aug));
+    assert_eq!(usd(&rates), dec!(1.5));
+    rates.merge(&newer, MergePolicy::Overwrite).unwrap();
+    assert_eq!(usd(&rates), dec!(2));
+    assert!(rates.months().eq([aug, aug.next()]));
+}
+
+#[cfg(feature = "feeds")]
+#[test]
+fn central_bank_feeds_answer_per_pound() {
+    use hmrc_rates::RateSource;
+    use hmrc_rates::feeds::DailyRates;
+
+    let date = |d| chrono::NaiveDate::from_ymd_opt(2025, 8, d).unwrap();
+    let (usd, eur, jpy) = (
+        "USD".parse().unwrap(),
+        "EUR".parse().unwrap(),
+        "JPY".parse().unwrap(),
+    );
+    let csv = "DATE,XUDLUSS,XUDLERS,XUDLJYS\n\
+               28 Aug 2025,1.3490,1.1580,198.61\n\
+               29 Aug 2025,1.3507,,198.90\n";
+    let boe = DailyRates::from_boe_csv(csv.as_bytes()).unwrap();
+    assert_eq!(boe.units_per_gbp_on(usd, date(29)), Some(dec!(1.3507)));
+    assert_eq!(boe.units_per_gbp_on(eur, date(29)), None);
+    assert_eq!(boe.units_per_gbp_on(usd, date(27)), None);
+    // A month answers with its last published day for that currency
+    let aug = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(boe.rate("USD", aug).unwrap().units_per_gbp(), dec!(1.3507));
+    assert_eq!(boe.rate("EUR", aug).unwrap().units_per_gbp(), dec!(1.1580));
+    assert!(boe.rate("USD", aug.next()).is_err());
+    assert!(DailyRates::from_boe_csv("DATE,XUDLQQQ\n".as_bytes()).is_err());
+
+    let xml = r#"<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01">
+      <Cube><Cube time="2025-08-29">
+        <Cube currency="USD" rate="1.1658"/><Cube currency="GBP" rate="0.8640"/>
+        <Cube currency="JPY" rate="171.44"/>
+      </Cube><Cube time="2025-08-28"><Cube currency="USD" rate="1.1650"/></Cube></Cube>
+    </gesmes:Envelope>"#;
+    let ecb = DailyRates::from_ecb_xml(xml.as_bytes()).unwrap();
+    assert!(ecb.days().eq([date(29)])); // no GBP quote on the 28th
+    assert_eq!(
+        ecb.units_per_gbp_on(usd, date(29)),
+        Some(dec!(1.1658) / dec!(0.8640))
+    );
+    assert_eq!(
+        ecb.units_per_gbp_on(eur, date(29)),
+        Some(dec!(1) / dec!(0.8640))
+    );
+    assert!(ecb.units_per_gbp_on(jpy, date(29)).is_some());
+    assert_eq!(ecb.monthly_coverage(), Some((aug, aug)));
+
+    let overflowing = r#"<Cube><Cube time="2025-08-29">
+      <Cube currency="GBP" rate="0.5"/>
+      <Cube currency="USD" rate="79228162514264337593543950335"/></Cube></Cube>"#;
+    assert!(matches!(
+        DailyRates::from_ecb_xml(overflowing.as_bytes()),
+        Err(LoadError::BadData { .. })
+    ));
+}
+
+#[test]
+fn csv_converter_appends_gbp_and_rate() {
+    use hmrc_rates::{CsvConverter, CsvError, NumberFormat, Rounding};
+
+    let rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let input = "when,what,value,ccy\n\
+                 14/08/2025,hotel,\"1,200.00\",usd\n\
+                 01/08/2025,\"taxi, airport\",3,USD\n";
+    let mut output = Vec::new();
+    let rows = CsvConverter::new()
+        .date_column("when")
+        .amount_column("value")
+        .currency_column("ccy")
+        .gbp_column("GBP")
+        .date_format("%d/%m/%Y")
+        .number_format(NumberFormat::UK)
+        .round(2, Rounding::HalfUp)
+        .convert(&rates, input.as_bytes(), &mut output)
+        .unwrap();
+    assert_eq!(rows, 2);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "when,what,value,ccy,GBP,rate\n\
+         14/08/2025,hotel,\"1,200.00\",usd,800.00,1.5\n\
+         01/08/2025,\"taxi, airport\",3,USD,2.00,1.5\n"
+    );
+
+    let convert =
+        |input: &str| CsvConverter::new().convert(&rates, input.as_bytes(), std::io::sink());
+    assert_eq!(
+        convert("amount,currency,date\n3,USD,2025-08-01\n").unwrap(),
+        1
+    );
+    assert!(matches!(
+        convert("amount,date\n3,2025-08-01\n"),
+        Err(CsvError::MissingColumn { column }) if column == "currency"
+    ));
+    assert!(matches!(
+        convert("amount,currency,date\n3,USD,2025-08-01\nx,USD,2025-08-01\n"),
+        Err(CsvError::InvalidValue { line: 3, column, value }) if column == "amount" && value == "x"
+    ));
+    assert!(matches!(
+        convert("amount,currency,date\n3,USD,2025-09-01\n"),
+        Err(CsvError::Lookup {
+            line: 2,
+            source: hmrc_rates::LookupError::PeriodNotAvailable { .. }
+        })
+    ));
+    assert!(matches!(
+        convert(
+            "amount,currency,date
+1000000000000000000000000.37,USD,2025-08-01
+"
+        ),
+        Err(CsvError::Arithmetic {
+            line: 2,
+            source: hmrc_rates::ArithmeticError::PrecisionLoss { .. }
+        })
+    ));
+}
+
+#[test]
+fn csv_converter_applies_the_rates_policy_and_observer() {
+    use hmrc_rates::{ConvertError, CsvConverter, CsvError, NegativeAmounts};
+    use std::sync::{Arc, Mutex};
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut rates = Rates::from_reader(USD_ONLY.as_bytes())
+        .unwrap()
+        .with_observer({
+            let log = Arc::clone(&log);
+            move |conversion: &hmrc_rates::Conversion| log.lock().unwrap().push(*conversion)
+        });
+    let convert = |rates: &Rates, input: &str| {
+        CsvConverter::new().convert(rates, input.as_bytes(), std::io::sink())
+    };
+
+    let input = "amount,currency,date\n3,USD,2025-08-01\n-6,usd,2025-08-02\n";
+    assert_eq!(convert(&rates, input).unwrap(), 2);
+    assert_eq!(
+        log.lock()
+            .unwrap()
+            .iter()
+            .map(|c| c.gbp().amount())
+            .collect::<Vec<_>>(),
+        [dec!(2), dec!(-4)]
+    );
+
+    rates.set_negative_amounts(NegativeAmounts::Reject);
+    assert!(matches!(
+        convert(&rates, input),
+        Err(CsvError::Rejected {
+            line: 3,
+            source: ConvertError::NegativeAmount { .. }
+        })
+    ));
+}
+
+#[test]
+fn builder_loads_readers_over_an_empty_start() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let rates = Rates::builder()
+        .empty()
+        .reader(USD_ONLY.as_bytes())
+        .unwrap()
+        .build();
+    assert_eq!(rates.months().collect::<Vec<_>>(), [aug]);
+    assert_eq!(
+        rates.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.5)
+    );
+    assert!(Rates::builder().reader(&b"<nope"[..]).is_err());
+}
+
+#[test]
+fn builder_applies_the_duplicate_rule_to_later_loads() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let repeated = USD_ONLY.replace(
+        "</exchangeRateMonthList>",
+        "<exchangeRate><currencyCode>USD</currencyCode><rateNew>1.6</rateNew></exchangeRate>\
+         </exchangeRateMonthList>",
+    );
+    let rates = Rates::builder()
+        .duplicate_rates(DuplicateRates::KeepLast)
+        .empty()
+        .reader(repeated.as_bytes())
+        .unwrap()
+        .build();
+    assert_eq!(rates.duplicate_rates(), DuplicateRates::KeepLast);
+    assert_eq!(
+        rates.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.6)
+    );
+    let strict = Rates::builder()
+        .empty()
+        .duplicate_rates(DuplicateRates::Reject)
+        .reader(repeated.as_bytes());
+    assert!(strict.is_err());
+}
+
+#[cfg(all(feature = "parallel", feature = "bundled"))]
+#[test]
+fn par_convert_many_matches_convert_many_in_order() {
+    let rates = Rates::new();
+    let months: Vec<_> = rates.months().rev().take(12).collect();
+    let codes = ["USD", "eur", "JPY", "XXX", "GBP"];
+    let items: Vec<_> = (0..20_000u32)
+        .map(|i| {
+            let amount = rust_decimal::Decimal::from(i) / dec!(7);
+            (
+                amount,
+                codes[i as usize % codes.len()],
+                months[i as usize % months.len()],
+            )
+        })
+        .collect();
+    let parallel = rates.par_convert_many(&items);
+    assert_eq!(parallel, rates.convert_many(items.iter().copied()));
+    assert!(parallel[3].is_err() && parallel[4].is_ok());
+}
+
+#[test]
+fn csv_converter_streams_headerless_tsv() {
+    use hmrc_rates::CsvConverter;
+
+    let rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let input = "3\tusd\t2025-08-01\n1.5\tUSD\t2025-08-31\n";
+    let mut output = Vec::new();
+    let rows = CsvConverter::new()
+        .headerless()
+        .streaming()
+        .delimiter(b'\t')
+        .convert(&rates, input.as_bytes(), &mut output)
+        .unwrap();
+    assert_eq!(rows, 2);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "3\tusd\t2025-08-01\t2.0\t1.5\n1.5\tUSD\t2025-08-31\t1\t1.5\n"
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_overrides_layer_over_loaded_months() {
+    use hmrc_rates::Source;
+
+    let mut rates = Rates::from_reader(USD_ONLY.as_bytes()).unwrap();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let sep = aug.next();
+    let months = rates
+        .load_overrides(
+            r#"
+            [[override]]
+            month = "2025-09"
+            currency = "EUR"
+            rate = 1.17
+
+            [[override]]
+            month = "2025-08"
+            currency = "usd"
+            rate = "1.4"
+
+            [[override]]
+            month = "2025-08"
+            currency = "USD"
+            rate = "1.3540"
+            note = "later entries win"
+            "#,
+        )
+        .unwrap();
+    assert_eq!(months, [aug, sep]);
+    let usd = rates.monthly_rate("USD", aug).unwrap();
+    assert_eq!(usd.units_per_gbp(), dec!(1.354));
+    assert_eq!(
+        rates.monthly_rate("EUR", sep).unwrap().units_per_gbp(),
+        dec!(1.17)
+    );
+    assert_eq!(*rates.provenance(aug).unwrap().source(), Source::Manual);
+
+    let bad = "[[override]]\nmonth = \"2025-08\"\ncurrency = \"USD\"\nrate = \"1.1\"\n\n[[override]]\nmonth = \"2025-08\"\ncurrency = \"USD\"\nrate = \"-1\"\n";
+    let err = rates.load_overrides(bad).unwrap_err();
+    assert!(err.to_string().contains("override 2: "), "{err}");
+    assert_eq!(rates.monthly_rate("USD", aug).unwrap(), usd);
+    let err = rates
+        .load_overrides("[[override]]\nmonth = \"2025-08\"\n")
+        .unwrap_err();
+    assert!(matches!(err, LoadError::BadData { .. }));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("overrides.toml");
+    std::fs::write(&path, bad).unwrap();
+    let err = rates.load_overrides_file(&path).unwrap_err();
+    assert!(err.to_string().starts_with(&path.display().to_string()));
+}
@@ -0,0 +1,129 @@
+//! Column-wise conversion behind the `polars` feature.
+#![cfg(all(feature = "polars", feature = "bundled"))]
+#![allow(clippy::unwrap_used)]
+
+use chrono::NaiveDate;
+use hmrc_rates::polars::gbp_series;
+use hmrc_rates::{Gbp, Rates, Rounding, YearMonth};
+use polars::prelude::*;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn dates(dates: &[Option<(i32, u32, u32)>]) -> Series {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let days: Vec<Option<i32>> = dates
+        .iter()
+        .map(|d| {
+            d.map(|(y, m, d)| (NaiveDate::from_ymd_opt(y, m, d).unwrap() - epoch).num_days() as i32)
+        })
+        .collect();
+    Series::new("date".into(), days)
+        .cast(&DataType::Date)
+        .unwrap()
+}
+
+fn decimals(series: &Series) -> Vec<Option<Decimal>> {
+    let column = series.decimal().unwrap();
+    let scale = column.scale() as u32;
+    column
+        .physical()
+        .iter()
+        .map(|m| m.map(|m| Decimal::from_i128_with_scale(m, scale)))
+        .collect()
+}
+
+#[test]
+fn gbp_series_matches_one_by_one_conversion() {
+    let rates = Rates::new();
+    let amounts = Series::new(
+        "amount".into(),
+        [Some(12_345i64), Some(-250), None, Some(7)],
+    )
+    .cast(&DataType::Decimal(38, 0))
+    .unwrap();
+    let codes = Series::new(
+        "currency".into(),
+        [Some("USD"), Some("eur"), Some("USD"), None],
+    );
+    let days = dates(&[
+        Some((2025, 8, 14)),
+        Some((2024, 2, 1)),
+        Some((2025, 8, 1)),
+        Some((2025, 8, 1)),
+    ]);
+
+    let gbp = gbp_series(&rates, &amounts, &codes, &days, 4, Rounding::HalfEven).unwrap();
+    assert_eq!(gbp.name().as_str(), "gbp");
+    assert_eq!(gbp.dtype(), &DataType::Decimal(38, 4));
+
+    let expected = |amount, code, month: YearMonth| {
+        let rate = rates.monthly_rate(code, month).unwrap();
+        Some(
+            Gbp::new(rate.to_gbp(amount))
+                .round(4, Rounding::HalfEven)
+                .amount(),
+        )
+    };
+    assert_eq!(
+        decimals(&gbp),
+        [
+            expected(dec!(12345), "USD", YearMonth::new(2025, 8).unwrap()),
+            expected(dec!(-250), "EUR", YearMonth::new(2024, 2).unwrap()),
+            None,
+            None,
+        ]
+    );
+}
+
+#[test]
+fn gbp_series_takes_decimal_amounts_at_their_scale() {
+    let amounts = Int128Chunked::from_iter_options("amount".into(), [Some(123_456)].into_iter())
+        .into_decimal_unchecked(38, 2)
+        .into_series();
+    let codes = Series::new("currency".into(), ["JPY"]);
+    let gbp = gbp_series(
+        &Rates::new(),
+        &amounts,
+        &codes,
+        &dates(&[Some((2025, 8, 1))]),
+        2,
+        Rounding::TowardZero,
+    )
+    .unwrap();
+    let rate = Rates::new()
+        .monthly_rate("JPY", YearMonth::new(2025, 8).unwrap())
+        .unwrap();
+    assert_eq!(
+        decimals(&gbp),
+        [Some(rate.to_gbp(dec!(1234.56)).trunc_with_scale(2))]
+    );
+}
+
+#[test]
+fn gbp_series_is_strict_and_refuses_floats() {
+    let rates = Rates::new();
+    let days = dates(&[Some((2025, 8, 1)), Some((2025, 8, 1))]);
+    let amounts = Series::new("amount".into(), [1i64, 2]);
+
+    let unknown = Series::new("currency".into(), ["USD", "XXX"]);
+    let err = gbp_series(&rates, &amounts, &unknown, &days, 2, Rounding::HalfUp).unwrap_err();
+    assert!(err.to_string().contains("row 1"), "{err}");
+
+    let floats = Series::new("amount".into(), [1.0f64, 2.0]);
+    let codes = Series::new("currency".into(), ["USD", "USD"]);
+    assert!(gbp_series(&rates, &floats, &codes, &days, 2, Rounding::HalfUp).is_err());
+
+    let short = Series::new("currency".into(), ["USD"]);
+    assert!(gbp_series(&rates, &amounts, &short, &days, 2, Rounding::HalfUp).is_err());
+
+    let huge = Int128Chunked::from_iter_options(
+        "amount".into(),
+        [Some(1), Some(Decimal::MAX.mantissa())].into_iter(),
+    )
+    .into_decimal_unchecked(38, 0)
+    .into_series();
+    let kwd = Series::new("currency".into(), ["KWD", "KWD"]);
+    let err = gbp_series(&rates, &huge, &kwd, &days, 2, Rounding::HalfUp).unwrap_err();
+    assert!(err.to_string().contains("row 1"), "{err}");
+    assert!(err.to_string().contains("overflows"), "{err}");
+}
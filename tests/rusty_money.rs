@@ -0,0 +1,51 @@
+//! Interop with `rusty_money` behind the `rusty-money` feature.
+#![cfg(all(feature = "rusty-money", feature = "bundled"))]
+#![allow(clippy::unwrap_used)]
+
+use hmrc_rates::{ForeignMoney, Gbp, Rates, YearMonth};
+use rust_decimal_macros::dec;
+use rusty_money::{Money, MoneyError, iso};
+
+#[test]
+fn money_values_convert_both_ways() {
+    let fee = Money::from(Gbp::new(dec!(12.50)));
+    assert_eq!(fee.currency(), iso::GBP);
+    assert_eq!(*fee.amount(), dec!(12.50));
+    assert_eq!(Gbp::try_from(fee).unwrap(), Gbp::new(dec!(12.50)));
+    assert!(matches!(
+        Gbp::try_from(Money::from_major(1, iso::USD)),
+        Err(MoneyError::CurrencyMismatch {
+            expected: "GBP",
+            actual: "USD"
+        })
+    ));
+
+    let invoice: ForeignMoney = "1234.56 JPY".parse().unwrap();
+    let money = Money::try_from(invoice).unwrap();
+    assert_eq!(money.currency(), iso::JPY);
+    assert_eq!(ForeignMoney::from(money), invoice);
+    let historical: ForeignMoney = "1 XXY".parse().unwrap();
+    assert_eq!(
+        Money::try_from(historical),
+        Err(MoneyError::InvalidCurrency)
+    );
+}
+
+#[test]
+fn monthly_rates_work_as_exchange_rates() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let usd = rates.monthly_rate("USD", month).unwrap();
+
+    let rate = usd.exchange_rate().unwrap();
+    assert_eq!((rate.from, rate.to), (iso::GBP, iso::USD));
+    let dollars = rate.convert(&Money::from_major(100, iso::GBP)).unwrap();
+    assert_eq!(*dollars.amount(), usd.from_gbp(dec!(100)));
+
+    let exchange = rates.exchange(month).unwrap();
+    assert_eq!(exchange.get_rate(iso::GBP, iso::USD), Some(rate));
+    let back = exchange.get_rate(iso::USD, iso::GBP).unwrap();
+    let pounds = back.convert(&dollars).unwrap();
+    assert_eq!(pounds.amount().round_dp(20), dec!(100));
+    assert!(rates.exchange(YearMonth::new(2000, 1).unwrap()).is_err());
+}
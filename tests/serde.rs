@@ -2,7 +2,10 @@
 #![cfg(all(feature = "serde", feature = "bundled"))]
 #![allow(clippy::unwrap_used)]
 
-use hmrc_rates::{Currency, Period, Rate, RateType, Rates, YearEnd, YearMonth};
+use hmrc_rates::{
+    Conversion, Currency, Gbp, Period, Rate, RateType, Rates, Table, YearEnd, YearMonth,
+};
+use rust_decimal::Decimal;
 
 fn roundtrip<T>(value: &T) -> T
 where
@@ -67,3 +70,141 @@ fn period_and_rate_roundtrip() {
     let week = rates.weeks().next().unwrap();
     assert_eq!(roundtrip(&week), week);
 }
+
+#[test]
+fn conversion_roundtrip() {
+    let rates = Rates::new();
+    let month = YearMonth::new(2025, 8).unwrap();
+    let conversion = rates
+        .convert_detailed(rust_decimal::Decimal::from(100), "USD", month)
+        .unwrap();
+    let back: Conversion = roundtrip(&conversion);
+    assert_eq!(back, conversion);
+    assert_eq!(back.gbp(), conversion.gbp());
+}
+
+#[test]
+fn gbp_is_a_decimal_string() {
+    let gbp = Gbp::new(Decimal::new(7385, 2));
+    assert_eq!(serde_json::to_string(&gbp).unwrap(), r#""73.85""#);
+    assert_eq!(roundtrip(&gbp), gbp);
+}
+
+#[test]
+fn table_serializes_as_a_rate_map() {
+    let rates = Rates::new();
+    let table = rates.monthly(YearMonth::new(2025, 8).unwrap()).unwrap();
+    let json: serde_json::Value = serde_json::to_value(table).unwrap();
+    assert_eq!(json["rate_type"], "monthly");
+    assert_eq!(json["period"]["year_month"], "2025-08");
+    let usd = table.rate("USD").unwrap().units_per_gbp().to_string();
+    assert_eq!(json["rates"]["USD"], usd.as_str());
+    assert_eq!(json["rates"].as_object().unwrap().len(), table.len());
+}
+
+#[test]
+fn rates_roundtrip_every_series() {
+    let rates = Rates::new();
+    let back: Rates = roundtrip(&rates);
+
+    assert!(back.months().eq(rates.months()));
+    assert!(back.spot_periods().eq(rates.spot_periods()));
+    assert!(back.average_periods().eq(rates.average_periods()));
+    assert!(back.weeks().eq(rates.weeks()));
+    for table in [
+        RateType::Monthly,
+        RateType::Spot,
+        RateType::Average,
+        RateType::Weekly,
+    ] {
+        assert!(back.currencies(table).eq(rates.currencies(table)));
+    }
+
+    let same = |a: Table<'_>, b: Table<'_>| a.iter().eq(b.iter());
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert!(same(
+        rates.monthly(month).unwrap(),
+        back.monthly(month).unwrap()
+    ));
+    let year_end = YearEnd::march(2025);
+    assert!(same(
+        rates.spot(year_end).unwrap(),
+        back.spot(year_end).unwrap()
+    ));
+    assert!(same(
+        rates.average(year_end).unwrap(),
+        back.average(year_end).unwrap()
+    ));
+    let day = chrono::NaiveDate::from_ymd_opt(2015, 6, 15).unwrap();
+    assert!(same(rates.weekly(day).unwrap(), back.weekly(day).unwrap()));
+}
+
+#[test]
+fn invalid_rate_tables_are_rejected() {
+    let month = r#""monthly":{"2025-08":{"USD":"0"}}"#;
+    let json = format!(r#"{{{month},"spot":{{}},"average":{{}},"weekly":[]}}"#);
+    assert!(serde_json::from_str::<Rates>(&json).is_err());
+
+    let week = r#"{"start":"2014-01-14","end":"2014-01-08","rates":{}}"#;
+    let json = format!(r#"{{"monthly":{{}},"spot":{{}},"average":{{}},"weekly":[{week}]}}"#);
+    assert!(serde_json::from_str::<Rates>(&json).is_err());
+
+    let empty = r#"{"monthly":{},"spot":{},"average":{},"weekly":[]}"#;
+    let rates: Rates = serde_json::from_str(empty).unwrap();
+    assert_eq!(rates.months().count(), 0);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_snapshots_are_stable_and_roundtrip() {
+    let json = Rates::new().to_json().unwrap();
+    assert!(json.starts_with(r#"{"monthly":{"2014-02":{"#));
+    let rates = Rates::from_json(&json).unwrap();
+    assert_eq!(rates.to_json().unwrap(), json);
+
+    let month = YearMonth::new(2025, 8).unwrap();
+    assert_eq!(
+        rates.monthly_rate("USD", month).unwrap(),
+        Rates::new().monthly_rate("USD", month).unwrap()
+    );
+    assert!(Rates::from_json(r#"{"monthly":{}}"#).is_err());
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Expense {
+    #[serde(deserialize_with = "hmrc_rates::serde::gbp_from_foreign")]
+    amount: Gbp,
+}
+
+#[test]
+fn gbp_from_foreign_converts_in_the_thread_context() {
+    use std::sync::Arc;
+
+    let rates = Arc::new(Rates::new());
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let jul = YearMonth::new(2025, 7).unwrap();
+    let usd = |month| {
+        rates
+            .convert_detailed(Decimal::from(35), "USD", month)
+            .unwrap()
+            .gbp()
+    };
+    let parse = |json: &str| serde_json::from_str::<Expense>(json).map(|e| e.amount);
+
+    let converted = hmrc_rates::serde::with_context(rates.clone(), aug, || {
+        let text = parse(r#"{"amount": "35 usd"}"#).unwrap();
+        let symbol = parse(r#"{"amount": "$35"}"#).unwrap();
+        let nested = hmrc_rates::serde::with_context(rates.clone(), jul, || {
+            parse(r#"{"amount": {"amount": "35", "currency": "USD"}}"#).unwrap()
+        });
+        let after = parse(r#"{"amount": "35 USD"}"#).unwrap();
+        let unknown = parse(r#"{"amount": "35 XXX"}"#).unwrap_err();
+        assert!(unknown.to_string().contains("XXX"), "{unknown}");
+        assert!(parse(r#"{"amount": "35"}"#).is_err());
+        [text, symbol, nested, after]
+    });
+    assert_eq!(converted, [usd(aug), usd(aug), usd(jul), usd(aug)]);
+
+    let outside = parse(r#"{"amount": "35 USD"}"#).unwrap_err();
+    assert!(outside.to_string().contains("with_context"), "{outside}");
+}
@@ -0,0 +1,103 @@
+//! The JSON service, driven in-process without a socket.
+#![cfg(feature = "serve")]
+#![allow(clippy::unwrap_used)]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use hmrc_rates::{Rates, YearMonth};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+async fn get(uri: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::get(uri).body(Body::empty()).unwrap();
+    let response = hmrc_rates::serve::router(Rates::new())
+        .oneshot(request)
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap_or_default())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn convert_answers_a_reproducible_conversion() {
+    let (status, json) = get("/convert?amount=100&currency=usd&month=2025-08").await;
+    assert_eq!(status, StatusCode::OK);
+    let month = YearMonth::new(2025, 8).unwrap();
+    let expected = Rates::new()
+        .convert_detailed(100.into(), "USD", month)
+        .unwrap();
+    assert_eq!(json, serde_json::to_value(expected).unwrap());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rates_and_currencies_list_a_month() {
+    let (status, table) = get("/rates/2025-08").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(table["rates"]["USD"], "1.3541");
+
+    let (_, month) = get("/currencies?month=2025-08").await;
+    let (_, all) = get("/currencies").await;
+    let count = table["rates"].as_object().unwrap().len();
+    assert_eq!(month.as_array().unwrap().len(), count);
+    assert!(all.as_array().unwrap().len() >= count);
+    assert!(month.as_array().unwrap().contains(&"USD".into()));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn lookup_failures_are_404_bad_queries_400_and_overflow_422() {
+    let (status, json) = get("/rates/2100-01").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(json["error"].as_str().unwrap().contains("available"));
+    assert_eq!(json["code"], "period_not_available");
+
+    let (status, _) = get("/convert?amount=1&currency=ZZZ&month=2025-08").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = get("/convert?amount=1&currency=dollars&month=2025-08").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = get("/convert?amount=lots&currency=USD&month=2025-08").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let huge = "/convert?amount=79228162514264337593543950335&currency=EUR&month=2025-08";
+    let (status, json) = get(huge).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["code"], "precision_loss");
+    let (status, _) = get("/rates/August").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn metrics_count_conversions_by_currency_and_fallback() {
+    let rates = Rates::new().with_lookup_policy(hmrc_rates::LookupPolicy::LatestWithin(u32::MAX));
+    let later = rates.latest_month().unwrap().next().next();
+    let app = hmrc_rates::serve::router(rates);
+    for uri in [
+        "/convert?amount=1&currency=USD&month=2025-08".to_owned(),
+        "/convert?amount=2&currency=USD&month=2025-07".to_owned(),
+        format!("/convert?amount=3&currency=EUR&month={later}"),
+        "/convert?amount=1&currency=ZZZ&month=2025-08".to_owned(),
+    ] {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        app.clone().oneshot(request).await.unwrap();
+    }
+
+    let request = Request::get("/metrics").body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let text = String::from_utf8(bytes.to_vec()).unwrap();
+    for line in [
+        "hmrc_rates_conversions_total{currency=\"EUR\"} 1",
+        "hmrc_rates_conversions_total{currency=\"USD\"} 2",
+        "hmrc_rates_lookup_fallback_months_bucket{le=\"0\"} 2",
+        "hmrc_rates_lookup_fallback_months_bucket{le=\"1\"} 2",
+        "hmrc_rates_lookup_fallback_months_bucket{le=\"2\"} 3",
+        "hmrc_rates_lookup_fallback_months_sum 2",
+        "hmrc_rates_lookup_fallback_months_count 3",
+        "# TYPE hmrc_rates_data_age_days gauge",
+    ] {
+        assert!(
+            text.lines().any(|l| l == line),
+            "{line} missing from\n{text}"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! The `testing` fixtures.
+#![cfg(feature = "testing")]
+#![allow(clippy::unwrap_used, clippy::panic)]
+
+use hmrc_rates::testing::RateTableFixture;
+use hmrc_rates::{Source, YearMonth};
+use rust_decimal_macros::dec;
+
+#[test]
+fn fixtures_hold_only_the_rates_named() {
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let rates = RateTableFixture::month(2025, 8)
+        .rate("usd", dec!(1.354))
+        .in_month(2025, 9)
+        .rate("EUR", dec!(1.15))
+        .rate("USD", dec!(1.36))
+        .build();
+    assert_eq!(rates.months().collect::<Vec<_>>(), [aug, aug.next()]);
+    assert_eq!(
+        rates.monthly_rate("USD", aug).unwrap().units_per_gbp(),
+        dec!(1.354)
+    );
+    assert!(rates.monthly_rate("EUR", aug).is_err());
+    assert_eq!(*rates.provenance(aug).unwrap().source(), Source::Manual);
+}
+
+#[test]
+#[should_panic(expected = "fixture rate for 2025-08")]
+fn bad_fixture_rates_panic() {
+    let _ = RateTableFixture::month(2025, 8).rate("USD", dec!(-1));
+}
+
+#[cfg(feature = "bundled")]
+#[test]
+fn tiny_matches_the_published_rates() {
+    use hmrc_rates::testing::tiny;
+
+    let bundled = hmrc_rates::Rates::new();
+    let tiny = tiny();
+    for (month, table) in tiny.rates_between(
+        YearMonth::new(2025, 7).unwrap(),
+        YearMonth::new(2025, 8).unwrap(),
+    ) {
+        assert_eq!(table.len(), 4);
+        for (currency, rate) in table.iter() {
+            let published = bundled.monthly_rate(currency.as_str(), month).unwrap();
+            assert_eq!(rate.units_per_gbp(), published.units_per_gbp());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Events emitted with the `tracing` feature, seen through a recording subscriber.
+#![cfg(all(feature = "tracing", feature = "bundled"))]
+#![allow(clippy::unwrap_used, clippy::panic)]
+
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "load")]
+use hmrc_rates::YearMonth;
+use hmrc_rates::{LookupPolicy, Rates};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Every event as `field=value` pairs, message first.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Line(String);
+
+impl Visit for Line {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let _ = write!(self.0, "{}={:?} ", field.name(), value);
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut line = Line(String::new());
+        event.record(&mut line);
+        self.0.lock().unwrap().push(line.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+impl Recorder {
+    fn lines(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+#[test]
+fn lookups_report_the_month_used() {
+    let rates = Rates::new();
+    let next = rates.months().next_back().unwrap().next();
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        rates.monthly_rate_or_earlier("USD", next, 1).unwrap();
+        rates
+            .monthly_rate_with("USD", next.next().next(), LookupPolicy::Exact)
+            .unwrap_err();
+    });
+    let lines = recorder.lines();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("message=monthly lookup "));
+    assert!(lines[0].contains(&format!("used={} ", next.prev())));
+    assert!(lines[0].contains("months_away=-1 "));
+    assert!(lines[1].starts_with("message=no monthly table within policy "));
+}
+
+#[cfg(feature = "load")]
+#[test]
+fn directory_loads_report_months_and_timing() {
+    let dir = tempfile::tempdir().unwrap();
+    let xml = concat!(env!("CARGO_MANIFEST_DIR"), "/data/monthly/2025-08.xml");
+    std::fs::copy(xml, dir.path().join("exrates-monthly-0825.xml")).unwrap();
+    std::fs::write(dir.path().join("exrates-monthly-0925.xml"), "not xml").unwrap();
+
+    let recorder = Recorder::default();
+    let report = tracing::subscriber::with_default(recorder.clone(), || {
+        Rates::empty().load_dir_partial(dir.path()).unwrap()
+    });
+    assert_eq!(report.loaded(), [YearMonth::new(2025, 8).unwrap()]);
+    let lines = recorder.lines();
+    assert!(lines[0].starts_with("message=month added month=2025-08 "));
+    assert!(lines[1].starts_with("message=file skipped "));
+    let summary = lines.last().unwrap();
+    assert!(summary.starts_with("message=directory loaded "));
+    assert!(summary.contains("months=1 skipped=1 elapsed_ms="));
+}
@@ -0,0 +1,63 @@
+//! Workbooks written with the `xlsx` feature, read back as the ZIP of XML parts they are.
+#![cfg(all(feature = "xlsx", feature = "bundled"))]
+#![allow(clippy::unwrap_used, clippy::panic)]
+
+use std::io::{Cursor, Read};
+
+use hmrc_rates::{LookupError, Rates, XlsxError, YearMonth};
+
+fn part(archive: &mut zip::ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+    let mut xml = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    xml
+}
+
+#[test]
+fn one_sheet_per_month_in_order() {
+    let rates = Rates::new();
+    let jul = YearMonth::new(2025, 7).unwrap();
+    let aug = YearMonth::new(2025, 8).unwrap();
+    let mut out = Cursor::new(Vec::new());
+    rates.write_xlsx(&mut out, [aug, jul, aug]).unwrap();
+
+    let mut archive = zip::ZipArchive::new(out).unwrap();
+    assert!(archive.by_name("[Content_Types].xml").is_ok());
+    assert!(archive.by_name("xl/worksheets/sheet3.xml").is_err());
+    let workbook = part(&mut archive, "xl/workbook.xml");
+    assert!(workbook.find(r#"name="2025-07""#) < workbook.find(r#"name="2025-08""#));
+
+    let sheet = part(&mut archive, "xl/worksheets/sheet2.xml");
+    assert!(sheet.contains("<t>Currency</t>"));
+    let usd = rates.monthly_rate("USD", aug).unwrap().units_per_gbp();
+    let row = sheet
+        .split("<row ")
+        .find(|row| row.contains("<t>USD</t>") && row.contains("<t>USA</t>"))
+        .unwrap();
+    assert!(row.contains(&format!("<v>{usd}</v>")));
+}
+
+#[test]
+fn a_missing_month_writes_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rates.xlsx");
+    let later = Rates::new().months().next_back().unwrap().next();
+    assert!(matches!(
+        Rates::new().export_xlsx(&path, [later]),
+        Err(XlsxError::Lookup(LookupError::PeriodNotAvailable { .. }))
+    ));
+    assert!(!path.exists());
+    assert!(matches!(
+        Rates::new().export_xlsx(&path, Vec::<YearMonth>::new()),
+        Err(XlsxError::NoMonths)
+    ));
+    assert!(!path.exists());
+
+    Rates::new()
+        .export_xlsx(&path, [YearMonth::new(2025, 8).unwrap()])
+        .unwrap();
+    assert!(path.metadata().unwrap().len() > 0);
+}
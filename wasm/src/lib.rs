@@ -0,0 +1,61 @@
+//! WebAssembly bindings for the `hmrc-rates` crate, via `wasm-bindgen`.
+//!
+//! The bundled history is compiled in, so conversions run fully client-side.
+//! Amounts and rates cross the boundary as decimal strings:
+//! JavaScript numbers are binary floats and would lose exactness.
+
+use hmrc_rates::{RateType, Rates, YearMonth};
+use rust_decimal::Decimal;
+use wasm_bindgen::prelude::*;
+
+fn parse_month(s: &str) -> Result<YearMonth, JsError> {
+    s.parse()
+        .map_err(|e: hmrc_rates::ParseYearMonthError| JsError::new(&e.to_string()))
+}
+
+/// Converts `amount` of `currency` to GBP at the monthly rate for `month` ("YYYY-MM").
+///
+/// Takes and returns exact decimal strings, e.g. `convert("100", "USD", "2025-08")`.
+/// An amount too large to convert exactly throws rather than trapping.
+#[wasm_bindgen]
+pub fn convert(amount: &str, currency: &str, month: &str) -> Result<String, JsError> {
+    let amount: Decimal = amount
+        .trim()
+        .parse()
+        .map_err(|_| JsError::new(&format!("invalid amount '{amount}'")))?;
+    let gbp = Rates::new()
+        .checked_convert(amount, currency, parse_month(month)?)
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(gbp.amount().to_string())
+}
+
+/// Currency codes in `month`'s monthly table, or every monthly code when omitted.
+#[wasm_bindgen(js_name = listCurrencies)]
+pub fn list_currencies(month: Option<String>) -> Result<Vec<String>, JsError> {
+    let rates = Rates::new();
+    Ok(match month {
+        Some(m) => rates
+            .monthly(parse_month(&m)?)
+            .map_err(|e| JsError::new(&e.to_string()))?
+            .currencies()
+            .map(|c| c.to_string())
+            .collect(),
+        None => rates
+            .currencies(RateType::Monthly)
+            .map(|c| c.to_string())
+            .collect(),
+    })
+}
+
+/// The first and last bundled months as `["YYYY-MM", "YYYY-MM"]`.
+#[wasm_bindgen(js_name = dateRange)]
+pub fn date_range() -> Vec<String> {
+    let rates = Rates::new();
+    let mut months = rates.months();
+    months
+        .next()
+        .into_iter()
+        .chain(months.next_back())
+        .map(|m| m.to_string())
+        .collect()
+}